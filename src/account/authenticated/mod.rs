@@ -0,0 +1,361 @@
+use std::{
+
+    borrow::{Cow}, 
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    }, 
+};
+
+use thiserror::{Error};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    account::{
+
+        AccountError,
+        Account,
+    },
+
+    repository::{
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Paginated,
+        Client, 
+
+        media,
+    }, 
+
+    models::common::{
+
+        key::{
+
+            SshSigningKey,
+            GpgKey,
+        },
+
+        user::{User},
+    },
+
+    common::{Date},
+    
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum HandleAuthenticatedError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Repository error!")]
+    Repository(#[from] HandleRepositoryError),
+    #[error("Account error!")]
+    Account(#[from] AccountError),
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize)]
+pub enum StarredSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "updated")]
+    Updated,
+}
+
+impl Default for StarredSort {
+    fn default() -> StarredSort {
+        StarredSort::Created
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Starred {
+    name: String,
+    date: Date,
+}
+
+impl Starred {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_date(&self) -> Date {
+        self.date.clone()
+    }
+
+    pub fn try_get_repository(&self, client: &Client) -> GitHubResult<HandleRepository, HandleAuthenticatedError> {
+        let nothing = || HandleRepositoryError::Nothing { name: self.name.clone() };
+
+        let owner = self.name.split_once('/')
+            .map(|(owner, _)| owner)
+            .unwrap_or(self.name.as_str());
+
+        let owner = match Account::try_from_name(client, owner) {
+            Err(AccountError::Client(ClientError::Response(ClientResponseError::Nothing { .. }))) => return Err(nothing().into()),
+            Err(error) => return Err(error.into()),
+            Ok(owner) => owner,
+        };
+
+        match HandleRepository::try_fetch(&(owner), self.name.as_str()) {
+            Err(HandleRepositoryError::Request { source: ClientError::Response(ClientResponseError::Nothing { .. }), .. }) => Err(nothing().into()),
+            Err(error) => Err(error.into()),
+            Ok(repository) => Ok(repository),
+        }
+    }
+}
+
+impl FmtDisplay for Starred {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{name}", name = self.name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleAuthenticated {
+    pub(crate) client: Client,
+}
+
+impl HandleAuthenticated {
+    pub(crate) fn from(client: &Client) -> HandleAuthenticated {
+        HandleAuthenticated { client: client.clone() }
+    }
+
+    pub fn try_list_starred(&self, sort: StarredSort) -> GitHubResult<Vec<Starred>, HandleAuthenticatedError> {
+        #[derive(Clone, Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            full_name: String,
+        }
+
+        #[derive(Clone, Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            starred_at: Date,
+            repo: CapsuleRepository,
+        }
+
+        let ref query = [
+            ("sort", match sort {
+                StarredSort::Created => "created",
+                StarredSort::Updated => "updated",
+            }.to_owned()),
+        ];
+
+        let collection: Vec<Capsule> = Paginated::try_fetch_all_as(&(self.client), "user/starred", query, media::STAR)?;
+
+        Ok(collection.into_iter().map(|Capsule { starred_at, repo: CapsuleRepository { full_name } }| Starred { 
+            name: full_name, date: starred_at,
+        }).collect())
+    }
+
+    pub fn try_has_starred(&self, repository: &HandleRepository) -> GitHubResult<bool, HandleAuthenticatedError> {
+        match self.client.get(format!("user/starred/{repository}"))?.send() {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    pub fn try_star(&self, repository: &HandleRepository) -> GitHubResult<(), HandleAuthenticatedError> {
+        let _ = {
+
+            self.client.put(format!("user/starred/{repository}"))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_unstar(&self, repository: &HandleRepository) -> GitHubResult<(), HandleAuthenticatedError> {
+        let _ = {
+
+            self.client.delete(format!("user/starred/{repository}"))?
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_list_watched(&self) -> GitHubResult<Vec<String>, HandleAuthenticatedError> {
+        #[derive(Clone, Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            full_name: String,
+        }
+
+        let collection: Vec<Capsule> = Paginated::try_fetch_all(&(self.client), "user/subscriptions", &[])?;
+
+        Ok(collection.into_iter()
+            .map(|Capsule { full_name }| full_name)
+            .collect())
+    }
+
+    pub fn try_is_watching(&self, repository: &HandleRepository) -> GitHubResult<bool, HandleAuthenticatedError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            subscribed: bool,
+        }
+
+        match self.client.get(format!("repos/{repository}/subscription"))?.send() {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(response) => {
+                let Capsule { subscribed } = response.json()?;
+                Ok(subscribed)
+            },
+        }
+    }
+
+    pub fn try_watch(&self, repository: &HandleRepository) -> GitHubResult<(), HandleAuthenticatedError> {
+        let ref payload = serde_json::json!({
+            "subscribed": true,
+            "ignored": false,
+        });
+
+        let _ = {
+
+            self.client.put(format!("repos/{repository}/subscription"))?
+                .json(payload)
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_unwatch(&self, repository: &HandleRepository) -> GitHubResult<(), HandleAuthenticatedError> {
+        let _ = {
+
+            self.client.delete(format!("repos/{repository}/subscription"))?
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    fn try_list_keys<T: DeserializeOwned + FmtDebug>(&self, endpoint: &str) -> GitHubResult<Vec<T>, HandleAuthenticatedError> {
+        Ok(Paginated::try_fetch_all(&(self.client), endpoint, &[])?)
+    }
+
+    pub fn try_list_ssh_signing_keys(&self) -> GitHubResult<Vec<SshSigningKey>, HandleAuthenticatedError> {
+        self.try_list_keys("user/ssh_signing_keys")
+    }
+
+    pub fn try_add_ssh_signing_key(&self, title: impl AsRef<str>, key: impl AsRef<str>) -> GitHubResult<SshSigningKey, HandleAuthenticatedError> {
+        let ref payload = serde_json::json!({
+            "title": title.as_ref(),
+            "key": key.as_ref().trim(),
+        });
+
+        Ok(self.client.post("user/ssh_signing_keys")?
+            .json(payload)
+            .send()?
+            .json()?)
+    }
+
+    pub fn try_delete_ssh_signing_key(&self, number: Number) -> GitHubResult<(), HandleAuthenticatedError> {
+        let _ = {
+
+            self.client.delete(format!("user/ssh_signing_keys/{number}"))?
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_list_gpg_keys(&self) -> GitHubResult<Vec<GpgKey>, HandleAuthenticatedError> {
+        self.try_list_keys("user/gpg_keys")
+    }
+
+    pub fn try_add_gpg_key(&self, name: impl AsRef<str>, armored: impl AsRef<str>) -> GitHubResult<GpgKey, HandleAuthenticatedError> {
+        let ref payload = serde_json::json!({
+            "name": name.as_ref(),
+            "armored_public_key": armored.as_ref(),
+        });
+
+        Ok(self.client.post("user/gpg_keys")?
+            .json(payload)
+            .send()?
+            .json()?)
+    }
+
+    pub fn try_delete_gpg_key(&self, number: Number) -> GitHubResult<(), HandleAuthenticatedError> {
+        let _ = {
+
+            self.client.delete(format!("user/gpg_keys/{number}"))?
+                .send()?
+        };
+
+        Ok(())
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleAuthenticated {
+    type Content = User;
+    type Parent = Client;
+
+    fn get_client(&'a self) -> &'a Client {
+        &(self.client)
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.client)
+    }
+    
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        "user".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            MockResponse,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_starred_repository() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "users/dev-bio", MockResponse::new(403));
+
+        let client = fixtures::client(&(transport));
+
+        let starred = Starred { name: "dev-bio/octo".to_owned(), date: Date::default() };
+        assert!(matches!(starred.try_get_repository(&(client)), Err(HandleAuthenticatedError::Account(..))));
+
+        let starred = Starred { name: "someone/else".to_owned(), date: Date::default() };
+        assert!(matches!(starred.try_get_repository(&(client)), Err(HandleAuthenticatedError::Repository(HandleRepositoryError::Nothing { .. }))));
+    }
+}
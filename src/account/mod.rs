@@ -56,6 +56,7 @@ use crate::{
     GitHubResult,
 };
 
+pub mod authenticated;
 pub mod organization;
 pub mod user;
 
@@ -83,17 +84,26 @@ impl Account {
     pub(crate) fn try_from_name(client: &Client, name: impl AsRef<str>) -> GitHubResult<Account, AccountError> {
         let name = name.as_ref();
 
-        let account = client.get(format!("users/{name}"))?
+        let account: User = client.get(format!("users/{name}"))?
             .send()?.json()?;
 
+        match Account::from_user(client, &(account)) {
+            None => Err(AccountError::Unsupported { account }),
+            Some(account) => Ok(account),
+        }
+    }
+
+    pub(crate) fn from_user(client: &Client, account: &User) -> Option<Account> {
+        let name = account.get_name();
+
         match account {
-            User::Organization { .. } => Ok(Account::Organization({
-                HandleOrganization { client: client.clone(), name: name.to_lowercase() }
+            User::Organization { .. } => Some(Account::Organization({
+                HandleOrganization { client: client.clone(), name: name.to_lowercase().into() }
             })),
-            User::User { .. } => Ok(Account::User({
-                HandleUser { client: client.clone(), name: name.to_lowercase() }
+            User::User { .. } => Some(Account::User({
+                HandleUser { client: client.clone(), name: name.to_lowercase().into() }
             })),
-            _ => Err(AccountError::Unsupported { account }),
+            _ => None,
         }
     }
 
@@ -1,7 +1,49 @@
 use anyhow::{Result};
+use secrecy::{Secret};
 
 use super::{HandleOrganization};
-use crate::{GitHubProperties};
+
+use crate::{
+
+    models::common::actions::{
+
+        RunnerGroupVisibility,
+        WorkflowPermissions,
+        TokenPermission,
+        RunnerGroup,
+        Runner,
+    },
+
+    repository::{
+
+        secrets::{
+
+            try_parse_public_key,
+            try_seal,
+        },
+
+        HandleRepository,
+    },
+
+    account::{Account},
+    client::{Paginated},
+
+    GitHubProperties,
+};
+
+#[derive(Clone, Debug)]
+pub enum RunnerGroupAccess {
+    Selected(Vec<HandleRepository>),
+    Private,
+    All,
+}
+
+#[derive(Clone, Debug)]
+pub enum SecretAccess {
+    Selected(Vec<HandleRepository>),
+    Private,
+    All,
+}
 
 #[derive(Debug)]
 pub struct HandleActions {
@@ -162,6 +204,391 @@ impl HandleActions {
 
         Ok(verified)
     }
+
+    pub fn try_get_workflow_permissions(&self) -> Result<WorkflowPermissions> {
+        let HandleActions { organization, .. } = { self };
+
+        let response = {
+
+            organization.get_client()
+                .get(format!("orgs/{organization}/actions/permissions/workflow"))?
+                .send()?.json()?
+        };
+
+        Ok(response)
+    }
+
+    pub fn try_set_workflow_permissions(&self, ref permissions: WorkflowPermissions) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/permissions/workflow"))?
+                .json(permissions)
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_set_default_token_permission(&self, permission: TokenPermission) -> Result<&HandleActions> {
+        let permissions = self.try_get_workflow_permissions()?
+            .with_default(permission);
+
+        self.try_set_workflow_permissions(permissions)
+    }
+
+    pub fn try_set_allow_approve(&self, approve: bool) -> Result<&HandleActions> {
+        let permissions = self.try_get_workflow_permissions()?
+            .with_approve(approve);
+
+        self.try_set_workflow_permissions(permissions)
+    }
+
+    pub fn try_get_enabled_repositories(&self) -> Result<Vec<HandleRepository>> {
+        let HandleActions { organization, .. } = { self };
+
+        self.try_list_repositories(format!("orgs/{organization}/actions/permissions/repositories"))
+    }
+
+    pub fn try_set_enabled_repositories(&self, set: impl AsRef<[HandleRepository]>) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let mut list = Vec::new();
+
+        for repository in set.as_ref().iter() {
+            list.push(repository.try_get_number()?);
+        }
+
+        list.sort();
+        list.dedup();
+
+        let ref payload = serde_json::json!({
+            "selected_repository_ids": list,
+        });
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/permissions/repositories"))?
+                .json(payload)
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_add_enabled_repository(&self, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/permissions/repositories/{number}"))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_remove_enabled_repository(&self, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .delete(format!("orgs/{organization}/actions/permissions/repositories/{number}"))?
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_get_runner_groups(&self) -> Result<Vec<RunnerGroup>> {
+        let HandleActions { organization, .. } = { self };
+
+        Ok(Paginated::try_fetch_all(organization.get_client(), {
+            format!("orgs/{organization}/actions/runner-groups")
+        }, &[])?)
+    }
+
+    pub fn try_create_runner_group(&self, name: impl AsRef<str>, visibility: RunnerGroupVisibility, repositories: impl AsRef<[HandleRepository]>) -> Result<RunnerGroup> {
+        let HandleActions { organization, .. } = { self };
+
+        let mut list = Vec::new();
+
+        for repository in repositories.as_ref().iter() {
+            list.push(repository.try_get_number()?);
+        }
+
+        let ref payload = serde_json::json!({
+            "name": name.as_ref(),
+            "visibility": visibility,
+            "selected_repository_ids": list,
+        });
+
+        Ok(organization.get_client()
+            .post(format!("orgs/{organization}/actions/runner-groups"))?
+            .json(payload).send()?.json()?)
+    }
+
+    pub fn try_delete_runner_group(&self, group: &RunnerGroup) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let _ = {
+
+            organization.get_client()
+                .delete(format!("orgs/{organization}/actions/runner-groups/{number}", number = group.get_number()))?
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_get_runner_group_repositories(&self, group: &RunnerGroup) -> Result<Vec<HandleRepository>> {
+        let HandleActions { organization, .. } = { self };
+
+        self.try_list_repositories(format!("orgs/{organization}/actions/runner-groups/{number}/repositories", number = group.get_number()))
+    }
+
+    pub fn try_set_runner_group_repositories(&self, group: &RunnerGroup, set: impl AsRef<[HandleRepository]>) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let mut list = Vec::new();
+
+        for repository in set.as_ref().iter() {
+            list.push(repository.try_get_number()?);
+        }
+
+        list.sort();
+        list.dedup();
+
+        let ref payload = serde_json::json!({
+            "selected_repository_ids": list,
+        });
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/runner-groups/{number}/repositories", number = group.get_number()))?
+                .json(payload)
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_add_runner_group_repository(&self, group: &RunnerGroup, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/runner-groups/{group}/repositories/{number}", group = group.get_number()))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_remove_runner_group_repository(&self, group: &RunnerGroup, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .delete(format!("orgs/{organization}/actions/runner-groups/{group}/repositories/{number}", group = group.get_number()))?
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_get_runner_group_runners(&self, group: &RunnerGroup) -> Result<Vec<Runner>> {
+        let HandleActions { organization, .. } = { self };
+
+        Ok(Paginated::try_fetch_all(organization.get_client(), {
+            format!("orgs/{organization}/actions/runner-groups/{number}/runners", number = group.get_number())
+        }, &[])?)
+    }
+
+    pub fn try_move_runner(&self, runner: &Runner, group: &RunnerGroup) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/runner-groups/{group}/runners/{runner}", group = group.get_number(), runner = runner.get_number()))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_get_runner_group_access(&self) -> Result<Vec<(RunnerGroup, RunnerGroupAccess)>> {
+        let mut collection = Vec::new();
+
+        for group in self.try_get_runner_groups()? {
+            let access = match group.get_visibility() {
+                RunnerGroupVisibility::Selected => RunnerGroupAccess::Selected({
+                    self.try_get_runner_group_repositories(&(group))?
+                }),
+                RunnerGroupVisibility::Private => RunnerGroupAccess::Private,
+                RunnerGroupVisibility::All => RunnerGroupAccess::All,
+            };
+
+            collection.push((group, access));
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_create_secret(&self, name: impl AsRef<str>, value: &Secret<String>, access: SecretAccess) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        use model::{SecretsPublicKey};
+
+        let name = name.as_ref();
+
+        let SecretsPublicKey { key_id, key } = {
+            organization.get_client()
+                .get(format!("orgs/{organization}/actions/secrets/public-key"))?
+                .send()?.json()?
+        };
+
+        let public = try_parse_public_key(&(key_id), &(key))?;
+        let sealed = try_seal(&(public), name, value)?;
+
+        let ref payload = match access {
+            SecretAccess::Selected(repositories) => {
+                let mut list = Vec::new();
+
+                for repository in repositories.iter() {
+                    list.push(repository.try_get_number()?);
+                }
+
+                serde_json::json!({
+                    "encrypted_value": sealed,
+                    "key_id": key_id,
+                    "visibility": "selected",
+                    "selected_repository_ids": list,
+                })
+            },
+            SecretAccess::Private => serde_json::json!({
+                "encrypted_value": sealed,
+                "key_id": key_id,
+                "visibility": "private",
+            }),
+            SecretAccess::All => serde_json::json!({
+                "encrypted_value": sealed,
+                "key_id": key_id,
+                "visibility": "all",
+            }),
+        };
+
+        organization.get_client()
+            .put(format!("orgs/{organization}/actions/secrets/{name}"))?
+            .json(payload).send()?;
+
+        Ok(self)
+    }
+
+    pub fn try_delete_secret(&self, name: impl AsRef<str>) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let _ = {
+
+            organization.get_client()
+                .delete(format!("orgs/{organization}/actions/secrets/{name}", name = name.as_ref()))?
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_get_secret_repositories(&self, name: impl AsRef<str>) -> Result<Vec<HandleRepository>> {
+        let HandleActions { organization, .. } = { self };
+
+        self.try_list_repositories(format!("orgs/{organization}/actions/secrets/{name}/repositories", name = name.as_ref()))
+    }
+
+    pub fn try_set_secret_repositories(&self, name: impl AsRef<str>, set: impl AsRef<[HandleRepository]>) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let mut list = Vec::new();
+
+        for repository in set.as_ref().iter() {
+            list.push(repository.try_get_number()?);
+        }
+
+        let ref payload = serde_json::json!({
+            "selected_repository_ids": list,
+        });
+
+        organization.get_client()
+            .put(format!("orgs/{organization}/actions/secrets/{name}/repositories", name = name.as_ref()))?
+            .json(payload).send()?;
+
+        Ok(self)
+    }
+
+    pub fn try_add_secret_repository(&self, name: impl AsRef<str>, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .put(format!("orgs/{organization}/actions/secrets/{name}/repositories/{number}", name = name.as_ref()))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_remove_secret_repository(&self, name: impl AsRef<str>, repository: &HandleRepository) -> Result<&HandleActions> {
+        let HandleActions { organization, .. } = { self };
+
+        let number = repository.try_get_number()?;
+
+        let _ = {
+
+            organization.get_client()
+                .delete(format!("orgs/{organization}/actions/secrets/{name}/repositories/{number}", name = name.as_ref()))?
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    fn try_list_repositories(&self, endpoint: String) -> Result<Vec<HandleRepository>> {
+        let HandleActions { organization, .. } = { self };
+
+        use model::{EnabledRepository};
+
+        let repositories: Vec<EnabledRepository> = Paginated::try_fetch_all(organization.get_client(), endpoint, &[])?;
+
+        Ok(repositories.into_iter()
+            .map(|EnabledRepository { name }| HandleRepository {
+                owner: Account::Organization(organization.clone()),
+                name: name.to_lowercase().into(),
+            })
+            .collect())
+    }
 }
 
 mod model {
@@ -199,4 +626,17 @@ mod model {
             Ok(response)
         }
     }
+
+    #[derive(Clone, Debug)]
+    #[derive(Deserialize)]
+    pub struct EnabledRepository {
+        pub(super) name: String,
+    }
+
+    #[derive(Clone, Debug)]
+    #[derive(Deserialize)]
+    pub struct SecretsPublicKey {
+        pub(super) key_id: String,
+        pub(super) key: String,
+    }
 }
\ No newline at end of file
@@ -0,0 +1,225 @@
+use std::{
+
+    borrow::{Cow},
+    time::{Duration, Instant},
+    path::{PathBuf, Path},
+    thread::{self},
+    fs::{File},
+    io::{self},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use crate::{
+
+    client::{
+
+        ClientError,
+        Paginated,
+        Client,
+    },
+
+    models::common::migration::{
+
+        MigrationState,
+        Migration,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+use super::{HandleOrganization};
+
+#[derive(Error, Debug)]
+pub enum HandleMigrationError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Migration failed: '{migration}'")]
+    Failed { migration: String },
+    #[error("Migration timed out: '{migration}'")]
+    Timeout { migration: String },
+    #[error("Failed to write archive: '{path:?}'")]
+    Write { path: PathBuf },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleMigration {
+    pub(crate) organization: HandleOrganization,
+    pub(crate) number: Number,
+}
+
+impl HandleMigration {
+    pub(crate) fn try_fetch(organization: &HandleOrganization, number: Number) -> GitHubResult<HandleMigration, HandleMigrationError> {
+        let Migration { number, .. } = {
+            organization.get_client()
+                .get(format!("orgs/{organization}/migrations/{number}"))?
+                .send()?.json()?
+        };
+
+        Ok(HandleMigration {
+            organization: organization.clone(),
+            number,
+        })
+    }
+
+    pub(crate) fn try_fetch_all(organization: &HandleOrganization) -> GitHubResult<Vec<HandleMigration>, HandleMigrationError> {
+        let client = organization.get_client();
+
+        let collection: Vec<Migration> = Paginated::try_fetch_all(client, format!("orgs/{organization}/migrations"), &[])?;
+
+        Ok(collection.into_iter()
+            .map(|Migration { number, .. }| HandleMigration {
+                organization: organization.clone(),
+                number,
+            }).collect())
+    }
+
+    pub(crate) fn try_create(organization: &HandleOrganization, repositories: impl IntoIterator<Item = impl AsRef<str>>, lock: bool, exclude_attachments: bool) -> GitHubResult<HandleMigration, HandleMigrationError> {
+        let repositories: Vec<String> = repositories.into_iter()
+            .map(|repository| repository.as_ref().to_owned())
+            .collect();
+
+        let ref payload = serde_json::json!({
+            "repositories": repositories,
+            "lock_repositories": lock,
+            "exclude_attachments": exclude_attachments,
+        });
+
+        let Migration { number, .. } = {
+            organization.get_client()
+                .post(format!("orgs/{organization}/migrations"))?
+                .json(payload)
+                .send()?.json()?
+        };
+
+        Ok(HandleMigration {
+            organization: organization.clone(),
+            number,
+        })
+    }
+
+    pub fn try_get_migration(&self) -> GitHubResult<Migration, HandleMigrationError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    pub fn try_get_state(&self) -> GitHubResult<MigrationState, HandleMigrationError> {
+        let Migration { state, .. } = self.try_get_migration()?;
+        Ok(state)
+    }
+
+    pub fn try_wait(&self, interval: Duration, timeout: Duration) -> GitHubResult<(), HandleMigrationError> {
+        let started = Instant::now();
+
+        loop {
+
+            match self.try_get_state()? {
+                MigrationState::Exported => return Ok(()),
+                MigrationState::Failed => return Err(HandleMigrationError::Failed {
+                    migration: self.to_string()
+                }),
+                _ => (),
+            }
+
+            if started.elapsed() >= timeout {
+                return Err(HandleMigrationError::Timeout {
+                    migration: self.to_string()
+                })
+            }
+
+            thread::sleep(interval);
+        }
+    }
+
+    pub fn try_download(&self, path: impl AsRef<Path>) -> GitHubResult<(), HandleMigrationError> {
+        let path = path.as_ref();
+        let organization = self.get_parent();
+
+        let mut reader = {
+            self.get_client()
+                .get(format!("orgs/{organization}/migrations/{self}/archive"))?
+                .send_reader()?
+        };
+
+        let mut file = File::create(path).map_err(|_| {
+            HandleMigrationError::Write { path: path.to_owned() }
+        })?;
+
+        io::copy(&mut reader, &mut file).map_err(|_| {
+            HandleMigrationError::Write { path: path.to_owned() }
+        })?;
+
+        Ok(())
+    }
+
+    pub fn try_delete_archive(&self) -> GitHubResult<(), HandleMigrationError> {
+        let organization = self.get_parent();
+
+        self.get_client()
+            .delete(format!("orgs/{organization}/migrations/{self}/archive"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_unlock(&self, repository: impl AsRef<str>) -> GitHubResult<(), HandleMigrationError> {
+        let organization = self.get_parent();
+        let repository = repository.as_ref();
+        let repository = repository.rsplit('/')
+            .next()
+            .unwrap_or(repository);
+
+        self.get_client()
+            .delete(format!("orgs/{organization}/migrations/{self}/repos/{repository}/lock"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_unlock_all(&self) -> GitHubResult<(), HandleMigrationError> {
+        for repository in self.try_get_migration()?.get_repositories() {
+            self.try_unlock(repository)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleMigration {
+    type Content = Migration;
+    type Parent = HandleOrganization;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.organization.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.organization)
+    }
+
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleMigration { organization, number } = { self };
+        format!("orgs/{organization}/migrations/{number}").into()
+    }
+}
+
+impl FmtDisplay for HandleMigration {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let HandleMigration { number, .. } = { self };
+        write!(fmt, "{number}")
+    }
+}
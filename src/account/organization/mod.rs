@@ -1,40 +1,99 @@
 use std::{
 
     borrow::{Cow}, 
+    sync::{Arc},
 
     fmt::{
 
         Formatter as FmtFormatter,
         Display as FmtDisplay,
         Result as FmtResult,
+        Debug as FmtDebug,
     }, 
 };
 
 use thiserror::{Error};
 
-use serde::{Deserialize};
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+};
 
 use crate::{
     
-    repository::{HandleRepositoryError},
+    repository::{
+
+        discussion::{CapsulePageInfo},
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
 
     client::{
 
         ClientError,
+        Paginated,
         Client, 
+
+        media,
     },
 
-    models::common::user::{User},
+    models::common::{
+
+        organization::{
+
+            OrganizationDomain,
+            Organization,
+        },
+
+        token::{
+
+            TokenRequest,
+            TokenReview,
+            TokenGrant,
+        },
+
+        scanning::{
+
+            BypassRequestStatus,
+            BypassTimePeriod,
+            BypassRequest,
+        },
+
+        allowlist::{IpAllowListEntry},
+        search::{CodeSearchMatch},
+        ruleset::{Ruleset},
+        user::{User},
+    },
     
     GitHubProperties,
     GitHubResult,
+    Number,
 };
 
+pub mod repositories;
+pub mod migration;
 pub mod actions;
 pub mod team;
 
 use self::{actions::{HandleActions}, team::{HandleTeamError, HandleTeam}};
 
+use self::migration::{
+
+    HandleMigrationError,
+    HandleMigration,
+};
+
+use self::repositories::{
+
+    RepositoryIterator,
+    RepositoryCursor,
+    RepositoryFilter,
+};
+
+pub const CODE_SEARCH_RESOURCE: &str = "code_search";
 
 #[derive(Error, Debug)]
 pub enum HandleOrganizationError {
@@ -44,6 +103,10 @@ pub enum HandleOrganizationError {
     Team(#[from] HandleTeamError),
     #[error("Repository error!")]
     Repository(#[from] HandleRepositoryError),
+    #[error("Migration error!")]
+    Migration(#[from] HandleMigrationError),
+    #[error("Invalid pattern: '{pattern}'")]
+    Pattern { pattern: String },
     #[error("Not an organization, got: '{account:?}'")]
     Organization { account: User },
 }
@@ -51,7 +114,7 @@ pub enum HandleOrganizationError {
 #[derive(Clone, Debug)]
 pub struct HandleOrganization {
     pub(crate) client: Client,
-    pub(crate) name: String,
+    pub(crate) name: Arc<str>,
 }
 
 impl HandleOrganization {
@@ -70,6 +133,82 @@ impl HandleOrganization {
         Ok(is_verified)
     }
 
+    pub fn try_get_organization(&self) -> GitHubResult<Organization, HandleOrganizationError> {
+        Ok(self.client.get(format!("orgs/{self}"))?
+            .send()?.json()?)
+    }
+
+    pub fn try_get_domains(&self) -> GitHubResult<Vec<OrganizationDomain>, HandleOrganizationError> {
+        const QUERY: &str = r#"
+            query($login: String!, $cursor: String) {
+                organization(login: $login) {
+                    domains(first: 100, after: $cursor) {
+                        pageInfo { hasNextPage endCursor }
+                        nodes { domain isVerified isApproved }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleDomains {
+            #[serde(rename = "pageInfo")]
+            page: CapsulePageInfo,
+            nodes: Vec<OrganizationDomain>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleOrganization {
+            domains: CapsuleDomains,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            organization: CapsuleOrganization,
+        }
+
+        let mut collection = Vec::new();
+        let mut cursor = None;
+
+        loop {
+
+            let Capsule { organization: CapsuleOrganization { domains } } = {
+
+                let ref variables = serde_json::json!({
+                    "login": self.name.as_ref(),
+                    "cursor": cursor,
+                });
+
+                self.client.try_query(QUERY, variables)?
+            };
+
+            let CapsuleDomains { page, nodes } = {
+                domains
+            };
+
+            collection.extend(nodes);
+
+            match page {
+                CapsulePageInfo { next: true, cursor: Some(next) } => {
+                    cursor = Some(next)
+                },
+                _ => break,
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_verified_domains(&self) -> GitHubResult<Vec<String>, HandleOrganizationError> {
+        Ok(self.try_get_domains()?.into_iter()
+            .filter(OrganizationDomain::is_verified)
+            .map(|OrganizationDomain { domain, .. }| domain)
+            .collect())
+    }
+
     pub fn try_get_team(&self, slug: impl AsRef<str>) -> GitHubResult<HandleTeam, HandleOrganizationError> {
         Ok(HandleTeam::try_fetch(self, slug.as_ref())?)
     }
@@ -78,13 +217,296 @@ impl HandleOrganization {
         Ok(HandleTeam::try_fetch_all(self)?)
     }
 
+    pub fn try_get_migration(&self, number: Number) -> GitHubResult<HandleMigration, HandleOrganizationError> {
+        Ok(HandleMigration::try_fetch(self, number)?)
+    }
+
+    pub fn try_get_all_migrations(&self) -> GitHubResult<Vec<HandleMigration>, HandleOrganizationError> {
+        Ok(HandleMigration::try_fetch_all(self)?)
+    }
+
+    pub fn try_start_migration(&self, repositories: impl IntoIterator<Item = impl AsRef<str>>, lock: bool, exclude_attachments: bool) -> GitHubResult<HandleMigration, HandleOrganizationError> {
+        Ok(HandleMigration::try_create(self, repositories, lock, exclude_attachments)?)
+    }
+
+    pub fn try_iter_repositories(&self, filter: RepositoryFilter) -> GitHubResult<RepositoryIterator, HandleOrganizationError> {
+        RepositoryIterator::try_new(self, filter, Default::default())
+    }
+
+    pub fn try_iter_repositories_from(&self, filter: RepositoryFilter, cursor: RepositoryCursor) -> GitHubResult<RepositoryIterator, HandleOrganizationError> {
+        RepositoryIterator::try_new(self, filter, cursor)
+    }
+
+    pub fn try_search_code(&self, query: impl AsRef<str>) -> GitHubResult<Vec<CodeSearchMatch>, HandleOrganizationError> {
+        let query = format!("{query} org:{self}", query = query.as_ref());
+
+        Ok(Paginated::try_fetch_all_paced(&(self.client), "search/code", &[("q", query)], media::TEXT_MATCH, Some(CODE_SEARCH_RESOURCE))?)
+    }
+
+    pub fn try_get_rulesets(&self) -> GitHubResult<Vec<Ruleset>, HandleOrganizationError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            id: Number,
+        }
+
+        let capsules: Vec<Capsule> = Paginated::try_fetch_all(&(self.client), format!("orgs/{self}/rulesets"), &[])?;
+
+        let mut collection = Vec::new();
+        for Capsule { id } in capsules.iter() {
+            collection.push(self.client.get(format!("orgs/{self}/rulesets/{id}"))?
+                .send()?.json()?);
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_required_workflow_rulesets(&self) -> GitHubResult<Vec<Ruleset>, HandleOrganizationError> {
+        Ok(self.try_get_rulesets()?.into_iter()
+            .filter(|ruleset| !(ruleset.get_required_workflows().is_empty()))
+            .collect())
+    }
+
+    pub fn try_find_uncovered_repositories(&self, path: impl AsRef<str>) -> GitHubResult<Vec<HandleRepository>, HandleOrganizationError> {
+        let path = path.as_ref();
+        let rulesets = self.try_get_required_workflow_rulesets()?;
+
+        let mut collection = Vec::new();
+
+        for repository in self.try_iter_repositories(RepositoryFilter::new().with_archived(false))? {
+            let repository = repository?;
+
+            if !(rulesets.iter().any(|ruleset| ruleset.requires(repository.name.as_ref(), path))) {
+                collection.push(repository);
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_ip_allow_list(&self) -> GitHubResult<Vec<IpAllowListEntry>, HandleOrganizationError> {
+        const QUERY: &str = r#"
+            query($login: String!, $cursor: String) {
+                organization(login: $login) {
+                    ipAllowListEntries(first: 100, after: $cursor) {
+                        pageInfo { hasNextPage endCursor }
+                        nodes { id allowListValue name isActive createdAt updatedAt }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleEntries {
+            #[serde(rename = "pageInfo")]
+            page: CapsulePageInfo,
+            nodes: Vec<IpAllowListEntry>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleOrganization {
+            #[serde(rename = "ipAllowListEntries")]
+            entries: CapsuleEntries,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            organization: CapsuleOrganization,
+        }
+
+        let mut collection = Vec::new();
+        let mut cursor = None;
+
+        loop {
+
+            let Capsule { organization: CapsuleOrganization { entries } } = {
+
+                let ref variables = serde_json::json!({
+                    "login": self.name.as_ref(),
+                    "cursor": cursor,
+                });
+
+                self.client.try_query(QUERY, variables)?
+            };
+
+            let CapsuleEntries { page, nodes } = {
+                entries
+            };
+
+            collection.extend(nodes);
+
+            match page {
+                CapsulePageInfo { next: true, cursor: Some(next) } => {
+                    cursor = Some(next)
+                },
+                _ => break,
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_create_ip_allow_list_entry(&self, value: impl AsRef<str>, name: Option<&str>, active: bool) -> GitHubResult<IpAllowListEntry, HandleOrganizationError> {
+        const QUERY: &str = r#"
+            mutation($owner: ID!, $value: String!, $name: String, $active: Boolean!) {
+                createIpAllowListEntry(input: { ownerId: $owner, allowListValue: $value, name: $name, isActive: $active }) {
+                    ipAllowListEntry { id allowListValue name isActive createdAt updatedAt }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleCreate {
+            #[serde(rename = "ipAllowListEntry")]
+            entry: IpAllowListEntry,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(rename = "createIpAllowListEntry")]
+            create: CapsuleCreate,
+        }
+
+        let Capsule { create: CapsuleCreate { entry } } = {
+
+            let ref variables = serde_json::json!({
+                "owner": self.try_get_node_id()?,
+                "value": value.as_ref(),
+                "name": name,
+                "active": active,
+            });
+
+            self.client.try_query(QUERY, variables)?
+        };
+
+        Ok(entry)
+    }
+
+    pub fn try_delete_ip_allow_list_entry(&self, entry: &IpAllowListEntry) -> GitHubResult<(), HandleOrganizationError> {
+        const QUERY: &str = r#"
+            mutation($entry: ID!) {
+                deleteIpAllowListEntry(input: { ipAllowListEntryId: $entry }) {
+                    ipAllowListEntry { id }
+                }
+            }
+        "#;
+
+        let ref variables = serde_json::json!({
+            "entry": entry.get_id(),
+        });
+
+        let _: serde_json::Value = {
+            self.client.try_query(QUERY, variables)?
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn try_get_node_id(&self) -> GitHubResult<String, ClientError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            node_id: String,
+        }
+
+        let Capsule { node_id } = {
+
+            self.client.get(format!("orgs/{self}"))?
+                .send()?
+                .json()?
+        };
+
+        Ok(node_id)
+    }
+
+    fn try_list_paginated<T>(&self, endpoint: &str) -> GitHubResult<Vec<T>, HandleOrganizationError>
+    where T: DeserializeOwned + FmtDebug {
+        Ok(Paginated::try_fetch_all(&(self.client), endpoint, &[])?)
+    }
+
+    pub fn try_get_token_requests(&self) -> GitHubResult<Vec<TokenRequest>, HandleOrganizationError> {
+        self.try_list_paginated(format!("orgs/{self}/personal-access-token-requests").as_str())
+    }
+
+    pub fn try_review_token_request(&self, request: &TokenRequest, review: TokenReview, reason: Option<&str>) -> GitHubResult<(), HandleOrganizationError> {
+        let ref payload = serde_json::json!({
+            "action": review,
+            "reason": reason,
+        });
+
+        self.client.post(format!("orgs/{self}/personal-access-token-requests/{number}", number = request.get_number()))?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_review_token_requests(&self, requests: impl AsRef<[TokenRequest]>, review: TokenReview, reason: Option<&str>) -> GitHubResult<(), HandleOrganizationError> {
+        let numbers: Vec<Number> = requests.as_ref().iter()
+            .map(TokenRequest::get_number)
+            .collect();
+
+        if numbers.is_empty() {
+            return Ok(())
+        }
+
+        let ref payload = serde_json::json!({
+            "pat_request_ids": numbers,
+            "action": review,
+            "reason": reason,
+        });
+
+        self.client.post(format!("orgs/{self}/personal-access-token-requests"))?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_token_grants(&self) -> GitHubResult<Vec<TokenGrant>, HandleOrganizationError> {
+        self.try_list_paginated(format!("orgs/{self}/personal-access-tokens").as_str())
+    }
+
+    pub fn try_revoke_token_grant(&self, grant: &TokenGrant) -> GitHubResult<(), HandleOrganizationError> {
+        let ref payload = serde_json::json!({
+            "action": "revoke",
+        });
+
+        self.client.post(format!("orgs/{self}/personal-access-tokens/{number}", number = grant.get_number()))?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_bypass_requests(&self, status: Option<BypassRequestStatus>, period: BypassTimePeriod) -> GitHubResult<Vec<BypassRequest>, HandleOrganizationError> {
+        let status = match status.map(serde_json::to_value) {
+            Some(Ok(serde_json::Value::String(status))) => status,
+            _ => "all".to_owned(),
+        };
+
+        let period = match serde_json::to_value(period) {
+            Ok(serde_json::Value::String(period)) => period,
+            _ => "month".to_owned(),
+        };
+
+        Ok(Paginated::try_fetch_all(&(self.client), {
+            format!("orgs/{self}/bypass-requests/secret-scanning")
+        }, &[("request_status", status), ("time_period", period)])?)
+    }
+
     pub fn get_actions(&self) -> HandleActions {
         HandleActions::from(self)
     }
 }
 
 impl<'a> GitHubProperties<'a> for HandleOrganization {
-    type Content = User;
+    type Content = Organization;
     type Parent = Client;
     
     fn get_client(&'a self) -> &'a Client {
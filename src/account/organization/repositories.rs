@@ -0,0 +1,276 @@
+use glob::{Pattern};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    account::{
+
+        organization::{
+
+            HandleOrganizationError,
+            HandleOrganization,
+        },
+
+        Account,
+    },
+
+    client::{
+
+        paginated::{DEFAULT_PER_PAGE},
+
+        Paginated,
+    },
+
+    repository::{HandleRepository},
+    common::{Date},
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct RepositoryFilter {
+    pattern: Option<Pattern>,
+    language: Option<String>,
+    topics: Vec<String>,
+    visibility: Option<String>,
+    archived: Option<bool>,
+    fork: Option<bool>,
+    pushed_after: Option<Date>,
+}
+
+impl RepositoryFilter {
+    pub fn new() -> RepositoryFilter {
+        Default::default()
+    }
+
+    pub fn with_pattern(mut self, pattern: impl AsRef<str>) -> GitHubResult<RepositoryFilter, HandleOrganizationError> {
+        let pattern = pattern.as_ref();
+
+        self.pattern = Some(Pattern::new(pattern).map_err(|_| {
+            HandleOrganizationError::Pattern { pattern: pattern.to_owned() }
+        })?);
+
+        Ok(self)
+    }
+
+    pub fn with_language(mut self, language: impl AsRef<str>) -> RepositoryFilter {
+        self.language = Some(language.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_topic(mut self, topic: impl AsRef<str>) -> RepositoryFilter {
+        self.topics.push(topic.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_visibility(mut self, visibility: impl AsRef<str>) -> RepositoryFilter {
+        self.visibility = Some(visibility.as_ref().to_lowercase());
+        self
+    }
+
+    pub fn with_archived(mut self, archived: bool) -> RepositoryFilter {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn with_fork(mut self, fork: bool) -> RepositoryFilter {
+        self.fork = Some(fork);
+        self
+    }
+
+    pub fn with_pushed_after(mut self, date: Date) -> RepositoryFilter {
+        self.pushed_after = Some(date);
+        self
+    }
+
+    fn matches(&self, capsule: &Capsule) -> bool {
+        let RepositoryFilter { pattern, language, topics, visibility, archived, fork, pushed_after } = { self };
+
+        pattern.as_ref().map_or(true, |pattern| pattern.matches(capsule.name.as_str())) &&
+        language.as_ref().map_or(true, |language| capsule.language.as_ref().map_or(false, |candidate| candidate.eq_ignore_ascii_case(language))) &&
+        topics.iter().all(|topic| capsule.topics.iter().any(|candidate| candidate.eq_ignore_ascii_case(topic))) &&
+        visibility.as_ref().map_or(true, |visibility| capsule.visibility.as_ref() == Some(visibility)) &&
+        archived.map_or(true, |archived| capsule.archived == archived) &&
+        fork.map_or(true, |fork| capsule.fork == fork) &&
+        pushed_after.map_or(true, |date| capsule.pushed_at.map_or(false, |pushed| pushed >= date))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct RepositoryCursor {
+    page: usize,
+    offset: usize,
+}
+
+impl Default for RepositoryCursor {
+    fn default() -> RepositoryCursor {
+        RepositoryCursor { page: 1, offset: 0 }
+    }
+}
+
+impl RepositoryCursor {
+    pub fn get_page(&self) -> usize {
+        self.page
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize)]
+struct Capsule {
+    name: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+    #[serde(default)]
+    visibility: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    fork: bool,
+    #[serde(default)]
+    pushed_at: Option<Date>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RepositoryIterator {
+    organization: HandleOrganization,
+    filter: RepositoryFilter,
+    cursor: RepositoryCursor,
+    buffer: Option<Paginated<Capsule>>,
+    exhausted: bool,
+}
+
+impl RepositoryIterator {
+    pub(crate) fn try_new(organization: &HandleOrganization, filter: RepositoryFilter, cursor: RepositoryCursor) -> GitHubResult<RepositoryIterator, HandleOrganizationError> {
+        let mut iterator = RepositoryIterator {
+            organization: organization.clone(),
+            filter,
+            cursor,
+            buffer: None,
+            exhausted: false,
+        };
+
+        iterator.try_fill()?;
+
+        Ok(iterator)
+    }
+
+    fn try_fill(&mut self) -> GitHubResult<(), HandleOrganizationError> {
+        let RepositoryIterator { organization, cursor, .. } = { &(*self) };
+
+        let ref query = [
+            ("sort", "created".to_owned()),
+            ("direction", "asc".to_owned()),
+        ];
+
+        let paginated = Paginated::try_fetch(organization.get_client(), format!("orgs/{organization}/repos"), query, cursor.page, DEFAULT_PER_PAGE)?;
+
+        self.buffer = Some(paginated);
+
+        Ok(())
+    }
+
+    pub fn get_cursor(&self) -> RepositoryCursor {
+        self.cursor
+    }
+}
+
+impl Iterator for RepositoryIterator {
+    type Item = GitHubResult<HandleRepository, HandleOrganizationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+
+            if self.exhausted {
+                return None
+            }
+
+            if self.buffer.is_none() {
+                if let Err(error) = self.try_fill() {
+                    self.exhausted = true;
+                    return Some(Err(error))
+                }
+            }
+
+            let paginated = self.buffer.as_ref()?;
+            let capsules = paginated.get_items();
+
+            if self.cursor.offset >= capsules.len() {
+                if paginated.is_last() {
+                    self.exhausted = true;
+                    return None
+                }
+
+                self.cursor = RepositoryCursor { page: self.cursor.page + 1, offset: 0 };
+                self.buffer = None;
+
+                continue
+            }
+
+            let capsule = capsules[self.cursor.offset]
+                .clone();
+
+            self.cursor.offset = { self.cursor.offset + 1 };
+
+            if self.filter.matches(&(capsule)) {
+                return Some(Ok(HandleRepository {
+                    owner: Account::Organization(self.organization.clone()),
+                    name: capsule.name.to_lowercase().into(),
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockResponse,
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_stops_after_error() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "orgs/dev-bio/repos", MockResponse::json(200, serde_json::json!([
+                { "name": "octo" },
+            ])).with_header("link", "<https://api.github.com/organizations/1/repos?page=2>; rel=\"next\""))
+            .with_response(Method::GET, "orgs/dev-bio/repos", MockResponse::json(500, serde_json::json!({
+                "message": "Server Error",
+            })));
+
+        let client = fixtures::client(&(transport));
+
+        let organization = HandleOrganization { client, name: "dev-bio".into() };
+        let results: Vec<_> = RepositoryIterator::try_new(&(organization), RepositoryFilter::new(), Default::default())
+            .unwrap()
+            .collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
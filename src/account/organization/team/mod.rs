@@ -1,5 +1,6 @@
 use std::{
 
+    collections::{HashSet},
     borrow::{Cow},
 
     fmt::{
@@ -13,7 +14,12 @@ use std::{
 
 use thiserror::{Error};
 
-use serde::de::{DeserializeOwned};
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+};
 
 use crate::{
     
@@ -22,6 +28,7 @@ use crate::{
     client::{
 
         ClientError,
+        Paginated,
         Client, 
     },
 
@@ -71,40 +78,86 @@ impl HandleTeam {
     }
 
     pub(crate) fn try_fetch_all(organization: &HandleOrganization) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
-        let client = organization.get_client();
-        
-        let mut collection = Vec::new();
-        let mut page = 0;
-        
+        let collection: Vec<Team> = Paginated::try_fetch_all(organization.get_client(), format!("orgs/{organization}/teams"), &[])?;
 
-        loop {
+        Ok(collection.into_iter()
+            .map(|Team { slug, .. }| HandleTeam { 
+                organization: organization.clone(),
+                slug,
+            }).collect())
+    }
 
-            page = { page + 1 };
+    pub fn try_get_parent(&self) -> GitHubResult<Option<HandleTeam>, HandleTeamError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleParent {
+            slug: String,
+        }
 
-            let capsules: Vec<Team> = {
-                let ref query = [
-                    ("per_page", 100),
-                    ("page", page),
-                ];
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(default)]
+            parent: Option<CapsuleParent>,
+        }
 
-                client.get(format!("orgs/{organization}/teams"))?
-                    .query(query).send()?.json()?
-            };
+        let Capsule { parent } = {
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
 
-            collection.extend_from_slice({
-                capsules.as_slice()
-            });
+        Ok(parent.map(|CapsuleParent { slug }| HandleTeam {
+            organization: self.organization.clone(),
+            slug,
+        }))
+    }
 
-            if capsules.len() < 100 {
-                break
-            }
+    pub fn try_list_children(&self) -> GitHubResult<Vec<HandleTeam>, HandleTeamError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            slug: String,
         }
 
-        Ok(collection.into_iter()
-            .map(|Team { slug, .. }| HandleTeam { 
+        let organization = self.get_parent();
+        let client = self.get_client();
+
+        let capsules: Vec<Capsule> = Paginated::try_fetch_all(client, format!("orgs/{organization}/teams/{self}/teams"), &[])?;
+
+        Ok(capsules.into_iter()
+            .map(|Capsule { slug }| HandleTeam {
                 organization: organization.clone(),
                 slug,
-            }).collect())
+            })
+            .collect())
+    }
+
+    pub fn try_flatten_members(&self) -> GitHubResult<Vec<User>, HandleTeamError> {
+        let mut visited = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut members = Vec::new();
+        let mut pending = vec![self.clone()];
+
+        while let Some(team) = pending.pop() {
+            if !(visited.insert(team.slug.clone())) {
+                continue
+            }
+
+            let users: Vec<User> = {
+                team.try_get_team_members()?
+            };
+
+            for user in users {
+                if seen.insert(user.get_number()) {
+                    members.push(user);
+                }
+            }
+
+            pending.extend(team.try_list_children()?);
+        }
+
+        Ok(members)
     }
 
     pub fn try_has_team_member<T>(&self, ref member: T) -> GitHubResult<bool, HandleTeamError>
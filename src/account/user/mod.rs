@@ -1,6 +1,8 @@
 use std::{
     
     borrow::{Cow}, 
+    sync::{Arc},
+    io::{Write},
 
     fmt::{
 
@@ -12,6 +14,8 @@ use std::{
 
 use thiserror::{Error};
 
+use serde::{Deserialize};
+
 use crate::{
 
     repository::{
@@ -21,13 +25,17 @@ use crate::{
 
     client::{
 
+        ClientResponseError,
         ClientError,
         Client, 
+
+        media,
     }, 
 
     models::common::user::{User},
     
     GitHubProperties,
+    GitHubResult,
 };
 
 #[derive(Error, Debug)]
@@ -38,12 +46,14 @@ pub enum HandleUserError {
     Repository(#[from] HandleRepositoryError),
     #[error("Not a user, got: '{account:?}'")]
     User { account: User },
+    #[error("Failed to stream avatar: '{name}', reason: '{reason}'")]
+    Avatar { name: String, reason: String },
 }
 
 #[derive(Clone, Debug)]
 pub struct HandleUser {
     pub(crate) client: Client,
-    pub(crate) name: String,
+    pub(crate) name: Arc<str>,
 }
 
 impl<'a> GitHubProperties<'a> for HandleUser {
@@ -63,6 +73,47 @@ impl<'a> GitHubProperties<'a> for HandleUser {
     }
 }
 
+impl HandleUser {
+    pub fn try_get_avatar(&self, writer: &mut impl Write) -> GitHubResult<u64, HandleUserError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            avatar_url: String,
+        }
+
+        let Capsule { avatar_url } = {
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
+
+        let mut reader = {
+            self.get_client()
+                .get(avatar_url)?
+                .send_reader()?
+        };
+
+        std::io::copy(&mut reader, writer).map_err(|error| {
+            HandleUserError::Avatar { name: self.to_string(), reason: error.to_string() }
+        })
+    }
+
+    pub fn try_get_profile_readme(&self) -> GitHubResult<Option<String>, HandleUserError> {
+        let result = {
+            self.get_client()
+                .get(format!("repos/{self}/{self}/readme"))?
+                .accept(media::RAW)
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => Ok(Some(response.text()?)),
+        }
+    }
+}
+
 impl FmtDisplay for HandleUser {
     fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
         let HandleUser { name, .. } = { self };
@@ -0,0 +1,242 @@
+use std::{
+
+    path::{PathBuf, Path},
+    io::{Write},
+    env::{self},
+    fs::{self},
+};
+
+use thiserror::{Error};
+use serde::{Serialize};
+
+use crate::{GitHubResult};
+
+#[derive(Error, Debug)]
+pub enum CommandError {
+    #[error("Failed to serialize problem matcher: '{owner}'")]
+    Serialize { owner: String },
+    #[error("Failed to write problem matcher: '{path:?}'")]
+    Write { path: PathBuf },
+    #[error("Failed to emit workflow command!")]
+    Emit,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProblemMatcherSeverity {
+    Error,
+    Warning,
+    Notice,
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize)]
+pub struct ProblemPattern {
+    regexp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<usize>,
+    #[serde(rename = "loop")]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    repeat: bool,
+}
+
+impl ProblemPattern {
+    pub fn new(regexp: impl AsRef<str>) -> ProblemPattern {
+        ProblemPattern {
+            regexp: regexp.as_ref().to_owned(),
+            .. Default::default()
+        }
+    }
+
+    pub fn with_file(mut self, group: usize) -> ProblemPattern {
+        self.file = Some(group);
+        self
+    }
+
+    pub fn with_line(mut self, group: usize) -> ProblemPattern {
+        self.line = Some(group);
+        self
+    }
+
+    pub fn with_column(mut self, group: usize) -> ProblemPattern {
+        self.column = Some(group);
+        self
+    }
+
+    pub fn with_severity(mut self, group: usize) -> ProblemPattern {
+        self.severity = Some(group);
+        self
+    }
+
+    pub fn with_code(mut self, group: usize) -> ProblemPattern {
+        self.code = Some(group);
+        self
+    }
+
+    pub fn with_message(mut self, group: usize) -> ProblemPattern {
+        self.message = Some(group);
+        self
+    }
+
+    pub fn with_loop(mut self, repeat: bool) -> ProblemPattern {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn get_regexp(&self) -> String {
+        self.regexp.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize)]
+pub struct ProblemMatcher {
+    owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<ProblemMatcherSeverity>,
+    pattern: Vec<ProblemPattern>,
+}
+
+impl ProblemMatcher {
+    pub fn new(owner: impl AsRef<str>) -> ProblemMatcher {
+        ProblemMatcher {
+            owner: owner.as_ref().to_owned(),
+            severity: None,
+            pattern: Vec::new(),
+        }
+    }
+
+    pub fn with_severity(mut self, severity: ProblemMatcherSeverity) -> ProblemMatcher {
+        self.severity = Some(severity);
+        self
+    }
+
+    pub fn with_pattern(mut self, pattern: ProblemPattern) -> ProblemMatcher {
+        self.pattern.push(pattern);
+        self
+    }
+
+    pub fn get_owner(&self) -> String {
+        self.owner.clone()
+    }
+
+    pub fn get_patterns(&self) -> Vec<ProblemPattern> {
+        self.pattern.clone()
+    }
+
+    pub fn to_json(&self) -> GitHubResult<String, CommandError> {
+        let ref document = serde_json::json!({
+            "problemMatcher": [self],
+        });
+
+        serde_json::to_string_pretty(document).map_err(|_| CommandError::Serialize {
+            owner: self.owner.clone(),
+        })
+    }
+
+    pub fn try_write(&self, directory: impl AsRef<Path>) -> GitHubResult<PathBuf, CommandError> {
+        let path = directory.as_ref()
+            .join(format!("{owner}-matcher.json", owner = escape_file(self.owner.as_str())));
+
+        fs::write(path.as_path(), self.to_json()?).map_err(|_| {
+            CommandError::Write { path: path.clone() }
+        })?;
+
+        Ok(path)
+    }
+
+    pub fn try_register(&self) -> GitHubResult<PathBuf, CommandError> {
+        let directory = env::var_os("RUNNER_TEMP")
+            .map(PathBuf::from)
+            .unwrap_or_else(env::temp_dir);
+
+        let path = self.try_write(directory)?;
+
+        emit(add_matcher_command(path.as_path()))?;
+
+        Ok(path)
+    }
+
+    pub fn try_unregister(&self) -> GitHubResult<(), CommandError> {
+        emit(remove_matcher_command(self.owner.as_str()))
+    }
+}
+
+pub fn add_matcher_command(path: impl AsRef<Path>) -> String {
+    format!("::add-matcher::{path}", path = escape_data(path.as_ref().to_string_lossy()))
+}
+
+pub fn remove_matcher_command(owner: impl AsRef<str>) -> String {
+    format!("::remove-matcher owner={owner}::", owner = escape_property(owner))
+}
+
+pub fn escape_data(value: impl AsRef<str>) -> String {
+    value.as_ref()
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+pub fn escape_property(value: impl AsRef<str>) -> String {
+    escape_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+fn escape_file(value: &str) -> String {
+    value.chars().map(|character| match character {
+        character if character.is_alphanumeric() || character == '-' || character == '_' => character,
+        _ => '-',
+    }).collect()
+}
+
+fn emit(command: String) -> GitHubResult<(), CommandError> {
+    let mut stdout = std::io::stdout()
+        .lock();
+
+    writeln!(stdout, "{command}").map_err(|_| {
+        CommandError::Emit
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_problem_matcher() {
+        let matcher = ProblemMatcher::new("rustc")
+            .with_severity(ProblemMatcherSeverity::Warning)
+            .with_pattern(ProblemPattern::new("^(warning|error): (.*)$").with_severity(1).with_message(2))
+            .with_pattern(ProblemPattern::new("^\\s+--> (.*):(\\d+):(\\d+)$").with_file(1).with_line(2).with_column(3));
+
+        let document: serde_json::Value = serde_json::from_str(matcher.to_json().unwrap().as_str())
+            .unwrap();
+
+        assert_eq!(document, serde_json::json!({
+            "problemMatcher": [{
+                "owner": "rustc",
+                "severity": "warning",
+                "pattern": [
+                    { "regexp": "^(warning|error): (.*)$", "severity": 1, "message": 2 },
+                    { "regexp": "^\\s+--> (.*):(\\d+):(\\d+)$", "file": 1, "line": 2, "column": 3 },
+                ],
+            }],
+        }));
+
+        assert_eq!(add_matcher_command("/tmp/rustc-matcher.json"), "::add-matcher::/tmp/rustc-matcher.json");
+        assert_eq!(remove_matcher_command("rust:c"), "::remove-matcher owner=rust%3Ac::");
+    }
+}
@@ -0,0 +1,8 @@
+pub mod command;
+pub use command::{
+
+    ProblemMatcherSeverity,
+    ProblemPattern,
+    ProblemMatcher,
+    CommandError,
+};
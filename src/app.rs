@@ -0,0 +1,188 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use thiserror::{Error};
+
+use serde::{Deserialize};
+
+use secrecy::{Secret};
+
+use crate::{
+
+    client::{
+
+        ClientError,
+        Client,
+    },
+
+    models::common::{
+
+        app::{
+
+            InstallationTokenScope,
+            AppManifestConversion,
+            InstallationToken,
+            WebhookConfig,
+            HookDelivery,
+        },
+
+        user::{User},
+    },
+
+    common::{Date},
+
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum HandleAppError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleApp {
+    pub(crate) client: Client,
+}
+
+impl HandleApp {
+    pub(crate) fn from(client: &Client) -> HandleApp {
+        HandleApp { client: client.clone() }
+    }
+
+    pub fn try_get_webhook_config(&self) -> GitHubResult<WebhookConfig, HandleAppError> {
+        Ok(self.client.get("app/hook/config")?
+            .send()?.json()?)
+    }
+
+    pub fn try_set_webhook_config(&self, ref config: WebhookConfig) -> GitHubResult<WebhookConfig, HandleAppError> {
+        Ok(self.client.patch("app/hook/config")?
+            .json(config).send()?.json()?)
+    }
+
+    pub fn try_list_deliveries(&self) -> GitHubResult<Vec<HookDelivery>, HandleAppError> {
+        let mut collection = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+
+            let response = {
+                let mut query = vec![("per_page", "100".to_owned())];
+
+                if let Some(cursor) = cursor.take() {
+                    query.push(("cursor", cursor));
+                }
+
+                self.client.get("app/hook/deliveries")?
+                    .query(&(query)).send()?
+            };
+
+            cursor = response.get_links()
+                .get_next_query("cursor");
+
+            let deliveries: Vec<HookDelivery> = response.json()?;
+
+            collection.extend(deliveries);
+
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_delivery(&self, number: Number) -> GitHubResult<HookDelivery, HandleAppError> {
+        Ok(self.client.get(format!("app/hook/deliveries/{number}"))?
+            .send()?.json()?)
+    }
+
+    pub fn try_redeliver(&self, delivery: &HookDelivery) -> GitHubResult<(), HandleAppError> {
+        let _ = {
+
+            self.client.post(format!("app/hook/deliveries/{number}/attempts", number = delivery.get_number()))?
+                .header("content-length", "0")
+                .send()?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_convert_manifest(&self, code: impl AsRef<str>) -> GitHubResult<AppManifestConversion, HandleAppError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            id: Number,
+            slug: String,
+            name: String,
+            #[serde(default)]
+            owner: Option<User>,
+            html_url: String,
+            client_id: String,
+            client_secret: String,
+            #[serde(default)]
+            webhook_secret: Option<String>,
+            pem: String,
+        }
+
+        let Capsule { id, slug, name, owner, html_url, client_id, client_secret, webhook_secret, pem } = {
+            self.client.post(format!("app-manifests/{code}/conversions", code = code.as_ref()))?
+                .header("content-length", "0")
+                .send()?.json()?
+        };
+
+        Ok(AppManifestConversion {
+            number: id,
+            slug,
+            name,
+            owner,
+            html_url,
+            client_id,
+            client_secret: Secret::new(client_secret),
+            webhook_secret: webhook_secret.map(Secret::new),
+            pem: Secret::new(pem),
+        })
+    }
+
+    pub fn try_create_installation_token(&self, installation: Number, ref scope: InstallationTokenScope) -> GitHubResult<InstallationToken, HandleAppError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            name: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            token: String,
+            #[serde(deserialize_with = "crate::common::deserialize_date")]
+            expires_at: Date,
+            #[serde(default)]
+            permissions: BTreeMap<String, String>,
+            #[serde(default)]
+            repository_selection: Option<String>,
+            #[serde(default)]
+            repositories: Vec<CapsuleRepository>,
+        }
+
+        let request = self.client.post(format!("app/installations/{installation}/access_tokens"))?;
+
+        let Capsule { token, expires_at, permissions, repository_selection, repositories } = match scope.is_unrestricted() {
+            true => request.header("content-length", "0").send()?.json()?,
+            false => request.json(scope).send()?.json()?,
+        };
+
+        Ok(InstallationToken {
+            token: Secret::new(token),
+            expires_at,
+            permissions,
+            repository_selection,
+            repositories: repositories.into_iter()
+                .map(|CapsuleRepository { name }| name)
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+
+    collections::{BTreeMap},
+    process::{ExitCode},
+    path::{PathBuf},
+};
+
+use clap::{
+
+    Subcommand,
+    Parser,
+};
+
+use library::{
+
+    repository::properties::{RepositoryProperties},
+    config::{Config},
+
+    GitHubResult,
+};
+
+#[derive(Debug)]
+#[derive(Parser)]
+#[command(name = "octo-cli", version)]
+struct Arguments {
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug)]
+#[derive(Subcommand)]
+enum Commands {
+    #[command(subcommand)]
+    Repo(RepoCommands),
+    #[command(subcommand)]
+    Issue(IssueCommands),
+    #[command(subcommand)]
+    Release(ReleaseCommands),
+    #[command(subcommand)]
+    Workflow(WorkflowCommands),
+}
+
+#[derive(Debug)]
+#[derive(Subcommand)]
+enum RepoCommands {
+    Info {
+        repository: String,
+    },
+}
+
+#[derive(Debug)]
+#[derive(Subcommand)]
+enum IssueCommands {
+    Create {
+        repository: String,
+        title: String,
+        #[arg(long, default_value = "")]
+        body: String,
+    },
+    Comment {
+        repository: String,
+        number: usize,
+        body: String,
+    },
+}
+
+#[derive(Debug)]
+#[derive(Subcommand)]
+enum ReleaseCommands {
+    Create {
+        repository: String,
+        tag: String,
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long, default_value = "")]
+        body: String,
+        #[arg(long)]
+        draft: bool,
+        #[arg(long)]
+        prerelease: bool,
+    },
+}
+
+#[derive(Debug)]
+#[derive(Subcommand)]
+enum WorkflowCommands {
+    Dispatch {
+        repository: String,
+        workflow: String,
+        #[arg(long, default_value = "main")]
+        reference: String,
+        #[arg(long = "input", value_parser = parse_input)]
+        inputs: Vec<(String, String)>,
+    },
+}
+
+fn parse_input(input: &str) -> Result<(String, String), String> {
+    input.split_once('=')
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .ok_or_else(|| format!("expected key=value, got '{input}'"))
+}
+
+fn run(Arguments { config, command }: Arguments) -> GitHubResult<()> {
+    let client = Config::try_load_from(config)?
+        .try_build_client()?;
+
+    match command {
+        Commands::Repo(RepoCommands::Info { repository }) => {
+            let repository = client.try_get_repository(repository)?;
+            let properties = RepositoryProperties::build_from(repository.clone())?;
+
+            println!("repository: {repository}");
+            println!("description: {description}", description = properties.get_description());
+            println!("default branch: {branch}", branch = properties.get_default_branch());
+            println!("visibility: {visibility:?}", visibility = properties.get_visibility());
+            println!("archived: {archived}", archived = properties.has_archived());
+        },
+        Commands::Issue(IssueCommands::Create { repository, title, body }) => {
+            let issue = client.try_get_repository(repository)?
+                .try_create_issue(title, body)?;
+
+            println!("{issue}");
+        },
+        Commands::Issue(IssueCommands::Comment { repository, number, body }) => {
+            let comment = client.try_get_repository(repository)?
+                .try_get_issue(number)?
+                .try_create_comment(body)?;
+
+            println!("{comment}");
+        },
+        Commands::Release(ReleaseCommands::Create { repository, tag, name, body, draft, prerelease }) => {
+            let name = name.unwrap_or_else(|| tag.clone());
+            let release = client.try_get_repository(repository)?
+                .try_create_release(tag, name, body, draft, prerelease)?;
+
+            println!("{url}", url = release.get_html_url());
+        },
+        Commands::Workflow(WorkflowCommands::Dispatch { repository, workflow, reference, inputs }) => {
+            let inputs: BTreeMap<String, String> = inputs.into_iter()
+                .collect();
+
+            client.try_get_repository(repository)?
+                .try_dispatch_workflow(workflow, reference, inputs)?;
+        },
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Arguments::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,653 @@
+use std::{
+
+    fmt::{Debug as FmtDebug},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+
+    time::{
+
+        Duration,
+        Instant,
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use backoff::{backoff::{Backoff}};
+
+use reqwest::{
+
+    header::{
+
+        HeaderValue,
+        HeaderName, 
+    }, 
+
+    Client as ReqwestClient, 
+
+    RequestBuilder,
+    Response,
+    Request,
+    Method,
+    Url,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{
+
+    blocking::{Request as BlockingRequest},
+};
+
+use secrecy::{ExposeSecret};
+
+#[cfg(target_arch = "wasm32")]
+use secrecy::{Secret};
+
+use serde::{
+    
+    de::{DeserializeOwned},
+
+    Serialize, 
+};
+
+use crate::{
+
+    client::{
+
+        ClientResponseError,
+        ClientRequestError,
+        ClientError,
+        CapsuleMessage,
+        Token,
+
+        is_same_origin,
+        join_endpoint,
+        endpoint_of,
+    },
+
+    GitHubResult,
+};
+
+#[cfg(target_arch = "wasm32")]
+use crate::{
+
+    client::{
+
+        DEFAULT_BASE_URL,
+
+        default_headers,
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+
+    client::{
+
+        GitHubResponse,
+        RetryWait,
+        Client,
+    },
+};
+
+pub use bytes::{Bytes};
+
+#[derive(Clone, Debug)]
+pub struct AsyncClient {
+    pub(crate) client: ReqwestClient,
+    pub(crate) base: Url,
+    pub(crate) token: Option<Token>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) shared: Client,
+}
+
+impl AsyncClient {
+    pub fn new() -> GitHubResult<AsyncClient, ClientError> {
+        AsyncClient::new_with_token(None::<String>)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new_with_token(token: Option<impl AsRef<str>>) -> GitHubResult<AsyncClient, ClientError> {
+        let builder = match token {
+            Some(token) => Client::builder().with_token(token),
+            None => Client::builder(),
+        };
+
+        Ok(builder.build()?
+            .get_async())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn new_with_token(token: Option<impl AsRef<str>>) -> GitHubResult<AsyncClient, ClientError> {
+        let client = ReqwestClient::builder()
+            .default_headers(default_headers()).build().map_err(|_| {
+                ClientError::Initialize
+            })?;
+
+        let token = token.and_then(|token| {
+            Some(Secret::new(token.as_ref()
+                .to_owned()))
+        });
+
+        let base = Url::parse(DEFAULT_BASE_URL).map_err(|_| {
+            ClientError::Initialize
+        })?;
+
+        Ok(AsyncClient { 
+            
+            client, 
+            base,
+            token,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn execute(self, request: Request) -> GitHubResult<AsyncResponse, ClientError> {
+        let AsyncClient { client, shared, .. } = { self };
+
+        Ok(AsyncResponse::from_response({
+            shared.send_async(&(client), request).await?
+        }))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    async fn execute(self, request: Request) -> GitHubResult<AsyncResponse, ClientError> {
+        let endpoint = endpoint_of(request.url());
+
+        let response = {
+
+            let response = self.client.execute(request).await;
+            AsyncResponse::from(response.map_err(|_| {
+                ClientRequestError::Unavailable { 
+                    endpoint: endpoint.clone() 
+                }
+            })?)
+        };
+
+        if response.is_success() { 
+            Ok(response) 
+        } 
+        
+        else {
+
+            let code = response.code();
+            let CapsuleMessage { message } = response.json().await
+                .unwrap_or_default();
+
+            Err(ClientError::Response({
+                ClientResponseError::from_code(code, endpoint, message)
+            }))
+        }
+    }
+
+    pub fn get_base_url(&self) -> Url {
+        self.base.clone()
+    }
+
+    pub fn request(&self, method: Method, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        let endpoint = join_endpoint(&(self.base), endpoint)?;
+
+        Ok(match self.token {
+            Some(ref token) if is_same_origin(&(self.base), &(endpoint)) => {
+                AsyncRequestBuilder {
+                    client: self.clone(),
+                    inner: self.client.request(method, endpoint)
+                        .bearer_auth(token.expose_secret()),
+                }
+            },
+            _ => {
+                AsyncRequestBuilder {
+                    client: self.clone(),
+                    inner: self.client.request(method, endpoint),
+                }
+            }
+        })
+    }
+
+    pub fn get(&self, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        self.request(Method::GET, endpoint)
+    }
+
+    pub fn put(&self, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        self.request(Method::PUT, endpoint)
+    }
+
+    pub fn post(&self, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        self.request(Method::POST, endpoint)
+    }
+
+    pub fn patch(&self, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        self.request(Method::PATCH, endpoint)
+    }
+
+    pub fn delete(&self, endpoint: impl AsRef<str>) -> GitHubResult<AsyncRequestBuilder, ClientError> {
+        self.request(Method::DELETE, endpoint)
+    }
+}
+
+pub struct AsyncRequestBuilder {
+    client: AsyncClient,
+    inner: RequestBuilder,
+}
+
+impl AsyncRequestBuilder {
+    pub fn header<K, V>(self, key: K, value: V) -> AsyncRequestBuilder
+    where <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+          <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+          HeaderValue: TryFrom<V>,
+          HeaderName: TryFrom<K>,
+    {
+        AsyncRequestBuilder {
+            inner: self.inner.header(key, value),
+            .. self
+        }
+    }
+
+    pub fn accept(self, media_type: impl AsRef<str>) -> AsyncRequestBuilder {
+        self.header("accept", media_type.as_ref())
+    }
+
+    pub fn body<T: Into<reqwest::Body>>(self, body: T) -> AsyncRequestBuilder {
+        AsyncRequestBuilder {
+            inner: self.inner.body(body),
+            .. self
+        }
+    }
+
+    pub fn query<T: Serialize + ?Sized>(self, query: &T) -> AsyncRequestBuilder {
+        AsyncRequestBuilder {
+            inner: self.inner.query(query),
+            .. self
+        }
+    }
+
+    pub fn json<T: Serialize + ?Sized>(self, json: &T) -> AsyncRequestBuilder {
+        AsyncRequestBuilder {
+            inner: self.inner.json(json),
+            .. self
+        }
+    }
+
+    pub async fn send(self) -> GitHubResult<AsyncResponse, ClientError> {
+        let request = {
+            self.inner.build().map_err(|_| {
+                ClientRequestError::Build
+            })?
+        };
+
+        self.client.execute(request).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Client {
+    pub(crate) async fn send_async(&self, client: &ReqwestClient, request: Request) -> GitHubResult<GitHubResponse, ClientError> {
+        let endpoint = endpoint_of(request.url());
+        let request = into_blocking(request)
+            .ok_or(ClientRequestError::Clone)?;
+
+        let debug = if self.is_debug() { request.try_clone() } else { 
+            None 
+        };
+
+        let mut attempt = 0;
+        let mut total = Duration::ZERO;
+
+        let response = loop {
+
+            attempt = { attempt + 1 };
+
+            let started = Instant::now();
+            let response = self.execute_retrying(client, {
+                request.try_clone().ok_or(ClientRequestError::Clone)?
+            }).await;
+
+            if let Some(ref request) = debug {
+                self.log(request, response.as_ref().ok().map(GitHubResponse::code), started);
+            }
+
+            let wait = response.as_ref().ok()
+                .and_then(GitHubResponse::get_retry_wait);
+
+            match wait {
+                Some(wait) if total + wait <= self.get_retry().get_max_wait() => {
+                    total = { total + wait };
+
+                    self.notify(&(RetryWait {
+                        endpoint: endpoint.clone(),
+                        status: response.as_ref().map(GitHubResponse::code).unwrap_or_default(),
+                        attempt,
+                        wait,
+                        total,
+                    }));
+
+                    tokio::time::sleep(wait).await;
+                },
+                _ => break response,
+            }
+        };
+
+        let response = response?;
+
+        if response.is_success() { 
+            Ok(response) 
+        } 
+        
+        else {
+
+            let code = response.code();
+            let CapsuleMessage { message } = response.json()
+                .unwrap_or_default();
+
+            Err(ClientError::Response({
+                ClientResponseError::from_code(code, endpoint, message)
+            }))
+        }
+    }
+
+    async fn execute_retrying(&self, client: &ReqwestClient, request: BlockingRequest) -> GitHubResult<GitHubResponse, ClientError> {
+        let mut backoff = self.get_retry()
+            .to_backoff();
+
+        loop {
+
+            let attempt = request.try_clone()
+                .ok_or(ClientRequestError::Clone)?;
+
+            match self.execute_async(client, attempt).await {
+                Err(error @ ClientError::Cassette(_)) => break Err(error),
+                Err(error) => match backoff.next_backoff() {
+                    Some(wait) => tokio::time::sleep(wait).await,
+                    None => break Err(error),
+                },
+                Ok(response) => break Ok(response),
+            }
+        }
+    }
+
+    async fn execute_async(&self, client: &ReqwestClient, mut request: BlockingRequest) -> GitHubResult<GitHubResponse, ClientError> {
+        for middleware in self.inner.middleware.iter() {
+            middleware.apply(&mut request);
+        }
+
+        let response = self.execute_raw_async(client, request).await?;
+
+        for hook in self.inner.hooks.iter() {
+            hook.notify(&response);
+        }
+
+        Ok(response)
+    }
+
+    async fn execute_raw_async(&self, client: &ReqwestClient, request: BlockingRequest) -> GitHubResult<GitHubResponse, ClientError> {
+        let endpoint = endpoint_of(request.url());
+
+        let recording = self.get_recording(&(request));
+        if let Some(response) = self.try_replay(&(request), recording.as_ref())? {
+            return Ok(response)
+        }
+
+        let permit = match self.inner.limiter {
+            Some(ref limiter) => Some(limiter.acquire_async(request.method()).await),
+            None => None,
+        };
+
+        let response = match self.inner.transport {
+            Some(ref transport) => transport.execute_async(request).await?,
+            None => {
+                let request = from_blocking(request)
+                    .ok_or(ClientRequestError::Clone)?;
+
+                let response = client.execute(request).await.map_err(|_| {
+                    ClientRequestError::Unavailable { endpoint }
+                })?;
+
+                let url = response.url().clone();
+                let status = response.status();
+                let headers = response.headers()
+                    .clone();
+
+                let body = response.bytes().await.map_err(|_| {
+                    ClientResponseError::Encoding
+                })?;
+
+                GitHubResponse::from_parts(url, status, headers, body)
+            },
+        };
+
+        drop(permit);
+
+        self.try_record(recording, &(response))?;
+
+        Ok(response)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn into_blocking(request: Request) -> Option<BlockingRequest> {
+    let mut converted = BlockingRequest::new(request.method().clone(), request.url().clone());
+
+    *converted.headers_mut() = request.headers().clone();
+    *converted.timeout_mut() = request.timeout().copied();
+
+    if let Some(body) = request.body() {
+        *converted.body_mut() = Some(body.as_bytes()?.to_vec().into());
+    }
+
+    Some(converted)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn from_blocking(request: BlockingRequest) -> Option<Request> {
+    let mut converted = Request::new(request.method().clone(), request.url().clone());
+
+    *converted.headers_mut() = request.headers().clone();
+    *converted.timeout_mut() = request.timeout().copied();
+
+    if let Some(body) = request.body() {
+        *converted.body_mut() = Some(body.as_bytes()?.to_vec().into());
+    }
+
+    Some(converted)
+}
+
+#[derive(Debug)]
+pub struct AsyncResponse {
+    inner: Response,
+}
+
+impl AsyncResponse {
+    pub fn from(response: Response) -> AsyncResponse {
+        AsyncResponse { inner: response }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn from_response(response: GitHubResponse) -> AsyncResponse {
+        let GitHubResponse { status, headers, body, .. } = { response };
+
+        let mut inner = http::Response::new(body);
+
+        *inner.status_mut() = status;
+        *inner.headers_mut() = headers;
+
+        AsyncResponse::from(Response::from(inner))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.inner.status()
+            .is_success()
+    }
+
+    pub fn code(&self) -> u16 {
+        self.inner.status()
+            .as_u16()
+    }
+
+    pub async fn bytes(self) -> GitHubResult<Bytes, ClientError> {
+        let bytes = {
+            self.inner.bytes().await.map_err(|_| {
+                ClientResponseError::Encoding
+            })?
+        };
+
+        Ok(bytes)
+    }
+
+    pub async fn text(self) -> GitHubResult<String, ClientError> {
+        let text = {
+            self.inner.text().await.map_err(|_| {
+                ClientResponseError::Encoding
+            })?
+        };
+
+        Ok(text)
+    }
+
+    pub async fn json<T: DeserializeOwned + FmtDebug>(self) -> GitHubResult<T, ClientError> {
+        let ref notation = {
+            self.inner.text().await.map_err(|_| {
+                ClientResponseError::Encoding
+            })?
+        };
+
+        Ok(serde_json::from_str(notation).map_err(|error| {
+            ClientResponseError::Malformed { 
+                reason: error.to_string() 
+            }
+        })?)
+    }
+
+    pub async fn json_with_path_errors<T: DeserializeOwned + FmtDebug>(self) -> GitHubResult<T, ClientError> {
+        let ref notation = {
+            self.inner.text().await.map_err(|_| {
+                ClientResponseError::Encoding
+            })?
+        };
+
+        let ref mut deserializer = serde_json::Deserializer::from_str(notation);
+
+        Ok(serde_path_to_error::deserialize(deserializer).map_err(|error| {
+            ClientResponseError::Deserialize {
+                path: error.path().to_string(),
+                reason: error.into_inner().to_string(),
+            }
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+
+        sync::{
+
+            atomic::{
+
+                AtomicUsize,
+                Ordering,
+            },
+
+            Arc,
+        },
+    };
+
+    use tokio::{
+
+        io::{
+
+            AsyncWriteExt,
+            AsyncReadExt,
+        },
+
+        net::{TcpListener},
+    };
+
+    use crate::client::{
+
+        MockResponse,
+        MockTransport,
+        ProxyConfig,
+        RetryPolicy,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shared_pipeline() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "user", MockResponse::json(403, serde_json::json!({
+                "message": "You have exceeded a secondary rate limit.",
+            })).with_header("retry-after", "0"))
+            .with_json(Method::GET, "user", serde_json::json!({ "login": "octocat" }));
+
+        let applied = Arc::new(AtomicUsize::new(0));
+        let notified = Arc::new(AtomicUsize::new(0));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap()
+            .with_middleware({
+                let applied = applied.clone();
+                move |_| { applied.fetch_add(1, Ordering::SeqCst); }
+            })
+            .with_response_hook({
+                let notified = notified.clone();
+                move |_| { notified.fetch_add(1, Ordering::SeqCst); }
+            });
+
+        let body: serde_json::Value = client.get_async()
+            .get("user").unwrap()
+            .send().await.unwrap()
+            .json().await.unwrap();
+
+        assert_eq!(body["login"], "octocat");
+        assert_eq!(transport.get_request_count(Method::GET, "user"), 2);
+        assert_eq!(applied.load(Ordering::SeqCst), 2);
+        assert_eq!(notified.load(Ordering::SeqCst), 2);
+
+        let result = client.get_async()
+            .get("missing").unwrap()
+            .send().await;
+
+        assert!(matches!(result, Err(ClientError::Response(ClientResponseError::Nothing { .. }))));
+    }
+
+    #[tokio::test]
+    async fn test_without_blocking_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut buffer = vec![0; 4096];
+            let _ = stream.read(buffer.as_mut_slice()).await.unwrap();
+
+            let body = r#"{"login":"octocat"}"#;
+            let response = format!("HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {length}\r\nconnection: close\r\n\r\n{body}", length = body.len());
+
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let client = Client::builder()
+            .with_base_url(format!("http://{address}"))
+            .with_proxy(ProxyConfig::disabled())
+            .with_retry(RetryPolicy::disabled())
+            .build()
+            .unwrap();
+
+        let body: serde_json::Value = client.get_async()
+            .get("user").unwrap()
+            .send().await.unwrap()
+            .json().await.unwrap();
+
+        server.await.unwrap();
+
+        assert_eq!(body["login"], "octocat");
+        assert!(!(client.inner.client.is_initialized()));
+
+        drop(client);
+    }
+}
@@ -0,0 +1,667 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    },
+
+    time::{Duration},
+    env::{self},
+
+    sync::{
+
+        OnceLock,
+        Arc,
+    },
+};
+
+use reqwest::{
+
+    blocking::{Client as ReqwestClient},
+
+    header::{
+
+        HeaderValue,
+        HeaderName,
+    },
+
+    Client as AsyncReqwestClient,
+    NoProxy,
+    Proxy,
+    Url,
+};
+
+use backoff::{ExponentialBackoff as BackoffExponential};
+
+use secrecy::{Secret};
+
+use crate::{
+
+    client::{
+
+        RequestLimiter,
+        ClientInner,
+        Transport,
+        ClientError,
+
+        DEFAULT_BASE_URL,
+        Cassette,
+        Client,
+        Token,
+
+        default_headers,
+    },
+
+    GitHubResult,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+pub struct RetryPolicy {
+    initial_interval: Duration,
+    max_interval: Duration,
+    max_elapsed: Option<Duration>,
+    max_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(60),
+            max_elapsed: Some(Duration::from_secs(900)),
+            max_wait: Duration::from_secs(600),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        Default::default()
+    }
+
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy { max_elapsed: Some(Duration::ZERO), max_wait: Duration::ZERO, .. Default::default() }
+    }
+
+    pub fn with_initial_interval(mut self, interval: Duration) -> RetryPolicy {
+        self.initial_interval = interval;
+        self
+    }
+
+    pub fn with_max_interval(mut self, interval: Duration) -> RetryPolicy {
+        self.max_interval = interval;
+        self
+    }
+
+    pub fn with_max_elapsed(mut self, elapsed: Option<Duration>) -> RetryPolicy {
+        self.max_elapsed = elapsed;
+        self
+    }
+
+    pub fn with_max_wait(mut self, wait: Duration) -> RetryPolicy {
+        self.max_wait = wait;
+        self
+    }
+
+    pub fn get_initial_interval(&self) -> Duration {
+        self.initial_interval
+    }
+
+    pub fn get_max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    pub fn get_max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+
+    pub fn get_max_wait(&self) -> Duration {
+        self.max_wait
+    }
+
+    pub(crate) fn to_backoff(&self) -> BackoffExponential {
+        BackoffExponential {
+            current_interval: self.initial_interval,
+            initial_interval: self.initial_interval,
+            max_interval: self.max_interval,
+            max_elapsed_time: self.max_elapsed,
+            .. Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq)]
+pub struct RetryWait {
+    pub(crate) endpoint: String,
+    pub(crate) status: u16,
+    pub(crate) attempt: usize,
+    pub(crate) wait: Duration,
+    pub(crate) total: Duration,
+}
+
+impl RetryWait {
+    pub fn get_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    pub fn get_status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn get_attempt(&self) -> usize {
+        self.attempt
+    }
+
+    pub fn get_wait(&self) -> Duration {
+        self.wait
+    }
+
+    pub fn get_total(&self) -> Duration {
+        self.total
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct WaitObserver(Arc<dyn Fn(&RetryWait) + Send + Sync>);
+
+impl WaitObserver {
+    pub(crate) fn notify(&self, wait: &RetryWait) {
+        (self.0)(wait)
+    }
+}
+
+impl FmtDebug for WaitObserver {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "WaitObserver")
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LazyClient {
+    factory: Arc<dyn Fn() -> Option<ReqwestClient> + Send + Sync>,
+    client: Arc<OnceLock<ReqwestClient>>,
+}
+
+impl LazyClient {
+    pub(crate) fn new(factory: impl Fn() -> Option<ReqwestClient> + Send + Sync + 'static) -> LazyClient {
+        LazyClient { factory: Arc::new(factory), client: Arc::new(OnceLock::new()) }
+    }
+
+    pub(crate) fn try_get(&self) -> GitHubResult<&ReqwestClient, ClientError> {
+        if let Some(client) = self.client.get() {
+            return Ok(client)
+        }
+
+        let client = (self.factory)().ok_or(ClientError::Initialize)?;
+        Ok(self.client.get_or_init(|| client))
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.client.get().is_some()
+    }
+}
+
+impl FmtDebug for LazyClient {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        fmt.debug_struct("LazyClient")
+            .field("initialized", &(self.is_initialized()))
+            .finish()
+    }
+}
+
+/// Environment variables consulted by token discovery, highest priority first.
+pub const TOKEN_VARIABLES: [&str; 3] = [
+    "GITHUB_TOKEN",
+    "INPUT_GITHUB-TOKEN",
+    "GH_TOKEN",
+];
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+pub enum TokenOrigin {
+    Environment(&'static str),
+    Explicit,
+}
+
+impl TokenOrigin {
+    pub fn get_variable(&self) -> Option<&'static str> {
+        match self {
+            TokenOrigin::Environment(variable) => Some(variable),
+            TokenOrigin::Explicit => None,
+        }
+    }
+
+    pub fn is_environment(&self) -> bool {
+        matches!(self, TokenOrigin::Environment(..))
+    }
+}
+
+pub fn discover_token() -> Option<(Token, TokenOrigin)> {
+    discover_token_with(|variable| env::var(variable).ok())
+}
+
+pub(crate) fn discover_token_with(lookup: impl Fn(&str) -> Option<String>) -> Option<(Token, TokenOrigin)> {
+    TOKEN_VARIABLES.iter().find_map(|variable| {
+        lookup(variable)
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !(value.is_empty()))
+            .map(|value| (Secret::new(value), TokenOrigin::Environment(variable)))
+    })
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+pub struct ProxyConfig {
+    pub(crate) http: Option<String>,
+    pub(crate) https: Option<String>,
+    pub(crate) all: Option<String>,
+    pub(crate) no_proxy: Vec<String>,
+    pub(crate) disabled: bool,
+}
+
+impl ProxyConfig {
+    pub fn new() -> ProxyConfig {
+        Default::default()
+    }
+
+    pub fn disabled() -> ProxyConfig {
+        ProxyConfig { disabled: true, .. Default::default() }
+    }
+
+    pub fn with_http(mut self, url: impl AsRef<str>) -> ProxyConfig {
+        self.http = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_https(mut self, url: impl AsRef<str>) -> ProxyConfig {
+        self.https = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_all(mut self, url: impl AsRef<str>) -> ProxyConfig {
+        self.all = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_no_proxy(mut self, host: impl AsRef<str>) -> ProxyConfig {
+        self.no_proxy.push(host.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_http(&self) -> Option<String> {
+        self.http.clone()
+    }
+
+    pub fn get_https(&self) -> Option<String> {
+        self.https.clone()
+    }
+
+    pub fn get_all(&self) -> Option<String> {
+        self.all.clone()
+    }
+
+    pub fn get_no_proxy(&self) -> Vec<String> {
+        self.no_proxy.clone()
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub(crate) fn try_get_proxies(&self) -> GitHubResult<Vec<Proxy>, ClientError> {
+        let no_proxy = match self.no_proxy.is_empty() {
+            false => NoProxy::from_string(self.no_proxy.join(",").as_str()),
+            true => None,
+        };
+
+        let settings: [(&Option<String>, fn(&str) -> reqwest::Result<Proxy>); 3] = [
+            (&(self.http), |url| Proxy::http(url)),
+            (&(self.https), |url| Proxy::https(url)),
+            (&(self.all), |url| Proxy::all(url)),
+        ];
+
+        let mut proxies = Vec::new();
+
+        for (url, constructor) in settings {
+            if let Some(url) = url {
+                let proxy = constructor(url.as_str()).map_err(|_| {
+                    ClientError::Proxy { url: url.clone() }
+                })?;
+
+                proxies.push(proxy.no_proxy(no_proxy.clone()));
+            }
+        }
+
+        Ok(proxies)
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct ClientBuilder {
+    token: Option<Token>,
+    origin: Option<TokenOrigin>,
+    base_url: Option<String>,
+    retry: RetryPolicy,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    cassette: Option<Arc<Cassette>>,
+    max_in_flight: Option<usize>,
+    mutation_interval: Option<Duration>,
+    observer: Option<WaitObserver>,
+    proxy: Option<ProxyConfig>,
+    transport: Option<Arc<dyn Transport>>,
+    user_agent: Option<String>,
+    api_version: Option<String>,
+    headers: Vec<(String, String)>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    debug: bool,
+}
+
+impl ClientBuilder {
+    pub fn new() -> ClientBuilder {
+        Default::default()
+    }
+
+    pub fn with_token(mut self, token: impl AsRef<str>) -> ClientBuilder {
+        self.token = Some(Secret::new(token.as_ref()
+            .to_owned()));
+        self.origin = Some(TokenOrigin::Explicit);
+        self
+    }
+
+    pub fn with_discovered_token(mut self) -> ClientBuilder {
+        if let Some((token, origin)) = discover_token() {
+            self.token = Some(token);
+            self.origin = Some(origin);
+        }
+
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl AsRef<str>) -> ClientBuilder {
+        self.base_url = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> ClientBuilder {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_max_wait(mut self, wait: Duration) -> ClientBuilder {
+        self.retry = self.retry.with_max_wait(wait);
+        self
+    }
+
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> ClientBuilder {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_tcp_keepalive(mut self, keepalive: Duration) -> ClientBuilder {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    pub fn with_cassette(mut self, cassette: Cassette) -> ClientBuilder {
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    pub fn with_max_in_flight(mut self, max: usize) -> ClientBuilder {
+        self.max_in_flight = Some(max);
+        self
+    }
+
+    pub fn with_mutation_interval(mut self, interval: Duration) -> ClientBuilder {
+        self.mutation_interval = Some(interval);
+        self
+    }
+
+    pub fn with_wait_observer(mut self, observer: impl Fn(&RetryWait) + Send + Sync + 'static) -> ClientBuilder {
+        self.observer = Some(WaitObserver(Arc::new(observer)));
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl AsRef<str>) -> ClientBuilder {
+        self.user_agent = Some(user_agent.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_api_version(mut self, version: impl AsRef<str>) -> ClientBuilder {
+        self.api_version = Some(version.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> ClientBuilder {
+        self.headers.push((name.as_ref().to_owned(), value.as_ref().to_owned()));
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> ClientBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> ClientBuilder {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn with_debug(mut self, debug: bool) -> ClientBuilder {
+        self.debug = debug;
+        self
+    }
+
+    pub fn build(self) -> GitHubResult<Client, ClientError> {
+        let ClientBuilder { 
+            
+            token, 
+            origin,
+            base_url,
+            retry,
+            pool_max_idle_per_host, 
+            pool_idle_timeout, 
+            tcp_keepalive, 
+            cassette,
+            max_in_flight,
+            mutation_interval,
+            observer,
+            proxy,
+            transport,
+            user_agent,
+            api_version,
+            headers,
+            connect_timeout,
+            timeout,
+            debug, 
+
+        } = { self };
+
+        let mut defaults = default_headers();
+
+        let overrides = [("user-agent", user_agent), ("x-github-api-version", api_version)].into_iter()
+            .filter_map(|(name, value)| Some((name.to_owned(), value?)))
+            .chain(headers);
+
+        for (name, value) in overrides {
+            let header = HeaderName::try_from(name.as_str()).ok()
+                .zip(HeaderValue::try_from(value.as_str()).ok());
+
+            let (name, value) = header.ok_or_else(|| {
+                ClientError::Header { name: name.clone() }
+            })?;
+
+            defaults.insert(name, value);
+        }
+
+        let (disabled, proxies) = match proxy {
+            Some(ref proxy) => (proxy.is_disabled(), proxy.try_get_proxies()?),
+            None => (false, Vec::new()),
+        };
+
+        let mut asynchronous = AsyncReqwestClient::builder()
+            .default_headers(defaults.clone());
+
+        if let Some(timeout) = connect_timeout {
+            asynchronous = asynchronous.connect_timeout(timeout);
+        }
+
+        if let Some(timeout) = timeout {
+            asynchronous = asynchronous.timeout(timeout);
+        }
+
+        if let Some(max) = pool_max_idle_per_host {
+            asynchronous = asynchronous.pool_max_idle_per_host(max);
+        }
+
+        if let Some(timeout) = pool_idle_timeout {
+            asynchronous = asynchronous.pool_idle_timeout(timeout);
+        }
+
+        if let Some(keepalive) = tcp_keepalive {
+            asynchronous = asynchronous.tcp_keepalive(keepalive);
+        }
+
+        if disabled {
+            asynchronous = asynchronous.no_proxy();
+        }
+
+        for setting in proxies.iter().cloned() {
+            asynchronous = asynchronous.proxy(setting);
+        }
+
+        let asynchronous = asynchronous.build().map_err(|_| {
+            ClientError::Initialize
+        })?;
+
+        let client = LazyClient::new(move || {
+            let mut builder = ReqwestClient::builder()
+                .default_headers(defaults.clone());
+
+            if let Some(timeout) = connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+
+            if let Some(timeout) = timeout {
+                builder = builder.timeout(timeout);
+            }
+
+            if let Some(max) = pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max);
+            }
+
+            if let Some(timeout) = pool_idle_timeout {
+                builder = builder.pool_idle_timeout(timeout);
+            }
+
+            if let Some(keepalive) = tcp_keepalive {
+                builder = builder.tcp_keepalive(keepalive);
+            }
+
+            if disabled {
+                builder = builder.no_proxy();
+            }
+
+            for setting in proxies.iter().cloned() {
+                builder = builder.proxy(setting);
+            }
+
+            builder.build().ok()
+        });
+
+        let base_url = base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+        let base = Url::parse(format!("{url}/", url = base_url.trim_end_matches('/')).as_str()).map_err(|_| {
+            ClientError::ParseEndpoint { endpoint: base_url.clone() }
+        })?;
+
+        let limiter = match (max_in_flight, mutation_interval) {
+            (None, None) => None,
+            (max_in_flight, mutation_interval) => Some(Arc::new({
+                RequestLimiter::new(max_in_flight, mutation_interval)
+            })),
+        };
+
+        Ok(Client { 
+            
+            inner: Arc::new(ClientInner {
+                asynchronous,
+                client,
+                base,
+                retry,
+                token,
+                origin,
+                cassette,
+                limiter,
+                observer,
+                transport,
+                middleware: Vec::new(),
+                hooks: Vec::new(),
+                debug,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use secrecy::{ExposeSecret};
+
+    use super::*;
+
+    #[test]
+    fn test_discover_token_priority() {
+        let lookup = |variable: &str| match variable {
+            "INPUT_GITHUB-TOKEN" => Some("input".to_owned()),
+            "GH_TOKEN" => Some("gh".to_owned()),
+            "GITHUB_TOKEN" => Some("  ".to_owned()),
+            _ => None,
+        };
+
+        let (token, origin) = discover_token_with(lookup).unwrap();
+
+        assert_eq!(token.expose_secret(), "input");
+        assert_eq!(origin, TokenOrigin::Environment("INPUT_GITHUB-TOKEN"));
+        assert_eq!(origin.get_variable(), Some("INPUT_GITHUB-TOKEN"));
+
+        assert!(discover_token_with(|_| None).is_none());
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        let proxy = ProxyConfig::new()
+            .with_https("http://proxy.internal:3128")
+            .with_no_proxy("localhost")
+            .with_no_proxy(".internal");
+
+        assert_eq!(proxy.try_get_proxies().unwrap().len(), 1);
+        assert_eq!(proxy.get_no_proxy(), vec!["localhost".to_owned(), ".internal".to_owned()]);
+
+        let invalid = ProxyConfig::new()
+            .with_all("not a url");
+
+        assert!(matches!(invalid.try_get_proxies(), Err(ClientError::Proxy { .. })));
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+
+    collections::{BTreeMap},
+    path::{PathBuf, Path},
+    sync::{Mutex},
+    fs::{self},
+};
+
+use base64::{
+
+    engine::general_purpose::{STANDARD as BASE64},
+
+    Engine,
+};
+
+use thiserror::{Error};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{GitHubResult};
+
+#[derive(Error, Debug)]
+pub enum CassetteError {
+    #[error("Failed to read cassette: '{path}'")]
+    Read { path: PathBuf },
+    #[error("Failed to write cassette: '{path}'")]
+    Write { path: PathBuf },
+    #[error("Malformed cassette: '{path}', reason: '{reason}'")]
+    Malformed { path: PathBuf, reason: String },
+    #[error("No recorded interaction for: {method} '{endpoint}'")]
+    Missing { method: String, endpoint: String },
+    #[error("Cassette is poisoned!")]
+    Poisoned,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CassetteBody {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    base64: bool,
+    content: String,
+}
+
+impl CassetteBody {
+    pub(crate) fn from_bytes(bytes: &[u8]) -> CassetteBody {
+        match std::str::from_utf8(bytes) {
+            Ok(content) => CassetteBody { base64: false, content: content.to_owned() },
+            Err(_) => CassetteBody { base64: true, content: BASE64.encode(bytes) },
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        if self.base64 { BASE64.decode(self.content.as_bytes()).unwrap_or_default() } else {
+            self.content.as_bytes().to_vec()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CassetteRequest {
+    pub(crate) method: String,
+    pub(crate) endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) body: Option<CassetteBody>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CassetteResponse {
+    pub(crate) status: u16,
+    #[serde(default)]
+    pub(crate) headers: BTreeMap<String, String>,
+    pub(crate) body: CassetteBody,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CassetteInteraction {
+    pub(crate) request: CassetteRequest,
+    pub(crate) response: CassetteResponse,
+}
+
+#[derive(Default, Debug)]
+#[derive(Serialize, Deserialize)]
+struct CassetteContent {
+    interactions: Vec<CassetteInteraction>,
+}
+
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    interactions: Mutex<Vec<(CassetteInteraction, bool)>>,
+}
+
+impl Cassette {
+    pub fn record(path: impl AsRef<Path>) -> Cassette {
+        Cassette {
+            path: path.as_ref().to_owned(),
+            mode: CassetteMode::Record,
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn try_replay(path: impl AsRef<Path>) -> GitHubResult<Cassette, CassetteError> {
+        let path = path.as_ref().to_owned();
+
+        let ref notation = fs::read_to_string(&(path)).map_err(|_| {
+            CassetteError::Read { path: path.clone() }
+        })?;
+
+        let CassetteContent { interactions } = {
+            serde_json::from_str(notation).map_err(|error| {
+                CassetteError::Malformed { path: path.clone(), reason: error.to_string() }
+            })?
+        };
+
+        Ok(Cassette {
+            path,
+            mode: CassetteMode::Replay,
+            interactions: Mutex::new(interactions.into_iter()
+                .map(|interaction| (interaction, false))
+                .collect()),
+        })
+    }
+
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn get_mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn is_replay(&self) -> bool {
+        self.mode == CassetteMode::Replay
+    }
+
+    pub fn is_record(&self) -> bool {
+        self.mode == CassetteMode::Record
+    }
+
+    pub(crate) fn try_take(&self, request: &CassetteRequest) -> GitHubResult<CassetteResponse, CassetteError> {
+        let mut interactions = self.interactions.lock().map_err(|_| {
+            CassetteError::Poisoned
+        })?;
+
+        let matches = |interaction: &CassetteInteraction| {
+            interaction.request.method == request.method &&
+            interaction.request.endpoint == request.endpoint &&
+            interaction.request.body.as_ref().map(CassetteBody::to_bytes) == {
+                request.body.as_ref().map(CassetteBody::to_bytes)
+            }
+        };
+
+        if let Some((interaction, used)) = interactions.iter_mut().find(|(interaction, used)| {
+            !(*used) && matches(interaction)
+        }) {
+            *used = true;
+            return Ok(interaction.response.clone())
+        }
+
+        Err(CassetteError::Missing {
+            method: request.method.clone(),
+            endpoint: request.endpoint.clone(),
+        })
+    }
+
+    pub(crate) fn try_push(&self, interaction: CassetteInteraction) -> GitHubResult<(), CassetteError> {
+        let mut interactions = self.interactions.lock().map_err(|_| {
+            CassetteError::Poisoned
+        })?;
+
+        interactions.push((interaction, true));
+
+        let content = CassetteContent {
+            interactions: interactions.iter()
+                .map(|(interaction, _)| interaction.clone())
+                .collect(),
+        };
+
+        let notation = serde_json::to_string_pretty(&(content)).map_err(|error| {
+            CassetteError::Malformed { path: self.path.clone(), reason: error.to_string() }
+        })?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|_| {
+                CassetteError::Write { path: self.path.clone() }
+            })?;
+        }
+
+        fs::write(&(self.path), notation).map_err(|_| {
+            CassetteError::Write { path: self.path.clone() }
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_replay() {
+        let path = std::env::temp_dir()
+            .join(format!("octo-cassette-{}.json", std::process::id()));
+
+        let request = |method: &str, endpoint: &str| CassetteRequest {
+            method: method.to_owned(),
+            endpoint: endpoint.to_owned(),
+            body: None,
+        };
+
+        let response = |status: u16, content: &[u8]| CassetteResponse {
+            status,
+            headers: BTreeMap::new(),
+            body: CassetteBody::from_bytes(content),
+        };
+
+        let cassette = Cassette::record(&(path));
+        for (request, response) in [
+            (request("GET", "repos/octo/octo"), response(200, b"{}")),
+            (request("GET", "repos/octo/octo"), response(304, b"")),
+            (request("DELETE", "repos/octo/octo"), response(204, &[0xff, 0xfe])),
+        ] {
+            cassette.try_push(CassetteInteraction { request, response })
+                .unwrap();
+        }
+
+        let cassette = Cassette::try_replay(&(path))
+            .unwrap();
+
+        assert_eq!(cassette.try_take(&(request("GET", "repos/octo/octo"))).unwrap().status, 200);
+        assert_eq!(cassette.try_take(&(request("GET", "repos/octo/octo"))).unwrap().status, 304);
+        assert_eq!(cassette.try_take(&(request("DELETE", "repos/octo/octo"))).unwrap().body.to_bytes(), vec![0xff, 0xfe]);
+        assert!(cassette.try_take(&(request("GET", "repos/octo/octo"))).is_err());
+
+        let _ = fs::remove_file(&(path));
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+
+    time::{
+
+        Duration,
+        Instant,
+    },
+
+    sync::{
+
+        Condvar,
+        Mutex,
+    },
+};
+
+use reqwest::{Method};
+
+const LIMITER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+fn is_mutation(method: &Method) -> bool {
+    !(matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS))
+}
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    in_flight: usize,
+    last_mutation: Option<Instant>,
+}
+
+#[derive(Debug)]
+pub(crate) struct RequestLimiter {
+    max_in_flight: Option<usize>,
+    mutation_interval: Option<Duration>,
+    state: Mutex<LimiterState>,
+    released: Condvar,
+}
+
+pub(crate) struct LimiterPermit<'a> {
+    limiter: &'a RequestLimiter,
+}
+
+impl RequestLimiter {
+    pub(crate) fn new(max_in_flight: Option<usize>, mutation_interval: Option<Duration>) -> RequestLimiter {
+        RequestLimiter {
+            max_in_flight: max_in_flight.map(|max| max.max(1)),
+            mutation_interval,
+            state: Default::default(),
+            released: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn get_max_in_flight(&self) -> Option<usize> {
+        self.max_in_flight
+    }
+
+    pub(crate) fn get_mutation_interval(&self) -> Option<Duration> {
+        self.mutation_interval
+    }
+
+    pub(crate) fn acquire(&self, method: &Method) -> LimiterPermit<'_> {
+        let mutation = is_mutation(method);
+
+        let mut state = self.state.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        loop {
+
+            state = match self.get_wait(&(state), mutation) {
+                Some(None) => self.released.wait(state)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+                Some(Some(wait)) => self.released.wait_timeout(state, wait)
+                    .map(|(state, _)| state)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner().0),
+                None => break,
+            };
+        }
+
+        self.admit(&mut (state), mutation)
+    }
+
+    pub(crate) fn try_acquire(&self, method: &Method) -> Result<LimiterPermit<'_>, Duration> {
+        let mutation = is_mutation(method);
+
+        let mut state = self.state.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match self.get_wait(&(state), mutation) {
+            Some(wait) => Err(wait.unwrap_or(LIMITER_POLL_INTERVAL)),
+            None => Ok(self.admit(&mut (state), mutation)),
+        }
+    }
+
+    pub(crate) async fn acquire_async(&self, method: &Method) -> LimiterPermit<'_> {
+        loop {
+
+            match self.try_acquire(method) {
+                Err(wait) => tokio::time::sleep(wait).await,
+                Ok(permit) => break permit,
+            }
+        }
+    }
+
+    fn get_wait(&self, state: &LimiterState, mutation: bool) -> Option<Option<Duration>> {
+        let saturated = self.max_in_flight
+            .is_some_and(|max| state.in_flight >= max);
+
+        if saturated {
+            return Some(None)
+        }
+
+        let wait = match (mutation, self.mutation_interval, state.last_mutation) {
+            (true, Some(interval), Some(last)) => interval.checked_sub(last.elapsed()),
+            _ => None,
+        };
+
+        wait.filter(|wait| !(wait.is_zero()))
+            .map(Some)
+    }
+
+    fn admit(&self, state: &mut LimiterState, mutation: bool) -> LimiterPermit<'_> {
+        state.in_flight += 1;
+
+        if mutation {
+            state.last_mutation = Some(Instant::now());
+        }
+
+        LimiterPermit { limiter: self }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        state.in_flight = state.in_flight.saturating_sub(1);
+
+        self.released.notify_all();
+    }
+}
+
+impl Drop for LimiterPermit<'_> {
+    fn drop(&mut self) {
+        self.limiter.release()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+
+        sync::{
+
+            atomic::{
+
+                AtomicUsize,
+                Ordering,
+            },
+        },
+
+        thread::{self},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_limiter() {
+        let limiter = RequestLimiter::new(Some(2), Some(Duration::from_millis(50)));
+
+        let started = Instant::now();
+        drop(limiter.acquire(&(Method::POST)));
+        drop(limiter.acquire(&(Method::PATCH)));
+        drop(limiter.acquire(&(Method::GET)));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+
+        let current = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    let _permit = limiter.acquire(&(Method::GET));
+                    let value = current.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(value, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    current.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+}
@@ -0,0 +1,129 @@
+use reqwest::{Url};
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+pub struct Links {
+    pub(crate) next: Option<Url>,
+    pub(crate) prev: Option<Url>,
+    pub(crate) first: Option<Url>,
+    pub(crate) last: Option<Url>,
+}
+
+impl Links {
+    pub fn parse(header: impl AsRef<str>) -> Links {
+        let mut links = Links::default();
+
+        for part in header.as_ref().split(',') {
+            let mut segments = part.split(';');
+
+            let target = match segments.next() {
+                Some(target) => target.trim(),
+                None => continue,
+            };
+
+            let url = match target.strip_prefix('<').and_then(|target| target.strip_suffix('>')) {
+                Some(target) => match Url::parse(target) {
+                    Ok(url) => url,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+
+            let relations = segments.filter_map(|parameter| {
+                let (key, value) = parameter.split_once('=')?;
+
+                match key.trim().eq_ignore_ascii_case("rel") {
+                    true => Some(value.trim().trim_matches('"').to_owned()),
+                    false => None,
+                }
+            });
+
+            for relations in relations {
+                for relation in relations.split_whitespace() {
+                    let slot = match relation.to_lowercase().as_str() {
+                        "next" => &mut links.next,
+                        "prev" => &mut links.prev,
+                        "first" => &mut links.first,
+                        "last" => &mut links.last,
+                        _ => continue,
+                    };
+
+                    *slot = Some(url.clone());
+                }
+            }
+        }
+
+        links
+    }
+
+    pub fn get_next(&self) -> Option<Url> {
+        self.next.clone()
+    }
+
+    pub fn get_prev(&self) -> Option<Url> {
+        self.prev.clone()
+    }
+
+    pub fn get_first(&self) -> Option<Url> {
+        self.first.clone()
+    }
+
+    pub fn get_last(&self) -> Option<Url> {
+        self.last.clone()
+    }
+
+    pub fn get_last_page(&self) -> Option<usize> {
+        Links::page_of(self.last.as_ref()?)
+    }
+
+    pub fn get_next_page(&self) -> Option<usize> {
+        Links::page_of(self.next.as_ref()?)
+    }
+
+    pub fn get_next_query(&self, key: impl AsRef<str>) -> Option<String> {
+        let key = key.as_ref();
+
+        self.next.as_ref()?.query_pairs()
+            .find(|(candidate, _)| candidate == key)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next.is_none() && self.prev.is_none() && self.first.is_none() && self.last.is_none()
+    }
+
+    pub(crate) fn page_of(url: &Url) -> Option<usize> {
+        url.query_pairs()
+            .find(|(key, _)| key == "page")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let links = Links::parse(concat!(
+            "<https://api.github.com/repositories/1/issues?per_page=100&page=2>; rel=\"next\", ",
+            "<https://api.github.com/repositories/1/issues?per_page=100&page=5>; rel=\"last\"",
+        ));
+
+        assert!(links.has_next());
+        assert_eq!(links.get_next_page(), Some(2));
+        assert_eq!(links.get_last_page(), Some(5));
+        assert!(links.get_prev().is_none());
+
+        let links = Links::parse("<https://api.github.com/app/hook/deliveries?per_page=100&cursor=v1_12>; rel=\"next\"");
+
+        assert_eq!(links.get_next_query("cursor").as_deref(), Some("v1_12"));
+
+        assert!(Links::parse("").is_empty());
+    }
+}
@@ -0,0 +1,11 @@
+pub const JSON: &str = "application/vnd.github+json";
+pub const RAW: &str = "application/vnd.github.raw+json";
+pub const HTML: &str = "application/vnd.github.html+json";
+pub const TEXT: &str = "application/vnd.github.text+json";
+pub const FULL: &str = "application/vnd.github.full+json";
+pub const OBJECT: &str = "application/vnd.github.object+json";
+pub const DIFF: &str = "application/vnd.github.diff";
+pub const PATCH: &str = "application/vnd.github.patch";
+pub const SHA: &str = "application/vnd.github.sha";
+pub const STAR: &str = "application/vnd.github.star+json";
+pub const TEXT_MATCH: &str = "application/vnd.github.text-match+json";
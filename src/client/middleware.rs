@@ -0,0 +1,59 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    },
+
+    sync::{Arc},
+};
+
+use reqwest::{
+
+    blocking::{Request},
+};
+
+use crate::{
+
+    client::{GitHubResponse},
+};
+
+#[derive(Clone)]
+pub(crate) struct RequestMiddleware(Arc<dyn Fn(&mut Request) + Send + Sync>);
+
+impl RequestMiddleware {
+    pub(crate) fn new(middleware: impl Fn(&mut Request) + Send + Sync + 'static) -> RequestMiddleware {
+        RequestMiddleware(Arc::new(middleware))
+    }
+
+    pub(crate) fn apply(&self, request: &mut Request) {
+        (self.0)(request)
+    }
+}
+
+impl FmtDebug for RequestMiddleware {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "RequestMiddleware")
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ResponseHook(Arc<dyn Fn(&GitHubResponse) + Send + Sync>);
+
+impl ResponseHook {
+    pub(crate) fn new(hook: impl Fn(&GitHubResponse) + Send + Sync + 'static) -> ResponseHook {
+        ResponseHook(Arc::new(hook))
+    }
+
+    pub(crate) fn notify(&self, response: &GitHubResponse) {
+        (self.0)(response)
+    }
+}
+
+impl FmtDebug for ResponseHook {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "ResponseHook")
+    }
+}
@@ -7,10 +7,45 @@ use std::{
     }, 
 };
 
-use backoff::{
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+
+    collections::{BTreeMap},
+
+    io::{
+
+        Cursor,
+        Read,
+    },
+    time::{
+
+        Duration,
+        Instant,
+    },
 
-    ExponentialBackoff as BackoffExponential,
-    Error as BackoffError,
+    thread::{self},
+
+    sync::{
+
+        atomic::{
+
+            AtomicUsize,
+            Ordering,
+        },
+
+        Mutex,
+        Arc,
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use backoff::{Error as BackoffError};
+
+#[cfg(not(target_arch = "wasm32"))]
+use chrono::{
+
+    DateTime,
+    Utc,
 };
 
 pub use bytes::{Bytes};
@@ -24,27 +59,38 @@ use reqwest::{
         HeaderMap, 
     }, 
 
+    StatusCode,
+    Url, 
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{
+
     blocking::{
 
         multipart::{Form}, 
 
-        Client as ReqwestClient, 
-
         RequestBuilder,
         Response,
-        Request,
         Body, 
     },
 
-    Url, 
+    Client as AsyncReqwestClient,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use reqwest::{Method};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use reqwest::blocking::{Request};
+
 use secrecy::{
     
     ExposeSecret,
     Secret,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
 use http::{
 
     Version as HttpVersion,
@@ -61,12 +107,16 @@ use serde::{
     Serialize, 
 };
 
+#[cfg(not(target_arch = "wasm32"))]
 use crate::{
     
     repository::{HandleRepository},
+    app::{HandleApp},
 
     account::{
 
+        authenticated::{HandleAuthenticated},
+
         organization::{
         
             HandleOrganizationError,
@@ -83,18 +133,155 @@ use crate::{
         Account,
     },
 
-    models::common::user::{User},
+    models::common::{
+
+        repository::{Repository},
+        user::{User},
+        rate::{
+
+            RateLimits,
+            RateLimit,
+        },
+    },
+
+    common::{parse_date},
 
-    GitHubResult, 
     GitHubError,
+    Number,
+};
+
+use crate::{GitHubResult};
+
+pub mod cassette;
+
+pub use cassette::{
+
+    CassetteError,
+    CassetteMode,
+    Cassette,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use cassette::{
+
+    CassetteInteraction,
+    CassetteResponse,
+    CassetteRequest,
+    CassetteBody,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod builder;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod limiter;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod paginated;
+
+pub mod link;
+pub use link::{Links};
+
+pub mod media;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use paginated::{Paginated};
+
+#[cfg(not(target_arch = "wasm32"))]
+use limiter::{RequestLimiter};
+
+#[cfg(not(target_arch = "wasm32"))]
+use builder::{
+
+    WaitObserver,
+    LazyClient,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod middleware;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod transport;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use transport::{
+
+    MockTransport,
+    MockResponse,
+    Transport,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use middleware::{
+
+    RequestMiddleware,
+    ResponseHook,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use builder::{
+
+    ClientBuilder,
+    RetryPolicy,
+    ProxyConfig,
+    RetryWait,
+    TokenOrigin,
+
+    TOKEN_VARIABLES,
+
+    discover_token,
+};
+
+pub mod asynchronous;
+pub use asynchronous::{
+
+    AsyncRequestBuilder,
+    AsyncResponse,
+    AsyncClient,
 };
 
 pub type Token = Secret<String>;
 
+#[cfg(not(target_arch = "wasm32"))]
+const REPOSITORY_CONCURRENCY: usize = 8;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub enum RepositoryLookup {
+    Found { handle: HandleRepository, repository: Box<Repository> },
+    Failed(ClientError),
+    NotFound,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RepositoryLookup {
+    pub fn get_handle(&self) -> Option<HandleRepository> {
+        match self {
+            RepositoryLookup::Found { handle, .. } => Some(handle.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_repository(&self) -> Option<Repository> {
+        match self {
+            RepositoryLookup::Found { repository, .. } => Some(repository.as_ref().clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_found(&self) -> bool {
+        matches!(self, RepositoryLookup::Found { .. })
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, RepositoryLookup::NotFound)
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ClientRequestError {
-    #[error("Server is unavailable!")]
-    Unavailable,
+    #[error("Server is unavailable: '{endpoint}'")]
+    Unavailable { endpoint: String },
     #[error("Request could not be built!")]
     Build,
     #[error("Request could not be cloned!")]
@@ -103,71 +290,337 @@ pub enum ClientRequestError {
 
 #[derive(Error, Debug)]
 pub enum ClientResponseError {
-    #[error("Unautorized!")]
-    Unauthorized { code: u16, message: Option<String> },
-    #[error("Invalid user input!")]
-    Validation { code: u16, message: Option<String> },
-    #[error("Nothing was found!")]
-    Nothing { code: u16, message: Option<String> },
-    #[error("Unhandled error!")]
-    Unhandled { code: u16, message: Option<String> },
+    #[error("Unauthorized ({code}): '{endpoint}', message: {message:?}")]
+    Unauthorized { code: u16, endpoint: String, message: Option<String> },
+    #[error("Invalid user input ({code}): '{endpoint}', message: {message:?}")]
+    Validation { code: u16, endpoint: String, message: Option<String> },
+    #[error("Nothing was found ({code}): '{endpoint}', message: {message:?}")]
+    Nothing { code: u16, endpoint: String, message: Option<String> },
+    #[error("Unhandled error ({code}): '{endpoint}', message: {message:?}")]
+    Unhandled { code: u16, endpoint: String, message: Option<String> },
     #[error("Malformed response, reason: '{reason}'")]
     Malformed { reason: String },
+    #[error("Failed to deserialize response at '{path}', reason: '{reason}'")]
+    Deserialize { path: String, reason: String },
+    #[error("Query error: '{message}'")]
+    Query { message: String },
     #[error("Encoding error!")]
     Encoding,
 }
 
+impl ClientResponseError {
+    pub(crate) fn from_code(code: u16, endpoint: String, message: Option<String>) -> ClientResponseError {
+        match code {
+            401 | 403 => ClientResponseError::Unauthorized { code, endpoint, message },
+            404 => ClientResponseError::Nothing { code, endpoint, message },
+            422 => ClientResponseError::Validation { code, endpoint, message },
+            _ => ClientResponseError::Unhandled { code, endpoint, message },
+        }
+    }
+
+    pub fn get_code(&self) -> Option<u16> {
+        match self {
+            ClientResponseError::Unauthorized { code, .. } |
+            ClientResponseError::Validation { code, .. } |
+            ClientResponseError::Nothing { code, .. } |
+            ClientResponseError::Unhandled { code, .. } => Some(code.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            ClientResponseError::Unauthorized { endpoint, .. } |
+            ClientResponseError::Validation { endpoint, .. } |
+            ClientResponseError::Nothing { endpoint, .. } |
+            ClientResponseError::Unhandled { endpoint, .. } => Some(endpoint.as_str()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ClientError {
     #[error("Request error!")]
     Request(#[from] ClientRequestError),
     #[error("Response error!")]
     Response(#[from] ClientResponseError),
-    #[error("Failed to parse endpoint: {endpoint}")]
+    #[error("Failed to parse endpoint: '{endpoint}'")]
     ParseEndpoint { endpoint:  String },
+    #[error("Cassette error!")]
+    Cassette(#[from] CassetteError),
+    #[error("Invalid proxy: '{url}'")]
+    Proxy { url: String },
+    #[error("Invalid header: '{name}'")]
+    Header { name: String },
     #[error("Initialization error!")]
     Initialize,
 }
 
+impl ClientError {
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            ClientError::Request(ClientRequestError::Unavailable { endpoint }) => Some(endpoint.as_str()),
+            ClientError::Response(error) => error.get_endpoint(),
+            ClientError::ParseEndpoint { endpoint } => Some(endpoint.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Debug)]
+#[derive(Deserialize)]
+pub(crate) struct CapsuleMessage {
+    pub(crate) message: Option<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const SECONDARY_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+pub const DEFAULT_USER_AGENT: &str = "general-action";
+pub const DEFAULT_API_VERSION: &str = "2022-11-28";
+
+pub(crate) fn default_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(HeaderName::from_static("x-github-api-version"), {
+        HeaderValue::from_static(DEFAULT_API_VERSION)
+    });
+
+    headers.insert(HeaderName::from_static("accept"), {
+        HeaderValue::from_static(media::JSON)
+    });
+
+    headers.insert(HeaderName::from_static("user-agent"), {
+        HeaderValue::from_static(DEFAULT_USER_AGENT)
+    });
+
+    headers
+}
+
+pub(crate) const DEFAULT_BASE_URL: &str = "https://api.github.com/";
+
+pub(crate) fn build_endpoint(endpoint: impl AsRef<str>) -> GitHubResult<Url, ClientError> {
+    let endpoint = endpoint.as_ref();
+
+    if let Ok(ref base) = Url::parse(DEFAULT_BASE_URL) {
+        return join_endpoint(base, endpoint)
+    }
+    
+    Err(ClientError::ParseEndpoint {
+        endpoint: endpoint.to_owned()
+    })
+}
+
+pub(crate) fn join_endpoint(base: &Url, endpoint: impl AsRef<str>) -> GitHubResult<Url, ClientError> {
+    let endpoint = endpoint.as_ref();
+
+    base.join(endpoint.trim_start_matches('/')).map_err(|_| {
+        ClientError::ParseEndpoint { endpoint: endpoint.to_owned() }
+    })
+}
+
+pub(crate) fn is_same_origin(base: &Url, url: &Url) -> bool {
+    base.scheme() == url.scheme() && base.host_str() == url.host_str() && base.port_or_known_default() == url.port_or_known_default()
+}
+
+pub(crate) fn endpoint_of(url: &Url) -> String {
+    url.path()
+        .trim_start_matches('/')
+        .to_owned()
+}
+
+pub(crate) fn html_base_of(base: &Url) -> Url {
+    let mut url = base.clone();
+
+    if let Some(host) = url.host_str().and_then(|host| host.strip_prefix("api.")).map(str::to_owned) {
+        let _ = url.set_host(Some(host.as_str()));
+    }
+
+    let path = url.path()
+        .trim_end_matches('/')
+        .to_owned();
+
+    let path = path.strip_suffix("/api/v3")
+        .unwrap_or(path.as_str());
+
+    url.set_path(format!("{path}/").as_str());
+    url
+}
+
+pub(crate) fn graphql_url_of(base: &Url) -> Url {
+    let mut url = base.clone();
+
+    let path = url.path()
+        .trim_end_matches('/')
+        .to_owned();
+
+    let path = path.strip_suffix("/v3")
+        .filter(|api| api.ends_with("/api"))
+        .map(str::to_owned)
+        .unwrap_or(path);
+
+    url.set_path(format!("{path}/graphql").as_str());
+    url
+}
+
+pub(crate) fn cassette_endpoint_of(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", endpoint_of(url)),
+        None => endpoint_of(url),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub(crate) struct ClientInner {
+    pub(crate) client: LazyClient,
+    pub(crate) base: Url,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) token: Option<Token>,
+    pub(crate) origin: Option<TokenOrigin>,
+    pub(crate) cassette: Option<Arc<Cassette>>,
+    pub(crate) limiter: Option<Arc<RequestLimiter>>,
+    pub(crate) asynchronous: AsyncReqwestClient,
+    pub(crate) observer: Option<WaitObserver>,
+    pub(crate) transport: Option<Arc<dyn Transport>>,
+    pub(crate) middleware: Vec<RequestMiddleware>,
+    pub(crate) hooks: Vec<ResponseHook>,
+    pub(crate) debug: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone, Debug)]
 pub struct Client {
-    pub client: ReqwestClient,
-    pub token: Option<Token>,
+    pub(crate) inner: Arc<ClientInner>,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Client {
     pub fn new() -> GitHubResult<Client, GitHubError> {
-        Client::new_with_token(None::<String>)
+        Ok(Client::builder()
+            .with_discovered_token()
+            .build()?)
     }
 
     pub fn new_with_token(token: Option<impl AsRef<str>>) -> GitHubResult<Client, GitHubError> {
-        let mut headers = HeaderMap::new();
+        let builder = match token {
+            Some(token) => Client::builder().with_token(token),
+            None => Client::builder(),
+        };
 
-        headers.insert(HeaderName::from_static("x-github-api-version"), {
-            "2022-11-28".parse()
-                .unwrap()
-        });
+        Ok(builder.build()?)
+    }
 
-        headers.insert(HeaderName::from_static("accept"), {
-            "application/vnd.github+json".parse()
-                .unwrap()
-        });
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    pub fn with_debug(mut self, debug: bool) -> Client {
+        Arc::make_mut(&mut self.inner).debug = debug;
+        self
+    }
 
-        let client = ReqwestClient::builder().user_agent("general-action")
-            .default_headers(headers).build().map_err(|_| {
-                ClientError::Initialize
-            })?;
+    pub fn get_base_url(&self) -> Url {
+        self.inner.base.clone()
+    }
 
-        let token = token.and_then(|token| {
-            Some(Secret::new(token.as_ref()
-                .to_owned()))
-        });
+    pub fn get_async(&self) -> AsyncClient {
+        AsyncClient {
+            client: self.inner.asynchronous.clone(),
+            base: self.inner.base.clone(),
+            token: self.inner.token.clone(),
+            shared: self.clone(),
+        }
+    }
 
-        Ok(Client { 
-            
-            client, 
-            token,
-        })
+    pub fn get_html_url(&self) -> Url {
+        html_base_of(&(self.inner.base))
+    }
+
+    pub fn get_graphql_url(&self) -> Url {
+        graphql_url_of(&(self.inner.base))
+    }
+
+    pub fn get_retry(&self) -> RetryPolicy {
+        self.inner.retry
+    }
+
+    pub fn get_token_origin(&self) -> Option<TokenOrigin> {
+        self.inner.origin
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.inner.token.is_some()
+    }
+
+    pub fn get_max_in_flight(&self) -> Option<usize> {
+        self.inner.limiter.as_ref()
+            .and_then(|limiter| limiter.get_max_in_flight())
+    }
+
+    pub fn get_mutation_interval(&self) -> Option<Duration> {
+        self.inner.limiter.as_ref()
+            .and_then(|limiter| limiter.get_mutation_interval())
+    }
+
+    pub fn is_debug(&self) -> bool {
+        self.inner.debug
+    }
+
+    pub(crate) fn redact(&self, text: impl AsRef<str>) -> String {
+        let text = text.as_ref();
+
+        match self.inner.token {
+            Some(ref token) if !(token.expose_secret().is_empty()) => {
+                text.replace(token.expose_secret().as_str(), "[REDACTED]")
+            },
+            _ => text.to_owned(),
+        }
+    }
+
+    pub(crate) fn wait(&self, wait: &RetryWait) {
+        self.notify(wait);
+
+        thread::sleep(wait.wait);
+    }
+
+    pub(crate) fn notify(&self, wait: &RetryWait) {
+        if self.is_debug() {
+            eprintln!("[github] waiting endpoint={endpoint} status={status} attempt={attempt} wait={duration}ms total={total}ms",
+                endpoint = wait.endpoint, status = wait.status, attempt = wait.attempt,
+                duration = wait.wait.as_millis(), total = wait.total.as_millis());
+        }
+
+        if let Some(ref observer) = self.inner.observer {
+            observer.notify(wait);
+        }
+    }
+
+    pub(crate) fn log(&self, request: &Request, status: Option<u16>, started: Instant) {
+        let method = request.method();
+        let url = self.redact(request.url().as_str());
+
+        let status = status.map(|status| status.to_string())
+            .unwrap_or_else(|| "none".to_owned());
+
+        let duration = started.elapsed()
+            .as_millis();
+
+        let body = request.body()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| self.redact(String::from_utf8_lossy(bytes)))
+            .unwrap_or_default();
+
+        eprintln!("[github] method={method} url={url} status={status} duration={duration}ms body={body}");
+    }
+
+    pub fn authenticated(&self) -> HandleAuthenticated {
+        HandleAuthenticated::from(self)
+    }
+
+    pub fn app(&self) -> HandleApp {
+        HandleApp::from(self)
     }
 
     pub fn try_get_username(&self, name: impl AsRef<str>) -> GitHubResult<User, GitHubError> {
@@ -229,6 +682,77 @@ impl Client {
             .try_get_repository(name.clone())?)
     }
 
+    pub fn try_get_repositories(&self, names: &[impl AsRef<str> + Sync]) -> GitHubResult<BTreeMap<String, RepositoryLookup>, ClientError> {
+        let lookup = |name: &str| -> GitHubResult<RepositoryLookup, ClientError> {
+            let Some((owner, repository)) = name.split_once('/') else {
+                return Ok(RepositoryLookup::NotFound)
+            };
+
+            let result = {
+                self.get(format!("repos/{owner}/{repository}"))?
+                    .send()
+            };
+
+            let repository: Repository = match result {
+                Err(ClientError::Response(ClientResponseError::Nothing { .. })) => return Ok(RepositoryLookup::NotFound),
+                Err(error @ ClientError::Response(ClientResponseError::Unauthorized { .. })) => return Err(error),
+                Err(error) => return Ok(RepositoryLookup::Failed(error)),
+                Ok(response) => match response.json() {
+                    Err(error) => return Ok(RepositoryLookup::Failed(error)),
+                    Ok(repository) => repository,
+                },
+            };
+
+            let Some(owner) = Account::from_user(self, &(repository.get_owner())) else {
+                return Ok(RepositoryLookup::NotFound)
+            };
+
+            Ok(RepositoryLookup::Found {
+                handle: HandleRepository {
+                    name: repository.get_name().to_lowercase().into(),
+                    owner,
+                },
+                repository: Box::new(repository),
+            })
+        };
+
+        let collection = Mutex::new(BTreeMap::new());
+        let failure = Mutex::new(None);
+        let index = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..REPOSITORY_CONCURRENCY.min(names.len()) {
+                scope.spawn(|| loop {
+                    let Some(name) = names.get(index.fetch_add(1, Ordering::Relaxed)) else {
+                        break
+                    };
+
+                    let name = name.as_ref();
+                    match lookup(name) {
+                        Ok(result) => if let Ok(mut collection) = collection.lock() {
+                            collection.insert(name.to_owned(), result);
+                        },
+                        Err(error) => {
+                            if let Ok(mut failure) = failure.lock() {
+                                failure.get_or_insert(error);
+                            }
+
+                            index.store(names.len(), Ordering::Relaxed);
+                            break
+                        },
+                    }
+                });
+            }
+        });
+
+        if let Some(error) = failure.into_inner().ok().flatten() {
+            return Err(error)
+        }
+
+        Ok(collection.into_inner()
+            .unwrap_or_default())
+    }
+
     pub fn try_get_all_repositories(&self, name: impl AsRef<str>) -> GitHubResult<Vec<HandleRepository>, GitHubError> {
         let name = name.as_ref();
 
@@ -236,132 +760,249 @@ impl Client {
             .try_get_all_repositories()?)
     }
 
-    fn build_endpoint(endpoint: impl AsRef<str>) -> GitHubResult<Url, ClientError> {
-        let endpoint = endpoint.as_ref();
+    pub fn try_query<T>(&self, query: impl AsRef<str>, ref variables: impl Serialize) -> GitHubResult<T, ClientError>
+    where T: DeserializeOwned + FmtDebug {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleError {
+            #[serde(rename = "type")]
+            kind: Option<String>,
+            message: String,
+        }
 
-        if let Ok(url) = Url::parse("https://api.github.com") {
-            if let Ok(url) = url.join(endpoint) {
-                return Ok(url)
-            }
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule<D> {
+            data: Option<D>,
+            errors: Option<Vec<CapsuleError>>,
         }
-        
-        Err(ClientError::ParseEndpoint {
-            endpoint: endpoint.to_owned()
-        })
-    }
 
-    pub fn get(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
-        let endpoint = Client::build_endpoint(endpoint)?;
+        let ref payload = serde_json::json!({
+            "query": query.as_ref(),
+            "variables": variables,
+        });
 
-        Ok(match self.token {
-            Some(ref token) => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.get(endpoint)
-                        .bearer_auth(token.expose_secret()),
+        let Capsule { data, errors } = {
+
+            self.post(self.get_graphql_url())?
+                .json(payload)
+                .send()?
+                .json()?
+        };
+
+        let errors = errors.unwrap_or_default();
+        if let Some(CapsuleError { message, .. }) = errors.iter().find(|error| {
+            error.kind.as_deref() == Some("NOT_FOUND")
+        }) {
+            return Err(ClientError::Response({
+                ClientResponseError::Nothing { 
+                    code: 404, endpoint: "graphql".to_owned(), message: Some(message.to_owned()) 
                 }
-            },
-            None => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.get(endpoint),
+            }))
+        }
+
+        if errors.len() > 0 {
+            return Err(ClientError::Response({
+                ClientResponseError::Query { 
+                    message: errors.iter()
+                        .map(|CapsuleError { message, .. }| message.as_str())
+                        .collect::<Vec<_>>()
+                        .join("; ")
                 }
-            }
-        })
+            }))
+        }
+
+        match data {
+            Some(data) => Ok(data),
+            None => Err(ClientError::Response({
+                ClientResponseError::Malformed { 
+                    reason: "missing query data".to_owned() 
+                }
+            })),
+        }
     }
 
-    pub fn put(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
-        let endpoint = Client::build_endpoint(endpoint)?;
+    pub fn request(&self, method: Method, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
+        let ClientInner { client, base, token, .. } = { 
+            self.inner.as_ref() 
+        };
 
-        Ok(match self.token {
-            Some(ref token) => {
+        let endpoint = join_endpoint(base, endpoint)?;
+        let client = client.try_get()?;
+
+        Ok(match token {
+            Some(ref token) if is_same_origin(base, &(endpoint)) => {
                 GitHubRequestBuilder {
                     client: self.clone(),
-                    inner: self.client.put(endpoint)
+                    inner: client.request(method, endpoint)
                         .bearer_auth(token.expose_secret()),
                 }
             },
-            None => {
+            _ => {
                 GitHubRequestBuilder {
                     client: self.clone(),
-                    inner: self.client.put(endpoint),
+                    inner: client.request(method, endpoint),
                 }
             }
         })
     }
 
-    pub fn post(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
-        let endpoint = Client::build_endpoint(endpoint)?;
+    pub fn get(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
+        self.request(Method::GET, endpoint)
+    }
 
-        Ok(match self.token {
-            Some(ref token) => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.post(endpoint)
-                        .bearer_auth(token.expose_secret()),
-                }
-            },
-            None => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.post(endpoint),
-                }
-            }
-        })
+    pub fn put(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
+        self.request(Method::PUT, endpoint)
     }
 
-    pub fn patch(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
-        let endpoint = Client::build_endpoint(endpoint)?;
+    pub fn post(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
+        self.request(Method::POST, endpoint)
+    }
 
-        Ok(match self.token {
-            Some(ref token) => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.patch(endpoint)
-                        .bearer_auth(token.expose_secret()),
-                }
-            },
-            None => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.patch(endpoint),
-                }
-            }
-        })
+    pub fn patch(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
+        self.request(Method::PATCH, endpoint)
     }
 
     pub fn delete(&self, endpoint: impl AsRef<str>) -> GitHubResult<GitHubRequestBuilder, ClientError> {
-        let endpoint = Client::build_endpoint(endpoint)?;
+        self.request(Method::DELETE, endpoint)
+    }
 
-        Ok(match self.token {
-            Some(ref token) => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.delete(endpoint)
-                        .bearer_auth(token.expose_secret()),
-                }
-            },
+    pub fn try_get_rate_limit(&self) -> GitHubResult<RateLimits, ClientError> {
+        Ok(self.get("rate_limit")?
+            .send()?.json()?)
+    }
+
+    pub fn get_cassette(&self) -> Option<&Cassette> {
+        self.inner.cassette.as_deref()
+    }
+
+    pub fn with_middleware(mut self, middleware: impl Fn(&mut Request) + Send + Sync + 'static) -> Client {
+        Arc::make_mut(&mut self.inner).middleware.push(RequestMiddleware::new(middleware));
+        self
+    }
+
+    pub fn with_response_hook(mut self, hook: impl Fn(&GitHubResponse) + Send + Sync + 'static) -> Client {
+        Arc::make_mut(&mut self.inner).hooks.push(ResponseHook::new(hook));
+        self
+    }
+
+    pub fn execute(&self, request: Request) -> GitHubResult<GitHubResponse, ClientError> {
+        let (response, _) = self.execute_with(request, false)?;
+
+        Ok(response)
+    }
+
+    fn execute_with(&self, mut request: Request, stream: bool) -> GitHubResult<(GitHubResponse, Option<Response>), ClientError> {
+        for middleware in self.inner.middleware.iter() {
+            middleware.apply(&mut request);
+        }
+
+        let (response, stream) = self.execute_raw(request, stream)?;
+
+        for hook in self.inner.hooks.iter() {
+            hook.notify(&response);
+        }
+
+        Ok((response, stream))
+    }
+
+    fn execute_raw(&self, request: Request, stream: bool) -> GitHubResult<(GitHubResponse, Option<Response>), ClientError> {
+        let endpoint = endpoint_of(request.url());
+
+        let recording = self.get_recording(&(request));
+        if let Some(response) = self.try_replay(&(request), recording.as_ref())? {
+            return Ok((response, None))
+        }
+
+        let permit = self.inner.limiter.as_ref()
+            .map(|limiter| limiter.acquire(request.method()));
+
+        let (response, stream) = match self.inner.transport {
+            Some(ref transport) => (transport.execute(request)?, None),
             None => {
-                GitHubRequestBuilder {
-                    client: self.clone(),
-                    inner: self.client.delete(endpoint),
+                let response = self.inner.client.try_get()?.execute(request).map_err(|_| {
+                    ClientRequestError::Unavailable { endpoint }
+                })?;
+
+                match stream && recording.is_none() && response.status().is_success() {
+                    true => (GitHubResponse::from_parts(response.url().clone(), response.status(), {
+                        response.headers().clone()
+                    }, Bytes::new()), Some(response)),
+                    false => (GitHubResponse::try_from_response(response)?, None),
                 }
-            }
+            },
+        };
+
+        drop(permit);
+
+        self.try_record(recording, &(response))?;
+
+        Ok((response, stream))
+    }
+
+    fn get_recording(&self, request: &Request) -> Option<CassetteRequest> {
+        self.inner.cassette.as_ref().map(|_| CassetteRequest {
+            method: request.method().to_string(),
+            endpoint: cassette_endpoint_of(request.url()),
+            body: request.body()
+                .and_then(|body| body.as_bytes())
+                .map(CassetteBody::from_bytes),
         })
     }
 
-    pub fn execute(&self, request: Request) -> GitHubResult<GitHubResponse, ClientError> {
-        Ok(GitHubResponse::from(self.client.execute(request).map_err(|_| {
-            ClientRequestError::Unavailable
-        })?))
+    fn try_replay(&self, request: &Request, recorded: Option<&CassetteRequest>) -> GitHubResult<Option<GitHubResponse>, ClientError> {
+        let (Some(cassette), Some(recorded)) = (self.inner.cassette.as_ref(), recorded) else {
+            return Ok(None)
+        };
+
+        if !(cassette.is_replay()) {
+            return Ok(None)
+        }
+
+        let CassetteResponse { status, headers, body } = {
+            cassette.try_take(recorded)?
+        };
+
+        let status = StatusCode::from_u16(status).map_err(|_| {
+            ClientResponseError::Malformed { reason: format!("invalid status: {status}") }
+        })?;
+
+        let headers = headers.iter().filter_map(|(key, value)| {
+            Some((HeaderName::try_from(key.as_str()).ok()?, HeaderValue::try_from(value.as_str()).ok()?))
+        }).collect();
+
+        Ok(Some(GitHubResponse::from_parts(request.url().clone(), status, headers, {
+            Bytes::from(body.to_bytes())
+        })))
+    }
+
+    fn try_record(&self, recorded: Option<CassetteRequest>, response: &GitHubResponse) -> GitHubResult<(), ClientError> {
+        let (Some(cassette), Some(recorded)) = (self.inner.cassette.as_ref(), recorded) else {
+            return Ok(())
+        };
+
+        cassette.try_push(CassetteInteraction {
+            request: recorded,
+            response: CassetteResponse {
+                status: response.code(),
+                headers: response.headers.iter().filter_map(|(key, value)| {
+                    Some((key.as_str().to_owned(), value.to_str().ok()?.to_owned()))
+                }).collect(),
+                body: CassetteBody::from_bytes(response.body.as_ref()),
+            },
+        })?;
+
+        Ok(())
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub struct GitHubRequestBuilder {
     client: Client,
     inner: RequestBuilder,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl GitHubRequestBuilder {
     pub fn header<K, V>(self, key: K, value: V) -> GitHubRequestBuilder
     where <HeaderValue as TryFrom<V>>::Error: Into<HttpError>,
@@ -382,6 +1023,16 @@ impl GitHubRequestBuilder {
         }
     }
 
+    pub fn accept(self, media_type: impl AsRef<str>) -> GitHubRequestBuilder {
+        let media_type = media_type.as_ref();
+        let name = HeaderName::from_static("accept");
+
+        match HeaderValue::from_str(media_type) {
+            Ok(value) => self.headers(HeaderMap::from_iter([(name, value)])),
+            Err(_) => self.header(name, media_type),
+        }
+    }
+
     pub fn version(self, version: HttpVersion) -> GitHubRequestBuilder {
         GitHubRequestBuilder {
             inner: self.inner.version(version),
@@ -420,6 +1071,25 @@ impl GitHubRequestBuilder {
         }
     }
 
+    pub fn body_stream(self, reader: impl Read + Send + 'static, len: u64, content_type: impl AsRef<str>) -> GitHubRequestBuilder {
+        GitHubRequestBuilder {
+            inner: self.inner
+                .header("content-type", content_type.as_ref())
+                .header("content-length", len)
+                .body(Body::sized(reader, len)),
+            .. self
+        }
+    }
+
+    pub fn body_chunked(self, reader: impl Read + Send + 'static, content_type: impl AsRef<str>) -> GitHubRequestBuilder {
+        GitHubRequestBuilder {
+            inner: self.inner
+                .header("content-type", content_type.as_ref())
+                .body(Body::new(reader)),
+            .. self
+        }
+    }
+
     pub fn multipart(self, multipart: Form) -> GitHubRequestBuilder {
         GitHubRequestBuilder {
             inner: self.inner.multipart(multipart),
@@ -448,105 +1118,223 @@ impl GitHubRequestBuilder {
         }
     }
    
+    pub fn send_reader(self) -> GitHubResult<Box<dyn Read + Send>, ClientError> {
+        match self.send_with(true)? {
+            (_, Some(stream)) => Ok(Box::new(stream)),
+            (response, None) => Ok(Box::new(Cursor::new(response.body))),
+        }
+    }
+
     pub fn send(self) -> GitHubResult<GitHubResponse, ClientError> {
+        let (response, _) = self.send_with(false)?;
+
+        Ok(response)
+    }
+
+    fn send_with(self, stream: bool) -> GitHubResult<(GitHubResponse, Option<Response>), ClientError> {
         let request = {
             self.inner.build().map_err(|_| {
                 ClientRequestError::Build
             })?
         };
 
-        let client = self.client.clone();
-        let response = backoff::retry(BackoffExponential::default(), move || {
-            if let Some(request) = request.try_clone() {
-                return client.execute(request).map_err(|error| {
-                    BackoffError::transient(error)
+        let endpoint = endpoint_of(request.url());
+
+        let debug = if self.client.is_debug() { request.try_clone() } else { 
+            None 
+        };
+
+        let mut pending = Some(request);
+        let mut attempt = 0;
+        let mut total = Duration::ZERO;
+
+        let response = loop {
+
+            attempt = { attempt + 1 };
+
+            let started = Instant::now();
+            let response = backoff::retry(self.client.get_retry().to_backoff(), || {
+                let (request, retryable) = match pending.as_ref().and_then(Request::try_clone) {
+                    Some(request) => (request, true),
+                    None => match pending.take() {
+                        Some(request) => (request, false),
+                        None => return Err(BackoffError::permanent(ClientError::Request({
+                            ClientRequestError::Clone
+                        }))),
+                    },
+                };
+
+                self.client.execute_with(request, stream).map_err(|error| match error {
+                    error @ ClientError::Cassette(_) => BackoffError::permanent(error),
+                    error if !(retryable) => BackoffError::permanent(error),
+                    error => BackoffError::transient(error),
                 })
+            }).map_err(|error| match error {
+                BackoffError::Transient { err, .. } => err,
+                BackoffError::Permanent(err) => err,
+            });
+
+            if let Some(ref request) = debug {
+                self.client.log(request, response.as_ref().ok().map(|(response, _)| {
+                    response.code()
+                }), started);
             }
 
-            Err(BackoffError::transient(ClientError::Request({
-                ClientRequestError::Clone
-            })))
-            
-        }).map_err(|error| match error {
-            BackoffError::Transient { err, .. } => err,
-            BackoffError::Permanent(err) => err,
-        })?;
+            let wait = response.as_ref().ok()
+                .and_then(|(response, _)| response.get_retry_wait());
+
+            match wait {
+                Some(wait) if pending.is_some() && total + wait <= self.client.get_retry().get_max_wait() => {
+                    total = { total + wait };
+
+                    self.client.wait(&(RetryWait {
+                        endpoint: endpoint.clone(),
+                        status: response.as_ref().map(|(response, _)| response.code()).unwrap_or_default(),
+                        attempt,
+                        wait,
+                        total,
+                    }));
+                },
+                _ => break response,
+            }
+        };
+
+        let (response, stream) = response?;
 
         if response.is_success() { 
-            Ok(response) 
+            Ok((response, stream)) 
         } 
         
         else {
 
-            #[derive(Default, Debug)]
-            #[derive(Deserialize)]
-            struct Capsule {
-                message: Option<String>,
-            }
-
             let code = response.code();
-            let Capsule { message } = response.json()
+            let CapsuleMessage { message } = response.json()
                 .unwrap_or_default();
 
-            match code {
-                401 | 403 => Err(ClientError::Response({
-                    ClientResponseError::Unauthorized { 
-                        code, message 
-                    }
-                })),
-                404 => Err(ClientError::Response({
-                    ClientResponseError::Nothing { 
-                        code, message 
-                    }
-                })),
-                422 => Err(ClientError::Response({
-                    ClientResponseError::Validation { 
-                        code, message 
-                    }
-                })),
-                _ => Err(ClientError::Response({
-                    ClientResponseError::Unhandled { 
-                        code, message 
-                    }
-                })),
-            }
+            Err(ClientError::Response({
+                ClientResponseError::from_code(code, endpoint, message)
+            }))
         }
     }
 }
 
-#[derive(Debug)]
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
 pub struct GitHubResponse {
-    inner: Response,
+    url: Url,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl GitHubResponse {
-    pub fn from(response: Response) -> GitHubResponse {
-        GitHubResponse { inner: response }
+    pub fn try_from_response(response: Response) -> GitHubResult<GitHubResponse, ClientError> {
+        let url = response.url().clone();
+        let status = response.status();
+        let headers = response.headers()
+            .clone();
+
+        let body = response.bytes().map_err(|_| {
+            ClientResponseError::Encoding
+        })?;
+
+        Ok(GitHubResponse::from_parts(url, status, headers, body))
+    }
+
+    pub(crate) fn from_parts(url: Url, status: StatusCode, headers: HeaderMap, body: Bytes) -> GitHubResponse {
+        GitHubResponse { url, status, headers, body }
     }
 
     pub fn is_success(&self) -> bool {
-        self.inner.status()
-            .is_success()
+        self.status.is_success()
     }
 
     pub fn code(&self) -> u16 {
-        self.inner.status()
-            .as_u16()
+        self.status.as_u16()
     }
 
-    pub fn bytes(self) -> GitHubResult<Bytes, ClientError> {
-        let bytes = {
-            self.inner.bytes().map_err(|_| {
-                ClientResponseError::Encoding
-            })?
-        };
+    pub fn get_url(&self) -> &Url {
+        &(self.url)
+    }
+
+    pub fn get_headers(&self) -> &HeaderMap {
+        &(self.headers)
+    }
+
+    pub fn get_rate_limit(&self) -> Option<RateLimit> {
+        let header = |name: &str| self.headers.get(name)
+            .and_then(|value| value.to_str().ok());
+
+        let number = |name: &str| header(name)
+            .and_then(|value| value.parse::<Number>().ok());
+
+        Some(RateLimit {
+            limit: number("x-ratelimit-limit")?,
+            remaining: number("x-ratelimit-remaining")?,
+            used: number("x-ratelimit-used").unwrap_or_default(),
+            reset: header("x-ratelimit-reset").and_then(parse_date)?,
+            resource: header("x-ratelimit-resource").map(str::to_owned),
+        })
+    }
+
+    pub fn get_links(&self) -> Links {
+        self.headers.get("link")
+            .and_then(|value| value.to_str().ok())
+            .map(Links::parse)
+            .unwrap_or_default()
+    }
+
+    pub fn get_retry_after(&self) -> Option<Duration> {
+        let value = self.headers.get("retry-after")?
+            .to_str().ok()?.trim();
+
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds))
+        }
+
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
 
-        Ok(bytes)
+        Some((date.with_timezone(&(Utc)) - Utc::now()).to_std()
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn get_retry_wait(&self) -> Option<Duration> {
+        if !(matches!(self.code(), 403 | 429)) {
+            return None
+        }
+
+        if let Some(wait) = self.get_retry_after() {
+            return Some(wait)
+        }
+
+        if let Some(limit) = self.get_rate_limit().filter(RateLimit::is_exhausted) {
+            return Some(limit.get_wait())
+        }
+
+        match self.is_secondary_rate_limit() {
+            true => Some(SECONDARY_RATE_LIMIT_WAIT),
+            false => None,
+        }
+    }
+
+    pub fn is_secondary_rate_limit(&self) -> bool {
+        match self.code() {
+            429 => true,
+            403 => String::from_utf8_lossy(self.body.as_ref())
+                .to_lowercase()
+                .contains("secondary rate limit"),
+            _ => false,
+        }
+    }
+
+    pub fn bytes(self) -> GitHubResult<Bytes, ClientError> {
+        Ok(self.body)
     }
 
     pub fn text(self) -> GitHubResult<String, ClientError> {
         let text = {
-            self.inner.text().map_err(|_| {
+            String::from_utf8(self.body.to_vec()).map_err(|_| {
                 ClientResponseError::Encoding
             })?
         };
@@ -555,16 +1343,21 @@ impl GitHubResponse {
     }
 
     pub fn json<T: DeserializeOwned + FmtDebug>(self) -> GitHubResult<T, ClientError> {
-        let ref notation = {
-            self.inner.text().map_err(|_| {
-                ClientResponseError::Encoding
-            })?
-        };
-
-        Ok(serde_json::from_str(notation).map_err(|error| {
+        Ok(serde_json::from_slice(self.body.as_ref()).map_err(|error| {
             ClientResponseError::Malformed { 
                 reason: error.to_string() 
             }
         })?)
     }
+
+    pub fn json_with_path_errors<T: DeserializeOwned + FmtDebug>(self) -> GitHubResult<T, ClientError> {
+        let ref mut deserializer = serde_json::Deserializer::from_slice(self.body.as_ref());
+
+        Ok(serde_path_to_error::deserialize(deserializer).map_err(|error| {
+            ClientResponseError::Deserialize {
+                path: error.path().to_string(),
+                reason: error.into_inner().to_string(),
+            }
+        })?)
+    }
 }
@@ -0,0 +1,310 @@
+use std::{
+
+    fmt::{Debug as FmtDebug},
+    thread::{self},
+    time::{Duration},
+};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+};
+
+use serde_json::{Value};
+
+use crate::{
+
+    models::common::rate::{RateLimit},
+
+    client::{
+
+        ClientResponseError,
+        GitHubResponse,
+        ClientError,
+        Client,
+        Links,
+
+        media,
+    },
+
+    GitHubResult,
+};
+
+pub const DEFAULT_PER_PAGE: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct Paginated<T> {
+    pub(crate) items: Vec<T>,
+    pub(crate) page: usize,
+    pub(crate) per_page: usize,
+    pub(crate) total: Option<usize>,
+    pub(crate) links: Links,
+    pub(crate) rate_limit: Option<RateLimit>,
+}
+
+impl<T: DeserializeOwned + FmtDebug> Paginated<T> {
+    pub fn try_fetch(client: &Client, endpoint: impl AsRef<str>, query: &[(&str, String)], page: usize, per_page: usize) -> GitHubResult<Paginated<T>, ClientError> {
+        Paginated::try_fetch_as(client, endpoint, query, page, per_page, media::JSON)
+    }
+
+    pub fn try_fetch_as(client: &Client, endpoint: impl AsRef<str>, query: &[(&str, String)], page: usize, per_page: usize, media_type: &str) -> GitHubResult<Paginated<T>, ClientError> {
+        let mut query = query.to_vec();
+
+        query.push(("per_page", per_page.to_string()));
+        query.push(("page", page.to_string()));
+
+        let response = {
+            client.get(endpoint)?
+                .query(&(query))
+                .accept(media_type)
+                .send()?
+        };
+
+        Paginated::try_from_response(response, page, per_page)
+    }
+
+    pub fn try_fetch_all(client: &Client, endpoint: impl AsRef<str>, query: &[(&str, String)]) -> GitHubResult<Vec<T>, ClientError> {
+        Paginated::try_fetch_all_as(client, endpoint, query, media::JSON)
+    }
+
+    pub fn try_fetch_all_as(client: &Client, endpoint: impl AsRef<str>, query: &[(&str, String)], media_type: &str) -> GitHubResult<Vec<T>, ClientError> {
+        Paginated::try_fetch_all_paced(client, endpoint, query, media_type, None)
+    }
+
+    pub fn try_fetch_all_paced(client: &Client, endpoint: impl AsRef<str>, query: &[(&str, String)], media_type: &str, resource: Option<&str>) -> GitHubResult<Vec<T>, ClientError> {
+        let endpoint = endpoint.as_ref();
+
+        let mut collection = Vec::new();
+        let mut paginated = Paginated::<T>::try_fetch_as(client, endpoint, query, 1, DEFAULT_PER_PAGE, media_type)?;
+
+        loop {
+
+            let (page, per_page) = (paginated.page, paginated.per_page);
+            let last = paginated.is_last();
+            let next = paginated.links.get_next();
+            let pacing = paginated.get_pacing(resource);
+
+            collection.extend(paginated.into_items());
+
+            if last {
+                break
+            }
+
+            if let Some(pacing) = pacing {
+                thread::sleep(pacing);
+            }
+
+            paginated = match next {
+                Some(ref url) => Paginated::try_from_response(client.get(url.as_str())?.accept(media_type).send()?, {
+                    Links::page_of(url).unwrap_or(page + 1)
+                }, per_page)?,
+                None => Paginated::try_fetch_as(client, endpoint, query, page + 1, per_page, media_type)?,
+            };
+        }
+
+        Ok(collection)
+    }
+
+    fn try_from_response(response: GitHubResponse, page: usize, per_page: usize) -> GitHubResult<Paginated<T>, ClientError> {
+        let rate_limit = response.get_rate_limit();
+        let links = response.get_links();
+        let (items, total) = match response.code() {
+            204 => (Vec::new(), None),
+            _ => Paginated::<T>::try_unwrap(response.json()?)?,
+        };
+
+        Ok(Paginated {
+            items,
+            page,
+            per_page,
+            total,
+            links,
+            rate_limit,
+        })
+    }
+
+    fn try_unwrap(body: Value) -> GitHubResult<(Vec<T>, Option<usize>), ClientError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(default)]
+            total_count: Option<usize>,
+        }
+
+        let malformed = |error: serde_json::Error| ClientResponseError::Malformed {
+            reason: error.to_string()
+        };
+
+        match body {
+            Value::Array(_) => Ok((serde_json::from_value(body).map_err(malformed)?, None)),
+            Value::Object(mut object) => {
+                let Capsule { total_count } = serde_json::from_value(Value::Object(object.clone()))
+                    .map_err(malformed)?;
+
+                let key = object.iter()
+                    .find(|(_, value)| value.is_array())
+                    .map(|(key, _)| key.clone())
+                    .ok_or_else(|| ClientResponseError::Malformed {
+                        reason: "paginated response has no item list".to_owned()
+                    })?;
+
+                let items = object.remove(key.as_str())
+                    .unwrap_or_default();
+
+                Ok((serde_json::from_value(items).map_err(malformed)?, total_count))
+            },
+            _ => Err(ClientResponseError::Malformed {
+                reason: "paginated response is neither a list nor an object".to_owned()
+            }.into()),
+        }
+    }
+}
+
+impl<T> Paginated<T> {
+    pub fn get_items(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn get_page(&self) -> usize {
+        self.page
+    }
+
+    pub fn get_per_page(&self) -> usize {
+        self.per_page
+    }
+
+    pub fn get_total(&self) -> Option<usize> {
+        self.total
+    }
+
+    pub fn get_links(&self) -> Links {
+        self.links.clone()
+    }
+
+    pub fn get_rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit.clone()
+    }
+
+    pub fn get_pacing(&self, resource: Option<&str>) -> Option<Duration> {
+        let resource = resource?;
+
+        self.rate_limit.as_ref()
+            .filter(|limit| limit.resource.as_deref() == Some(resource))
+            .map(RateLimit::get_pacing)
+    }
+
+    pub fn get_last_page(&self) -> Option<usize> {
+        self.links.get_last_page()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn is_last(&self) -> bool {
+        if !(self.links.is_empty()) {
+            return !(self.links.has_next())
+        }
+
+        match self.total {
+            Some(total) if self.page * self.per_page >= total => true,
+            _ => self.items.len() < self.per_page,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::{
+
+        MockResponse,
+        MockTransport,
+        Method,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_unwrap() {
+        let (items, total) = Paginated::<usize>::try_unwrap(serde_json::json!([1, 2, 3])).unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(total, None);
+
+        let (items, total) = Paginated::<usize>::try_unwrap(serde_json::json!({
+            "total_count": 42,
+            "runners": [4, 5],
+        })).unwrap();
+
+        assert_eq!(items, vec![4, 5]);
+        assert_eq!(total, Some(42));
+
+        let paginated = Paginated { items, page: 1, per_page: 2, total, links: Links::default(), rate_limit: None };
+
+        assert!(!(paginated.is_last()));
+        assert!(Paginated { page: 21, .. paginated.clone() }.is_last());
+
+        let links = Links::parse("<https://api.github.com/repositories/1/issues?page=1>; rel=\"first\"");
+
+        assert!(Paginated { links, .. paginated }.is_last());
+    }
+
+    #[test]
+    fn test_follows_links() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "repos/dev-bio/octo/tags?per_page=100&page=1", MockResponse::json(200, serde_json::json!([1, 2]))
+                .with_header("link", "<https://api.github.com/repos/dev-bio/octo/tags?per_page=100&page=2>; rel=\"next\""))
+            .with_json(Method::GET, "repos/dev-bio/octo/tags?per_page=100&page=2", serde_json::json!([3]));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let items: Vec<usize> = Paginated::try_fetch_all(&(client), "repos/dev-bio/octo/tags", &[]).unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(transport.get_requests().len(), 2);
+    }
+
+    #[test]
+    fn test_paced() {
+        let reset = (chrono::Utc::now().timestamp() + 2).to_string();
+
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "search/code?q=octo&per_page=100&page=1", MockResponse::json(200, serde_json::json!({ "total_count": 3, "items": [1, 2] }))
+                .with_header("link", "<https://api.github.com/search/code?q=octo&per_page=100&page=2>; rel=\"next\"")
+                .with_header("x-ratelimit-resource", "code_search")
+                .with_header("x-ratelimit-limit", "10")
+                .with_header("x-ratelimit-remaining", "1")
+                .with_header("x-ratelimit-reset", reset.as_str()))
+            .with_json(Method::GET, "search/code?q=octo&per_page=100&page=2", serde_json::json!({ "total_count": 3, "items": [3] }));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let items: Vec<usize> = Paginated::try_fetch_all_paced(&(client), "search/code", &[("q", "octo".to_owned())], media::JSON, Some("code_search")).unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert!(started.elapsed() >= Duration::from_millis(400));
+        assert_eq!(transport.get_request_count(Method::GET, "search/code"), 2);
+
+        let paginated = Paginated::<usize> { items: Vec::new(), page: 1, per_page: 100, total: None, links: Links::default(), rate_limit: None };
+
+        assert_eq!(paginated.get_pacing(Some("code_search")), None);
+    }
+}
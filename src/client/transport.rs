@@ -0,0 +1,384 @@
+use std::{
+
+    fmt::{Debug as FmtDebug},
+    collections::{VecDeque},
+
+    sync::{
+
+        Mutex,
+        Arc,
+    },
+};
+
+use reqwest::{
+
+    blocking::{Request},
+
+    header::{
+
+        HeaderValue,
+        HeaderName,
+        HeaderMap,
+
+        AUTHORIZATION,
+    },
+
+    StatusCode,
+    Method,
+};
+
+use futures::{
+
+    future::{
+
+        BoxFuture,
+        self,
+    },
+};
+
+use bytes::{Bytes};
+
+use crate::{
+
+    client::{
+
+        cassette_endpoint_of,
+        endpoint_of,
+
+        ClientResponseError,
+        ClientRequestError,
+        GitHubResponse,
+        ClientError,
+    },
+
+    GitHubResult,
+};
+
+pub trait Transport: Send + Sync + FmtDebug {
+    fn execute(&self, request: Request) -> GitHubResult<GitHubResponse, ClientError>;
+
+    fn execute_async(&self, request: Request) -> BoxFuture<'_, GitHubResult<GitHubResponse, ClientError>> {
+        Box::pin(future::ready(self.execute(request)))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+    unavailable: bool,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> MockResponse {
+        MockResponse { status, headers: Vec::new(), body: Bytes::new(), unavailable: false }
+    }
+
+    pub fn unavailable() -> MockResponse {
+        MockResponse { unavailable: true, .. MockResponse::new(0) }
+    }
+
+    pub fn json(status: u16, body: serde_json::Value) -> MockResponse {
+        MockResponse::new(status)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+    }
+
+    pub fn with_header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> MockResponse {
+        self.headers.push((name.as_ref().to_owned(), value.as_ref().to_owned()));
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<Bytes>) -> MockResponse {
+        self.body = body.into();
+        self
+    }
+
+    pub fn get_status(&self) -> u16 {
+        self.status
+    }
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    method: Method,
+    endpoint: String,
+    responses: VecDeque<MockResponse>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<MockRoute>,
+    requests: Vec<(Method, String)>,
+    authorized: Vec<(Method, String)>,
+    bodies: Vec<(Method, String, Bytes)>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        Default::default()
+    }
+
+    pub fn with_response(self, method: Method, endpoint: impl AsRef<str>, response: MockResponse) -> MockTransport {
+        let endpoint = endpoint.as_ref()
+            .trim_start_matches('/')
+            .to_owned();
+
+        if let Ok(mut state) = self.state.lock() {
+            match state.routes.iter_mut().find(|route| route.method == method && route.endpoint == endpoint) {
+                Some(route) => route.responses.push_back(response),
+                None => state.routes.push(MockRoute {
+                    method,
+                    endpoint,
+                    responses: VecDeque::from([response]),
+                }),
+            }
+        }
+
+        self
+    }
+
+    pub fn with_json(self, method: Method, endpoint: impl AsRef<str>, body: serde_json::Value) -> MockTransport {
+        self.with_response(method, endpoint, MockResponse::json(200, body))
+    }
+
+    pub fn get_requests(&self) -> Vec<(Method, String)> {
+        self.state.lock()
+            .map(|state| state.requests.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn get_request_count(&self, method: Method, endpoint: impl AsRef<str>) -> usize {
+        let endpoint = endpoint.as_ref()
+            .trim_start_matches('/');
+
+        self.get_requests().iter()
+            .filter(|(candidate, path)| *candidate == method && path == endpoint)
+            .count()
+    }
+
+    pub fn get_request_body(&self, method: Method, endpoint: impl AsRef<str>) -> Option<serde_json::Value> {
+        let endpoint = endpoint.as_ref()
+            .trim_start_matches('/');
+
+        self.state.lock().ok()?
+            .bodies.iter().rev()
+            .find(|(candidate, path, _)| *candidate == method && path == endpoint)
+            .and_then(|(_, _, body)| serde_json::from_slice(body.as_ref()).ok())
+    }
+
+    pub fn get_authorized_count(&self, method: Method, endpoint: impl AsRef<str>) -> usize {
+        let endpoint = endpoint.as_ref()
+            .trim_start_matches('/');
+
+        self.state.lock()
+            .map(|state| state.authorized.iter()
+                .filter(|(candidate, path)| *candidate == method && path == endpoint)
+                .count())
+            .unwrap_or_default()
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: Request) -> GitHubResult<GitHubResponse, ClientError> {
+        let method = request.method().clone();
+        let endpoint = endpoint_of(request.url());
+        let query = cassette_endpoint_of(request.url());
+
+        let response = {
+            let mut state = self.state.lock().map_err(|_| {
+                ClientResponseError::Malformed { reason: "mock transport is poisoned".to_owned() }
+            })?;
+
+            state.requests.push((method.clone(), endpoint.clone()));
+
+            if request.headers().contains_key(AUTHORIZATION) {
+                state.authorized.push((method.clone(), endpoint.clone()));
+            }
+
+            if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+                state.bodies.push((method.clone(), endpoint.clone(), Bytes::copy_from_slice(body)));
+            }
+
+            let route = state.routes.iter_mut()
+                .filter(|route| route.method == method)
+                .find(|route| route.endpoint == query || route.endpoint == endpoint);
+
+            route.and_then(|route| match route.responses.len() {
+                0 => None,
+                1 => route.responses.front().cloned(),
+                _ => route.responses.pop_front(),
+            })
+        };
+
+        let MockResponse { status, headers, body, unavailable } = response.unwrap_or_else(|| {
+            MockResponse::json(404, serde_json::json!({ "message": "Not Found" }))
+        });
+
+        if unavailable {
+            return Err(ClientRequestError::Unavailable { endpoint }.into())
+        }
+
+        let status = StatusCode::from_u16(status).map_err(|_| {
+            ClientResponseError::Malformed { reason: format!("invalid status: {status}") }
+        })?;
+
+        let headers: HeaderMap = headers.iter().filter_map(|(key, value)| {
+            Some((HeaderName::try_from(key.as_str()).ok()?, HeaderValue::try_from(value.as_str()).ok()?))
+        }).collect();
+
+        Ok(GitHubResponse::from_parts(request.url().clone(), status, headers, body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::{Duration};
+
+    use crate::client::{
+
+        media,
+        Client,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_mock_transport() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "repos/dev-bio/octo", serde_json::json!({ "name": "octo" }))
+            .with_response(Method::DELETE, "repos/dev-bio/octo", MockResponse::new(403));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let body: serde_json::Value = client.get("repos/dev-bio/octo").unwrap()
+            .send().unwrap()
+            .json().unwrap();
+
+        assert_eq!(body["name"], "octo");
+
+        assert!(client.delete("repos/dev-bio/octo").unwrap().send().is_err());
+        assert!(client.get("repos/dev-bio/missing").unwrap().send().is_err());
+
+        assert_eq!(transport.get_request_count(Method::GET, "repos/dev-bio/octo"), 1);
+        assert_eq!(transport.get_requests().len(), 3);
+    }
+
+    #[test]
+    fn test_foreign_host_unauthorized() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "user", serde_json::json!({ "login": "octocat" }))
+            .with_response(Method::GET, "u/1", MockResponse::new(200).with_body("avatar"));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .with_token("secret")
+            .build()
+            .unwrap();
+
+        client.get("user").unwrap().send().unwrap();
+        client.get("https://avatars.githubusercontent.com/u/1").unwrap().send().unwrap();
+
+        assert_eq!(transport.get_authorized_count(Method::GET, "user"), 1);
+        assert_eq!(transport.get_request_count(Method::GET, "u/1"), 1);
+        assert_eq!(transport.get_authorized_count(Method::GET, "u/1"), 0);
+    }
+
+    #[test]
+    fn test_secondary_rate_limit() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "user", MockResponse::json(403, serde_json::json!({
+                "message": "You have exceeded a secondary rate limit.",
+            })).with_header("retry-after", "0"))
+            .with_json(Method::GET, "user", serde_json::json!({ "login": "octocat" }));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let body: serde_json::Value = client.get("user").unwrap()
+            .send().unwrap()
+            .json().unwrap();
+
+        assert_eq!(body["login"], "octocat");
+        assert_eq!(transport.get_request_count(Method::GET, "user"), 2);
+
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "user", MockResponse::json(403, serde_json::json!({
+                "message": "You have exceeded a secondary rate limit.",
+            })));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .with_max_wait(Duration::ZERO)
+            .build()
+            .unwrap();
+
+        assert!(client.get("user").unwrap().send().is_err());
+        assert_eq!(transport.get_request_count(Method::GET, "user"), 1);
+    }
+
+    #[test]
+    fn test_accept() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "repos/dev-bio/octo/commits/main", MockResponse::new(200)
+                .with_header("content-type", "application/vnd.github.diff")
+                .with_body("diff --git a/README.md b/README.md"));
+
+        let accepted = Arc::new(Mutex::new(Vec::new()));
+
+        let client = Client::builder()
+            .with_transport(transport)
+            .build()
+            .unwrap()
+            .with_middleware({
+                let accepted = accepted.clone();
+                move |request| {
+                    if let Ok(mut accepted) = accepted.lock() {
+                        accepted.extend(request.headers().get_all("accept").iter().filter_map(|value| {
+                            value.to_str().ok().map(str::to_owned)
+                        }));
+                    }
+                }
+            });
+
+        let diff = client.get("repos/dev-bio/octo/commits/main").unwrap()
+            .accept(media::PATCH)
+            .accept(media::DIFF)
+            .send().unwrap()
+            .text().unwrap();
+
+        assert!(diff.starts_with("diff --git"));
+        assert_eq!(accepted.lock().unwrap().as_slice(), [media::DIFF]);
+    }
+
+    #[test]
+    fn test_unclonable_body_keeps_error() {
+        let transport = MockTransport::new()
+            .with_response(Method::POST, "upload", MockResponse::unavailable())
+            .with_json(Method::POST, "upload", serde_json::json!({}));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let result = client.post("upload").unwrap()
+            .body_chunked(std::io::Cursor::new(b"payload".to_vec()), "application/octet-stream")
+            .send();
+
+        assert!(matches!(result, Err(ClientError::Request(ClientRequestError::Unavailable { .. }))));
+        assert_eq!(transport.get_request_count(Method::POST, "upload"), 1);
+    }
+}
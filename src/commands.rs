@@ -0,0 +1,285 @@
+use std::{
+
+    collections::{
+
+        BTreeMap,
+        HashMap,
+    },
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use crate::{
+
+    models::common::{
+
+        issue::comment::{Comment},
+        repository::{Permission},
+        user::{AuthorAssociation},
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+
+    repository::{
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    GitHubResult,
+};
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+pub struct Command {
+    name: String,
+    arguments: Vec<String>,
+    flags: BTreeMap<String, Option<String>>,
+}
+
+impl Command {
+    pub fn parse(line: impl AsRef<str>) -> Option<Command> {
+        let line = line.as_ref()
+            .trim();
+
+        let line = line.strip_prefix('/')?;
+        let mut tokens = tokenize(line)
+            .into_iter();
+
+        let name = tokens.next()
+            .filter(|name| !(name.is_empty()))?;
+
+        if !(name.chars().all(|character| character.is_alphanumeric() || character == '-' || character == '_')) {
+            return None
+        }
+
+        let mut command = Command { name, .. Default::default() };
+
+        for token in tokens {
+            if let Some(flag) = token.strip_prefix("--").filter(|flag| !(flag.is_empty())) {
+                match flag.split_once('=') {
+                    Some((key, value)) => command.flags.insert(key.to_owned(), Some(value.to_owned())),
+                    None => command.flags.insert(flag.to_owned(), None),
+                };
+            }
+
+            else if let Some(flags) = token.strip_prefix('-').filter(|flags| {
+                !(flags.is_empty()) && flags.chars().all(char::is_alphabetic)
+            }) {
+                for flag in flags.chars() {
+                    command.flags.insert(flag.to_string(), None);
+                }
+            }
+
+            else {
+                command.arguments.push(token);
+            }
+        }
+
+        Some(command)
+    }
+
+    pub fn parse_all(body: impl AsRef<str>) -> Vec<Command> {
+        let mut fenced = false;
+
+        body.as_ref().lines().filter_map(|line| {
+            if line.trim_start().starts_with("```") {
+                fenced = !(fenced);
+                return None
+            }
+
+            if fenced || line.trim_start().starts_with('>') { None } else {
+                Command::parse(line)
+            }
+        }).collect()
+    }
+
+    pub fn from_comment(comment: &Comment) -> Vec<Command> {
+        Command::parse_all(comment.get_body())
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn get_arguments(&self) -> &[String] {
+        self.arguments.as_slice()
+    }
+
+    pub fn get_argument(&self, index: usize) -> Option<&str> {
+        self.arguments.get(index)
+            .map(String::as_str)
+    }
+
+    pub fn get_flag(&self, flag: impl AsRef<str>) -> Option<&str> {
+        self.flags.get(flag.as_ref())
+            .and_then(Option::as_deref)
+    }
+
+    pub fn has_flag(&self, flag: impl AsRef<str>) -> bool {
+        self.flags.contains_key(flag.as_ref())
+    }
+
+    pub fn is(&self, name: impl AsRef<str>) -> bool {
+        self.name.eq_ignore_ascii_case(name.as_ref())
+    }
+}
+
+impl FmtDisplay for Command {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "/{name}", name = self.name)?;
+
+        for argument in self.arguments.iter() {
+            write!(fmt, " {argument}")?;
+        }
+
+        for (flag, value) in self.flags.iter() {
+            match value {
+                Some(value) => write!(fmt, " --{flag}={value}")?,
+                None => write!(fmt, " --{flag}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandPolicy {
+    requirements: HashMap<String, Permission>,
+    default: Permission,
+    association: AuthorAssociation,
+}
+
+impl Default for CommandPolicy {
+    fn default() -> CommandPolicy {
+        CommandPolicy {
+            requirements: HashMap::new(),
+            default: Permission::Write,
+            association: AuthorAssociation::Collaborator,
+        }
+    }
+}
+
+impl CommandPolicy {
+    pub fn new() -> CommandPolicy {
+        Default::default()
+    }
+
+    pub fn with_default(mut self, permission: Permission) -> CommandPolicy {
+        self.default = permission;
+        self
+    }
+
+    pub fn with_requirement(mut self, name: impl AsRef<str>, permission: Permission) -> CommandPolicy {
+        self.requirements.insert(name.as_ref().to_lowercase(), permission);
+        self
+    }
+
+    pub fn with_association(mut self, association: AuthorAssociation) -> CommandPolicy {
+        self.association = association;
+        self
+    }
+
+    pub fn get_association(&self) -> AuthorAssociation {
+        self.association
+    }
+
+    pub fn is_allowed_association(&self, association: AuthorAssociation) -> bool {
+        association.satisfies(self.association)
+    }
+
+    pub fn is_comment_allowed(&self, comment: &Comment) -> bool {
+        comment.get_author_association()
+            .map(|association| self.is_allowed_association(association))
+            .unwrap_or(false)
+    }
+
+    pub fn get_requirement(&self, command: &Command) -> Permission {
+        self.requirements.get(&(command.name.to_lowercase()))
+            .cloned()
+            .unwrap_or(self.default)
+    }
+
+    pub fn is_allowed(&self, command: &Command, permission: Permission) -> bool {
+        permission.satisfies(self.get_requirement(command))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_authorize(&self, repository: &HandleRepository, user: impl AsRef<str>, command: &Command) -> GitHubResult<bool, HandleRepositoryError> {
+        let permission = repository.try_get_collaborator_permission(user)?;
+        Ok(self.is_allowed(command, permission))
+    }
+}
+
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut pending = false;
+
+    for character in line.chars() {
+        match (quote, character) {
+            (Some(open), character) if character == open => quote = None,
+            (Some(_), character) => current.push(character),
+            (None, '"' | '\'') => {
+                quote = Some(character);
+                pending = true;
+            },
+            (None, character) if character.is_whitespace() => {
+                if pending || !(current.is_empty()) {
+                    tokens.push(std::mem::take(&mut current));
+                    pending = false;
+                }
+            },
+            (None, character) => current.push(character),
+        }
+    }
+
+    if pending || !(current.is_empty()) {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let commands = Command::parse_all("Please run:\n/deploy prod \"eu west\" --force --wait=30 -qv\n```\n/ignored\n```\n> /quoted\n/label bug");
+
+        assert_eq!(commands.len(), 2);
+
+        let deploy = &(commands[0]);
+
+        assert!(deploy.is("deploy"));
+        assert_eq!(deploy.get_arguments(), &["prod".to_owned(), "eu west".to_owned()]);
+        assert!(deploy.has_flag("force"));
+        assert_eq!(deploy.get_flag("wait"), Some("30"));
+        assert!(deploy.has_flag("q") && deploy.has_flag("v"));
+
+        let policy = CommandPolicy::new()
+            .with_requirement("label", Permission::Triage);
+
+        assert!(policy.is_allowed(&(commands[1]), Permission::Triage));
+        assert!(!(policy.is_allowed(deploy, Permission::Triage)));
+
+        assert!(policy.is_allowed_association(AuthorAssociation::Owner));
+        assert!(!(policy.is_allowed_association(AuthorAssociation::FirstTimeContributor)));
+
+        let policy = policy.with_association(AuthorAssociation::Contributor);
+
+        assert!(policy.is_allowed_association(AuthorAssociation::Contributor));
+        assert!(!(policy.is_allowed_association(AuthorAssociation::None)));
+    }
+}
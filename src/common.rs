@@ -1,9 +1,151 @@
 use chrono::{
 
+    NaiveDateTime as ChronoNaiveDateTime,
+    NaiveDate as ChronoNaiveDate,
     Duration as ChronoDuration,
     DateTime as ChronoDateTime,
     Utc as ChronoUtc,
 };
 
+use serde::{
+
+    de::{Error as DeError},
+
+    Deserializer,
+    Deserialize,
+};
+
 pub type Duration = ChronoDuration;
-pub type Date = ChronoDateTime<ChronoUtc>;
\ No newline at end of file
+pub type Date = ChronoDateTime<ChronoUtc>;
+
+pub(crate) fn parse_date(text: &str) -> Option<Date> {
+    let text = text.trim();
+
+    if let Ok(date) = ChronoDateTime::parse_from_rfc3339(text) {
+        return Some(date.with_timezone(&(ChronoUtc)))
+    }
+
+    if let Ok(seconds) = text.parse::<i64>() {
+        return from_epoch(seconds)
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S %z", "%Y/%m/%d %H:%M:%S %z"] {
+        if let Ok(date) = ChronoDateTime::parse_from_str(text, format) {
+            return Some(date.with_timezone(&(ChronoUtc)))
+        }
+    }
+
+    let text = text.trim_end_matches(" UTC")
+        .trim_end_matches('Z');
+
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(date) = ChronoNaiveDateTime::parse_from_str(text, format) {
+            return Some(date.and_utc())
+        }
+    }
+
+    ChronoNaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+}
+
+fn from_epoch(value: i64) -> Option<Date> {
+    match value.unsigned_abs() >= 100_000_000_000 {
+        true => ChronoDateTime::from_timestamp_millis(value),
+        false => ChronoDateTime::from_timestamp(value, 0),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DateCapsule {
+    Seconds(i64),
+    Fractional(f64),
+    Text(String),
+}
+
+impl DateCapsule {
+    fn to_date(&self) -> Option<Date> {
+        match self {
+            DateCapsule::Seconds(seconds) => from_epoch(*seconds),
+            DateCapsule::Fractional(seconds) => from_epoch(seconds.trunc() as i64),
+            DateCapsule::Text(text) => parse_date(text.as_str()),
+        }
+    }
+}
+
+pub(crate) fn deserialize_date<'de, D>(deserializer: D) -> Result<Date, D::Error>
+where D: Deserializer<'de> {
+    let capsule = DateCapsule::deserialize(deserializer)?;
+
+    capsule.to_date().ok_or_else(|| match capsule {
+        DateCapsule::Text(text) => DeError::custom(format!("invalid date: '{text}'")),
+        _ => DeError::custom("invalid timestamp"),
+    })
+}
+
+pub(crate) fn deserialize_optional_date<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+where D: Deserializer<'de> {
+    match Option::<DateCapsule>::deserialize(deserializer)? {
+        Some(DateCapsule::Text(text)) if text.trim().is_empty() => Ok(None),
+        Some(capsule) => capsule.to_date().map(Some).ok_or_else(|| {
+            DeError::custom("invalid date")
+        }),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize};
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Capsule {
+        #[serde(deserialize_with = "deserialize_date")]
+        date: Date,
+        #[serde(default, deserialize_with = "deserialize_optional_date")]
+        optional: Option<Date>,
+    }
+
+    fn parse(value: serde_json::Value) -> Capsule {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_date() {
+        let expected: Date = "2024-03-05T10:20:30Z".parse().unwrap();
+
+        assert_eq!(parse(serde_json::json!({ "date": "2024-03-05T10:20:30Z" })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": "2024-03-05T11:20:30+01:00" })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": "2024-03-05 10:20:30 UTC" })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": "2024-03-05 10:20:30 +0000" })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": 1709634030 })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": 1709634030000i64 })).date, expected);
+        assert_eq!(parse(serde_json::json!({ "date": "1709634030" })).date, expected);
+
+        assert!(serde_json::from_value::<Capsule>(serde_json::json!({ "date": "yesterday" })).is_err());
+        assert!(serde_json::from_value::<Capsule>(serde_json::json!({ "date": null })).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_optional_date() {
+        let expected: Date = "2024-03-05T00:00:00Z".parse().unwrap();
+
+        let capsule = parse(serde_json::json!({ "date": 0 }));
+        assert_eq!(capsule.optional, None);
+
+        let capsule = parse(serde_json::json!({ "date": 0, "optional": null }));
+        assert_eq!(capsule.optional, None);
+
+        let capsule = parse(serde_json::json!({ "date": 0, "optional": "" }));
+        assert_eq!(capsule.optional, None);
+
+        let capsule = parse(serde_json::json!({ "date": 0, "optional": "2024-03-05" }));
+        assert_eq!(capsule.optional, Some(expected));
+
+        let capsule = parse(serde_json::json!({ "date": 0, "optional": 1709596800 }));
+        assert_eq!(capsule.optional, Some(expected));
+    }
+}
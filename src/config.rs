@@ -0,0 +1,388 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    },
+
+    path::{PathBuf, Path},
+    time::{Duration},
+    process::{Command},
+    env::{self},
+    fs::{self},
+};
+
+use thiserror::{Error};
+use serde::{Deserialize};
+
+use crate::{
+
+    client::{
+
+        ClientBuilder,
+        ClientError,
+        RetryPolicy,
+        Client,
+    },
+
+    GitHubResult,
+};
+
+pub const CONFIG_FILE: &str = ".octo.toml";
+pub const USER_CONFIG_FILE: &str = "octo/config.toml";
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Failed to read configuration: '{path:?}'")]
+    Read { path: PathBuf },
+    #[error("Failed to parse configuration: '{path:?}', reason: '{reason}'")]
+    Parse { path: PathBuf, reason: String },
+    #[error("Invalid configuration, reason: '{reason}'")]
+    Invalid { reason: String },
+    #[error("Failed to resolve token, reason: '{reason}'")]
+    Token { reason: String },
+    #[error("Refusing untrusted configuration: '{path:?}', reason: '{reason}'")]
+    Untrusted { path: PathBuf, reason: String },
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+pub enum ConfigOrigin {
+    #[default]
+    Explicit,
+    User,
+    Project { path: PathBuf },
+}
+
+#[derive(Clone)]
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenSource {
+    Env(String),
+    Command(Vec<String>),
+    Value(String),
+}
+
+impl FmtDebug for TokenSource {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            TokenSource::Env(name) => fmt.debug_tuple("Env").field(name).finish(),
+            TokenSource::Command(command) => fmt.debug_tuple("Command").field(command).finish(),
+            TokenSource::Value(_) => fmt.debug_tuple("Value").field(&("[REDACTED]")).finish(),
+        }
+    }
+}
+
+impl TokenSource {
+    pub fn try_resolve(&self) -> GitHubResult<String, ConfigError> {
+        match self {
+            TokenSource::Value(token) => Ok(token.clone()),
+            TokenSource::Env(name) => env::var(name).map_err(|_| ConfigError::Token {
+                reason: format!("environment variable '{name}' is not set")
+            }),
+            TokenSource::Command(command) => {
+                let (program, arguments) = command.split_first().ok_or_else(|| ConfigError::Token {
+                    reason: "empty token command".to_owned()
+                })?;
+
+                let output = Command::new(program).args(arguments).output().map_err(|_| ConfigError::Token {
+                    reason: format!("failed to run '{program}'")
+                })?;
+
+                if !(output.status.success()) {
+                    return Err(ConfigError::Token {
+                        reason: format!("'{program}' exited with {status}", status = output.status)
+                    })
+                }
+
+                Ok(String::from_utf8_lossy(output.stdout.as_slice())
+                    .trim().to_owned())
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Deserialize)]
+pub struct RetryConfig {
+    initial_interval_ms: Option<u64>,
+    max_interval_ms: Option<u64>,
+    max_elapsed_secs: Option<u64>,
+    max_wait_secs: Option<u64>,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl RetryConfig {
+    pub fn to_policy(&self) -> RetryPolicy {
+        if !(self.enabled) {
+            return RetryPolicy::disabled()
+        }
+
+        let mut policy = RetryPolicy::new();
+
+        if let Some(interval) = self.initial_interval_ms {
+            policy = policy.with_initial_interval(Duration::from_millis(interval));
+        }
+
+        if let Some(interval) = self.max_interval_ms {
+            policy = policy.with_max_interval(Duration::from_millis(interval));
+        }
+
+        if let Some(elapsed) = self.max_elapsed_secs {
+            policy = policy.with_max_elapsed(Some(Duration::from_secs(elapsed)));
+        }
+
+        if let Some(wait) = self.max_wait_secs {
+            policy = policy.with_max_wait(Duration::from_secs(wait));
+        }
+
+        policy
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Deserialize)]
+pub struct Config {
+    token: Option<TokenSource>,
+    base_url: Option<String>,
+    retry: Option<RetryConfig>,
+    organization: Option<String>,
+    #[serde(skip)]
+    origin: ConfigOrigin,
+    #[serde(skip)]
+    env_base_url: bool,
+}
+
+impl Config {
+    pub fn parse(content: impl AsRef<str>) -> GitHubResult<Config, ConfigError> {
+        toml::from_str(content.as_ref()).map_err(|error| {
+            ConfigError::Invalid { reason: error.to_string() }
+        })
+    }
+
+    pub fn try_from_path(path: impl AsRef<Path>) -> GitHubResult<Config, ConfigError> {
+        let path = path.as_ref();
+
+        let content = fs::read_to_string(path).map_err(|_| {
+            ConfigError::Read { path: path.to_owned() }
+        })?;
+
+        Config::parse(content).map_err(|error| match error {
+            ConfigError::Invalid { reason } => ConfigError::Parse { path: path.to_owned(), reason },
+            error => error,
+        })
+    }
+
+    pub fn try_from_origin(path: impl AsRef<Path>, origin: ConfigOrigin) -> GitHubResult<Config, ConfigError> {
+        Ok(Config { origin, ..Config::try_from_path(path)? })
+    }
+
+    pub fn find(start: impl AsRef<Path>) -> Option<PathBuf> {
+        start.as_ref().ancestors()
+            .map(|directory| directory.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    pub fn find_user() -> Option<PathBuf> {
+        let directory = match env::var_os("XDG_CONFIG_HOME") {
+            Some(directory) => PathBuf::from(directory),
+            None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+
+        Some(directory.join(USER_CONFIG_FILE))
+            .filter(|path| path.is_file())
+    }
+
+    pub fn try_load() -> GitHubResult<Config, ConfigError> {
+        Config::try_load_from(None)
+    }
+
+    pub fn try_load_from(path: Option<PathBuf>) -> GitHubResult<Config, ConfigError> {
+        let path = path.or_else(|| {
+            env::var_os("OCTO_CONFIG")
+                .map(PathBuf::from)
+        });
+
+        let config = match path {
+            Some(path) => Config::try_from_origin(path, ConfigOrigin::Explicit)?,
+            None => match env::current_dir().ok().and_then(Config::find) {
+                Some(path) => Config::try_from_origin(&(path), ConfigOrigin::Project { path: path.clone() })?,
+                None => match Config::find_user() {
+                    Some(path) => Config::try_from_origin(path, ConfigOrigin::User)?,
+                    None => Config::default(),
+                },
+            },
+        };
+
+        Ok(config.with_env())
+    }
+
+    pub fn with_env(mut self) -> Config {
+        if env::var_os("OCTO_TOKEN").is_some() {
+            self.token = Some(TokenSource::Env("OCTO_TOKEN".to_owned()));
+        }
+
+        else if self.token.is_none() && env::var_os("GITHUB_TOKEN").is_some() {
+            self.token = Some(TokenSource::Env("GITHUB_TOKEN".to_owned()));
+        }
+
+        if let Ok(url) = env::var("OCTO_BASE_URL") {
+            self.base_url = Some(url);
+            self.env_base_url = true;
+        }
+
+        else if self.base_url.is_none() {
+            self.base_url = env::var("GITHUB_API_URL").ok();
+            self.env_base_url = self.base_url.is_some();
+        }
+
+        if let Ok(organization) = env::var("OCTO_ORGANIZATION") {
+            self.organization = Some(organization);
+        }
+
+        self
+    }
+
+    pub fn get_token(&self) -> Option<TokenSource> {
+        self.token.clone()
+    }
+
+    pub fn get_base_url(&self) -> Option<String> {
+        self.base_url.clone()
+    }
+
+    pub fn get_retry(&self) -> RetryPolicy {
+        self.retry.as_ref()
+            .map(RetryConfig::to_policy)
+            .unwrap_or_default()
+    }
+
+    pub fn get_organization(&self) -> Option<String> {
+        self.organization.clone()
+    }
+
+    pub fn get_origin(&self) -> ConfigOrigin {
+        self.origin.clone()
+    }
+
+    pub fn try_validate(&self) -> GitHubResult<(), ConfigError> {
+        let ConfigOrigin::Project { ref path } = self.origin else {
+            return Ok(())
+        };
+
+        match self.token {
+            Some(TokenSource::Command(_)) => Err(ConfigError::Untrusted {
+                reason: format!("token commands are only honored from '{CONFIG_FILE}' passed explicitly or from the user configuration"),
+                path: path.clone(),
+            }),
+            Some(TokenSource::Env(ref name)) if self.base_url.is_some() && !(self.env_base_url) => Err(ConfigError::Untrusted {
+                reason: format!("refusing to send '{name}' to a base url set by a discovered project configuration"),
+                path: path.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn to_builder(&self) -> GitHubResult<ClientBuilder, ConfigError> {
+        self.try_validate()?;
+
+        let mut builder = Client::builder()
+            .with_retry(self.get_retry());
+
+        if let Some(ref source) = self.token {
+            builder = builder.with_token(source.try_resolve()?);
+        }
+
+        if let Some(ref url) = self.base_url {
+            builder = builder.with_base_url(url);
+        }
+
+        Ok(builder)
+    }
+
+    pub fn try_build_client(&self) -> GitHubResult<Client, ConfigError> {
+        Ok(self.to_builder()?.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(r#"
+            base_url = "https://github.example.com/api/v3"
+            organization = "dev-bio"
+            token = { env = "EXAMPLE_TOKEN" }
+
+            [retry]
+            max_elapsed_secs = 30
+            max_wait_secs = 120
+        "#).unwrap();
+
+        assert_eq!(config.get_organization(), Some("dev-bio".to_owned()));
+        assert_eq!(config.get_base_url(), Some("https://github.example.com/api/v3".to_owned()));
+        assert_eq!(config.get_retry().get_max_elapsed(), Some(Duration::from_secs(30)));
+        assert_eq!(config.get_retry().get_max_wait(), Duration::from_secs(120));
+        assert!(matches!(config.get_token(), Some(TokenSource::Env(name)) if name == "EXAMPLE_TOKEN"));
+
+        let config = Config::parse("[retry]\nenabled = false").unwrap();
+        assert_eq!(config.get_retry(), RetryPolicy::disabled());
+    }
+
+    #[test]
+    fn test_graphql_url() {
+        let config = Config::parse(r#"base_url = "https://github.example.com/api/v3""#).unwrap();
+        let client = config.try_build_client().unwrap();
+
+        assert_eq!(client.get_graphql_url().as_str(), "https://github.example.com/api/graphql");
+
+        let client = Config::default().try_build_client().unwrap();
+        assert_eq!(client.get_graphql_url().as_str(), "https://api.github.com/graphql");
+    }
+
+    #[test]
+    fn test_token_redacted() {
+        let config = Config::parse(r#"token = { value = "ghp_secret" }"#).unwrap();
+        let debug = format!("{config:?}");
+
+        assert!(!(debug.contains("ghp_secret")));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(matches!(Config::parse("token = 1"), Err(ConfigError::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_untrusted() {
+        let project = ConfigOrigin::Project { path: PathBuf::from(CONFIG_FILE) };
+
+        let config = Config { origin: project.clone(), ..Config::parse(r#"token = { command = ["gh", "auth", "token"] }"#).unwrap() };
+        assert!(matches!(config.to_builder(), Err(ConfigError::Untrusted { .. })));
+
+        let config = Config { origin: ConfigOrigin::User, ..config };
+        assert!(config.try_validate().is_ok());
+
+        let content = r#"
+            base_url = "https://attacker.example.com"
+            token = { env = "GITHUB_TOKEN" }
+        "#;
+
+        let config = Config { origin: project.clone(), ..Config::parse(content).unwrap() };
+        assert!(matches!(config.try_build_client(), Err(ConfigError::Untrusted { .. })));
+
+        let config = Config { env_base_url: true, ..config };
+        assert!(config.try_validate().is_ok());
+
+        let config = Config { origin: project, ..Config::parse(r#"token = { env = "GITHUB_TOKEN" }"#).unwrap() };
+        assert!(config.try_validate().is_ok());
+    }
+}
@@ -1,27 +1,107 @@
 #![allow(unused_braces)]
 #![allow(dead_code)]
 
+#[cfg(not(target_arch = "wasm32"))]
 use std::borrow::{Cow};
+#[cfg(not(target_arch = "wasm32"))]
 use std::fmt::{Debug as FmtDebug};
+#[cfg(not(target_arch = "wasm32"))]
+use std::future::{Future};
 
+#[cfg(not(target_arch = "wasm32"))]
 pub mod repository;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod account;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod config;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod actions;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod app;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod storage;
+pub mod commands;
 pub mod client;
 pub mod common;
 pub mod models;
 
-use account::{AccountError};
+#[cfg(target_arch = "wasm32")]
+pub mod repository {
+    pub mod sha;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use account::{
+
+    authenticated::{HandleAuthenticatedError},
+    organization::{
+        
+        migration::{HandleMigrationError},
+        team::{HandleTeamError},
+        
+        HandleOrganizationError,
+    },
+    user::{HandleUserError},
+
+    AccountError,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use config::{ConfigError};
+
+#[cfg(not(target_arch = "wasm32"))]
+use actions::{CommandError};
+
+#[cfg(not(target_arch = "wasm32"))]
+use app::{HandleAppError};
 
+#[cfg(not(target_arch = "wasm32"))]
+use storage::{StorageError};
+
+#[cfg(not(target_arch = "wasm32"))]
 use client::{
 
     ClientError, 
     Client,
 };
 
-use repository::{HandleRepositoryError};
+#[cfg(not(target_arch = "wasm32"))]
+use repository::{
+
+    discussion::{
+        
+        comment::{DiscussionCommentError},
+        
+        DiscussionError,
+    },
+
+    import::{ImportError},
+    run::{WorkflowRunError},
+
+    issue::{
+        
+        comment::{IssueCommentError},
+        
+        IssueError,
+    },
+
+    commit::{
+
+        CompareError,
+        CommitError,
+    },
+
+    reference::{ReferenceError},
+    blob::{BlobError},
+    tree::{TreeError},
 
+    HandleRepositoryError,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
 use thiserror::{Error};
 
+#[cfg(not(target_arch = "wasm32"))]
 use serde::{
 
     de::{DeserializeOwned},
@@ -31,16 +111,64 @@ use serde::{
 
 pub type Number = usize;
 
+#[cfg(not(target_arch = "wasm32"))]
 #[derive(Debug, Error)]
 pub enum GitHubError {
     #[error("Account error!")]
     Account(#[from] AccountError),
     #[error("Client error!")]
     Client(#[from] ClientError),
+    #[error("Configuration error!")]
+    Config(#[from] ConfigError),
+    #[error("Workflow command error!")]
+    Command(#[from] CommandError),
+    #[error("App error!")]
+    App(#[from] HandleAppError),
+    #[error("Storage error!")]
+    Storage(#[from] StorageError),
+    #[error("Authenticated user error!")]
+    Authenticated(#[from] HandleAuthenticatedError),
+    #[error("Organization error!")]
+    Organization(#[from] HandleOrganizationError),
+    #[error("Team error!")]
+    Team(#[from] HandleTeamError),
+    #[error("Migration error!")]
+    Migration(#[from] HandleMigrationError),
+    #[error("User error!")]
+    User(#[from] HandleUserError),
+    #[error("Repository error!")]
+    Repository(#[from] HandleRepositoryError),
+    #[error("Reference error!")]
+    Reference(#[from] ReferenceError),
+    #[error("Commit error!")]
+    Commit(#[from] CommitError),
+    #[error("Compare error!")]
+    Compare(#[from] CompareError),
+    #[error("Issue error!")]
+    Issue(#[from] IssueError),
+    #[error("Issue comment error!")]
+    IssueComment(#[from] IssueCommentError),
+    #[error("Discussion error!")]
+    Discussion(#[from] DiscussionError),
+    #[error("Discussion comment error!")]
+    DiscussionComment(#[from] DiscussionCommentError),
+    #[error("Import error!")]
+    Import(#[from] ImportError),
+    #[error("Workflow run error!")]
+    WorkflowRun(#[from] WorkflowRunError),
+    #[error("Blob error!")]
+    Blob(#[from] BlobError),
+    #[error("Tree error!")]
+    Tree(#[from] TreeError),
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub type GitHubResult<T, E = GitHubError> = Result<T, E>;
 
+#[cfg(target_arch = "wasm32")]
+pub type GitHubResult<T, E = client::ClientError> = Result<T, E>;
+
+#[cfg(not(target_arch = "wasm32"))]
 pub trait GitHubProperties<'a>
 where Self: Sized + Clone {
 
@@ -81,4 +209,44 @@ where Self: Sized + Clone {
 
         Ok(self.clone())
     }
+
+    fn try_get_content_async(&'a self) -> impl Future<Output = GitHubResult<Self::Content, HandleRepositoryError>> + Send + 'a
+    where Self: Sync, Self::Content: Send {
+        async move {
+            Ok(self.get_client().get_async()
+                .get(self.get_endpoint())?
+                .send().await?
+                .json().await?)
+        }
+    }
+
+    fn try_get_properties_async<T>(&'a self) -> impl Future<Output = GitHubResult<T, HandleRepositoryError>> + Send + 'a
+    where Self: Sync, T: DeserializeOwned + FmtDebug + Send + 'a {
+        async move {
+            let result = {
+
+                self.get_client().get_async()
+                    .get(self.get_endpoint())?
+                    .send().await?
+                    .json().await?
+            };
+
+            Ok(result)
+        }
+    }
+
+    fn try_set_properties_async<T>(&'a self, payload: T) -> impl Future<Output = GitHubResult<Self, HandleRepositoryError>> + Send + 'a
+    where Self: Send + Sync, T: Serialize + FmtDebug + Send + 'a {
+        async move {
+            let _ = {
+
+                self.get_client().get_async()
+                    .patch(self.get_endpoint())?
+                    .json(&(payload))
+                    .send().await?
+            };
+
+            Ok(self.clone())
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,170 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{Number};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TokenPermission {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "write")]
+    Write,
+}
+
+impl Default for TokenPermission {
+    fn default() -> TokenPermission {
+        TokenPermission::Read
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowPermissions {
+    #[serde(rename = "default_workflow_permissions")]
+    pub(crate) default: TokenPermission,
+    #[serde(rename = "can_approve_pull_request_reviews")]
+    pub(crate) approve: bool,
+}
+
+impl WorkflowPermissions {
+    pub fn new(default: TokenPermission, approve: bool) -> WorkflowPermissions {
+        WorkflowPermissions { default, approve }
+    }
+
+    pub fn with_default(mut self, default: TokenPermission) -> WorkflowPermissions {
+        self.default = default;
+        self
+    }
+
+    pub fn with_approve(mut self, approve: bool) -> WorkflowPermissions {
+        self.approve = approve;
+        self
+    }
+
+    pub fn get_default(&self) -> TokenPermission {
+        self.default
+    }
+
+    pub fn can_approve(&self) -> bool {
+        self.approve
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum RunnerGroupVisibility {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "selected")]
+    Selected,
+    #[serde(rename = "private")]
+    Private,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct RunnerGroup {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    pub(crate) visibility: RunnerGroupVisibility,
+    #[serde(default)]
+    pub(crate) default: bool,
+    #[serde(default)]
+    pub(crate) inherited: bool,
+    #[serde(default)]
+    pub(crate) allows_public_repositories: bool,
+    #[serde(default)]
+    pub(crate) restricted_to_workflows: bool,
+    #[serde(default)]
+    pub(crate) selected_workflows: Vec<String>,
+}
+
+impl RunnerGroup {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_visibility(&self) -> RunnerGroupVisibility {
+        self.visibility
+    }
+
+    pub fn get_selected_workflows(&self) -> Vec<String> {
+        self.selected_workflows.clone()
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.default
+    }
+
+    pub fn is_inherited(&self) -> bool {
+        self.inherited
+    }
+
+    pub fn is_restricted_to_workflows(&self) -> bool {
+        self.restricted_to_workflows
+    }
+
+    pub fn allows_public_repositories(&self) -> bool {
+        self.allows_public_repositories
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct RunnerLabel {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Runner {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    pub(crate) os: String,
+    pub(crate) status: String,
+    #[serde(default)]
+    pub(crate) busy: bool,
+    #[serde(default)]
+    pub(crate) labels: Vec<RunnerLabel>,
+}
+
+impl Runner {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_os(&self) -> String {
+        self.os.clone()
+    }
+
+    pub fn get_labels(&self) -> Vec<String> {
+        self.labels.iter()
+            .map(|label| label.name.clone())
+            .collect()
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.status == "online"
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+}
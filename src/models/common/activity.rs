@@ -0,0 +1,109 @@
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ActivityType {
+    #[serde(rename = "push")]
+    Push,
+    #[serde(rename = "force_push")]
+    ForcePush,
+    #[serde(rename = "branch_creation")]
+    BranchCreation,
+    #[serde(rename = "branch_deletion")]
+    BranchDeletion,
+    #[serde(rename = "pr_merge")]
+    PullRequestMerge,
+    #[serde(rename = "merge_queue_merge")]
+    MergeQueueMerge,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Activity {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) before: String,
+    pub(crate) after: String,
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) timestamp: Date,
+    pub(crate) activity_type: ActivityType,
+    #[serde(default)]
+    pub(crate) actor: Option<User>,
+}
+
+impl Activity {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_before(&self) -> String {
+        self.before.clone()
+    }
+
+    pub fn get_after(&self) -> String {
+        self.after.clone()
+    }
+
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_timestamp(&self) -> Date {
+        self.timestamp
+    }
+
+    pub fn get_activity_type(&self) -> ActivityType {
+        self.activity_type
+    }
+
+    pub fn get_actor(&self) -> Option<User> {
+        self.actor.clone()
+    }
+
+    pub fn is_force_push(&self) -> bool {
+        self.activity_type == ActivityType::ForcePush
+    }
+
+    pub fn is_deletion(&self) -> bool {
+        self.activity_type == ActivityType::BranchDeletion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let activity: Activity = serde_json::from_value(serde_json::json!({
+            "id": 1296269,
+            "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+            "before": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            "after": "827efc6d56897b048c772eb4087f854f46256132",
+            "ref": "refs/heads/main",
+            "timestamp": "2011-01-26T19:06:43Z",
+            "activity_type": "force_push",
+            "actor": { "login": "octocat", "id": 1, "type": "User" },
+        })).unwrap();
+
+        assert!(activity.is_force_push());
+        assert_eq!(activity.get_reference(), "refs/heads/main");
+        assert_eq!(activity.get_actor().map(|actor| actor.get_name()).as_deref(), Some("octocat"));
+    }
+}
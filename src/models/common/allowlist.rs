@@ -0,0 +1,51 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::common::{Date};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct IpAllowListEntry {
+    pub(crate) id: String,
+    #[serde(rename = "allowListValue")]
+    pub(crate) value: String,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(rename = "isActive")]
+    pub(crate) active: bool,
+    #[serde(rename = "createdAt")]
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created: Date,
+    #[serde(rename = "updatedAt")]
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) updated: Date,
+}
+
+impl IpAllowListEntry {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_value(&self) -> String {
+        self.value.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created.clone()
+    }
+
+    pub fn get_updated(&self) -> Date {
+        self.updated.clone()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
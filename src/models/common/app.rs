@@ -0,0 +1,376 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use chrono::{Utc};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use secrecy::{Secret};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum WebhookContentType {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "form")]
+    Form,
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct WebhookConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) content_type: Option<WebhookContentType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) insecure_ssl: Option<String>,
+}
+
+impl WebhookConfig {
+    pub fn new() -> WebhookConfig {
+        Default::default()
+    }
+
+    pub fn with_url(mut self, url: impl AsRef<str>) -> WebhookConfig {
+        self.url = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_content_type(mut self, content_type: WebhookContentType) -> WebhookConfig {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    pub fn with_secret(mut self, secret: impl AsRef<str>) -> WebhookConfig {
+        self.secret = Some(secret.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_insecure_ssl(mut self, insecure: bool) -> WebhookConfig {
+        self.insecure_ssl = Some(if insecure { "1" } else { "0" }.to_owned());
+        self
+    }
+
+    pub fn get_url(&self) -> Option<String> {
+        self.url.clone()
+    }
+
+    pub fn get_content_type(&self) -> Option<WebhookContentType> {
+        self.content_type
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    pub fn is_insecure_ssl(&self) -> bool {
+        matches!(self.insecure_ssl.as_deref(), Some("1"))
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct HookDelivery {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) guid: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) delivered_at: Date,
+    #[serde(default)]
+    pub(crate) redelivery: bool,
+    #[serde(default)]
+    pub(crate) duration: f64,
+    pub(crate) status: String,
+    pub(crate) status_code: u16,
+    pub(crate) event: String,
+    #[serde(default)]
+    pub(crate) action: Option<String>,
+    #[serde(default)]
+    pub(crate) installation_id: Option<Number>,
+    #[serde(default)]
+    pub(crate) repository_id: Option<Number>,
+}
+
+impl HookDelivery {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_guid(&self) -> String {
+        self.guid.clone()
+    }
+
+    pub fn get_delivered_at(&self) -> Date {
+        self.delivered_at
+    }
+
+    pub fn get_duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn get_status(&self) -> String {
+        self.status.clone()
+    }
+
+    pub fn get_status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    pub fn get_event(&self) -> String {
+        self.event.clone()
+    }
+
+    pub fn get_action(&self) -> Option<String> {
+        self.action.clone()
+    }
+
+    pub fn get_installation_id(&self) -> Option<Number> {
+        self.installation_id
+    }
+
+    pub fn get_repository_id(&self) -> Option<Number> {
+        self.repository_id
+    }
+
+    pub fn is_redelivery(&self) -> bool {
+        self.redelivery
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200 .. 300).contains(&(self.status_code))
+    }
+}
+
+#[derive(Debug)]
+pub struct AppManifestConversion {
+    pub(crate) number: Number,
+    pub(crate) slug: String,
+    pub(crate) name: String,
+    pub(crate) owner: Option<User>,
+    pub(crate) html_url: String,
+    pub(crate) client_id: String,
+    pub(crate) client_secret: Secret<String>,
+    pub(crate) webhook_secret: Option<Secret<String>>,
+    pub(crate) pem: Secret<String>,
+}
+
+impl AppManifestConversion {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_slug(&self) -> String {
+        self.slug.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_owner(&self) -> Option<User> {
+        self.owner.clone()
+    }
+
+    pub fn get_html_url(&self) -> String {
+        self.html_url.clone()
+    }
+
+    pub fn get_client_id(&self) -> String {
+        self.client_id.clone()
+    }
+
+    pub fn get_client_secret(&self) -> Secret<String> {
+        self.client_secret.clone()
+    }
+
+    pub fn get_webhook_secret(&self) -> Option<Secret<String>> {
+        self.webhook_secret.clone()
+    }
+
+    pub fn get_pem(&self) -> Secret<String> {
+        self.pem.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize)]
+pub struct InstallationTokenScope {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) repositories: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) repository_ids: Vec<Number>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) permissions: BTreeMap<String, String>,
+}
+
+impl InstallationTokenScope {
+    pub fn new() -> InstallationTokenScope {
+        Default::default()
+    }
+
+    pub fn with_repository(mut self, name: impl AsRef<str>) -> InstallationTokenScope {
+        self.repositories.push(name.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_repository_id(mut self, number: Number) -> InstallationTokenScope {
+        self.repository_ids.push(number);
+        self
+    }
+
+    pub fn with_permission(mut self, name: impl AsRef<str>, access: impl AsRef<str>) -> InstallationTokenScope {
+        self.permissions.insert(name.as_ref().to_owned(), access.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_repositories(&self) -> Vec<String> {
+        self.repositories.clone()
+    }
+
+    pub fn get_repository_ids(&self) -> Vec<Number> {
+        self.repository_ids.clone()
+    }
+
+    pub fn get_permissions(&self) -> BTreeMap<String, String> {
+        self.permissions.clone()
+    }
+
+    pub fn is_unrestricted(&self) -> bool {
+        self.repositories.is_empty() && self.repository_ids.is_empty() && self.permissions.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct InstallationToken {
+    pub(crate) token: Secret<String>,
+    pub(crate) expires_at: Date,
+    pub(crate) permissions: BTreeMap<String, String>,
+    pub(crate) repository_selection: Option<String>,
+    pub(crate) repositories: Vec<String>,
+}
+
+impl InstallationToken {
+    pub fn get_token(&self) -> Secret<String> {
+        self.token.clone()
+    }
+
+    pub fn get_expires_at(&self) -> Date {
+        self.expires_at
+    }
+
+    pub fn get_permissions(&self) -> BTreeMap<String, String> {
+        self.permissions.clone()
+    }
+
+    pub fn get_permission(&self, name: impl AsRef<str>) -> Option<String> {
+        self.permissions.get(name.as_ref()).cloned()
+    }
+
+    pub fn get_repository_selection(&self) -> Option<String> {
+        self.repository_selection.clone()
+    }
+
+    pub fn get_repositories(&self) -> Vec<String> {
+        self.repositories.clone()
+    }
+
+    pub fn has_permission(&self, name: impl AsRef<str>, access: impl AsRef<str>) -> bool {
+        let rank = |access: &str| match access {
+            "read" => 1,
+            "write" => 2,
+            "admin" => 3,
+            _ => 0,
+        };
+
+        let required = rank(access.as_ref());
+
+        self.permissions.get(name.as_ref())
+            .map_or(false, |granted| required > 0 && rank(granted) >= required)
+    }
+
+    pub fn satisfies(&self, scope: &InstallationTokenScope) -> bool {
+        scope.permissions.iter().all(|(name, access)| self.has_permission(name, access)) &&
+            scope.repositories.iter().all(|name| match self.repository_selection.as_deref() {
+                Some("all") => true,
+                _ => self.repositories.iter().any(|candidate| candidate.eq_ignore_ascii_case(name)),
+            })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_webhook_config() {
+        let config: WebhookConfig = serde_json::from_value(serde_json::json!({
+            "content_type": "json",
+            "insecure_ssl": "0",
+            "secret": "********",
+            "url": "https://example.com/webhook",
+        })).unwrap();
+
+        assert_eq!(config.get_content_type(), Some(WebhookContentType::Json));
+        assert_eq!(config.get_url().as_deref(), Some("https://example.com/webhook"));
+        assert!(!(config.is_insecure_ssl()));
+        assert!(config.has_secret());
+
+        let update = WebhookConfig::new()
+            .with_insecure_ssl(true);
+
+        assert_eq!(serde_json::to_value(update).unwrap(), serde_json::json!({
+            "insecure_ssl": "1",
+        }));
+    }
+
+    #[test]
+    fn test_installation_token_scope() {
+        let scope = InstallationTokenScope::new()
+            .with_repository("octo")
+            .with_permission("contents", "read");
+
+        assert_eq!(serde_json::to_value(&(scope)).unwrap(), serde_json::json!({
+            "repositories": ["octo"],
+            "permissions": { "contents": "read" },
+        }));
+
+        let token = InstallationToken {
+            token: Secret::new("token".to_owned()),
+            expires_at: Utc::now(),
+            permissions: BTreeMap::from([("contents".to_owned(), "write".to_owned())]),
+            repository_selection: Some("selected".to_owned()),
+            repositories: vec!["Octo".to_owned()],
+        };
+
+        assert!(token.has_permission("contents", "read"));
+        assert!(!(token.has_permission("contents", "admin")));
+        assert!(!(token.has_permission("issues", "read")));
+        assert!(token.satisfies(&(scope)));
+        assert!(!(token.satisfies(&(scope.with_repository("other")))));
+    }
+}
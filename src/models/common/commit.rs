@@ -11,6 +11,7 @@ use crate::{common::{Date}, repository::sha::Sha};
 pub struct CommitAuthor {
     pub email: String,
     pub name: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
     pub date: Date,
 }
 
@@ -111,6 +112,18 @@ pub struct Commit {
     pub author: CommitAuthor,
     pub verified: CommitVerification,
     pub parents: Vec<Sha<'static>>,
+    pub html_url: Option<String>,
+    pub api_url: Option<String>,
+}
+
+impl Commit {
+    pub fn get_html_url(&self) -> Option<String> {
+        self.html_url.clone()
+    }
+
+    pub fn get_api_url(&self) -> Option<String> {
+        self.api_url.clone()
+    }
 }
 
 impl<'de> Deserialize<'de> for Commit {
@@ -131,9 +144,14 @@ impl<'de> Deserialize<'de> for Commit {
         struct Capsule {
             commit: CapsuleCommit,
             parents: Vec<CapsuleParent>,
+            #[serde(default)]
+            html_url: Option<String>,
+            #[serde(rename = "url")]
+            #[serde(default)]
+            api_url: Option<String>,
         }
 
-        let Capsule { commit: CapsuleCommit { author, verified }, parents } = {
+        let Capsule { commit: CapsuleCommit { author, verified }, parents, html_url, api_url } = {
             Capsule::deserialize(deserializer)?
         };
 
@@ -144,6 +162,8 @@ impl<'de> Deserialize<'de> for Commit {
             parents: parents.into_iter()
                 .map(|CapsuleParent { sha }| { sha })
                 .collect(),
+            html_url,
+            api_url,
         })
     }
 }
\ No newline at end of file
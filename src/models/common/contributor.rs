@@ -0,0 +1,98 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Contributor {
+    Account {
+        #[serde(flatten)]
+        user: User,
+        contributions: Number,
+    },
+    Anonymous {
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        email: Option<String>,
+        contributions: Number,
+    },
+}
+
+impl Contributor {
+    pub fn get_user(&self) -> Option<User> {
+        match self {
+            Contributor::Account { user, .. } => Some(user.clone()),
+            Contributor::Anonymous { .. } => None,
+        }
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        match self {
+            Contributor::Account { user, .. } => Some(user.get_name()),
+            Contributor::Anonymous { name, .. } => name.clone(),
+        }
+    }
+
+    pub fn get_email(&self) -> Option<String> {
+        match self {
+            Contributor::Account { .. } => None,
+            Contributor::Anonymous { email, .. } => email.clone(),
+        }
+    }
+
+    pub fn get_contributions(&self) -> Number {
+        match self {
+            Contributor::Account { contributions, .. } |
+            Contributor::Anonymous { contributions, .. } => contributions.clone(),
+        }
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        match self {
+            Contributor::Account { .. } => false,
+            Contributor::Anonymous { .. } => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let contributors: Vec<Contributor> = serde_json::from_value(serde_json::json!([
+            {
+                "login": "octocat",
+                "id": 1,
+                "type": "User",
+                "contributions": 32,
+            },
+            {
+                "name": "Mona",
+                "email": "mona@example.com",
+                "type": "Anonymous",
+                "contributions": 3,
+            },
+        ])).unwrap();
+
+        assert!(!contributors[0].is_anonymous());
+        assert_eq!(contributors[0].get_name().as_deref(), Some("octocat"));
+        assert_eq!(contributors[0].get_contributions(), 32);
+
+        assert!(contributors[1].is_anonymous());
+        assert_eq!(contributors[1].get_email().as_deref(), Some("mona@example.com"));
+        assert_eq!(contributors[1].get_contributions(), 3);
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::{
+
+    Formatter as FmtFormatter,
+    Display as FmtDisplay,
+    Result as FmtResult,
+};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+
+    Number,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct DiscussionCategory {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) slug: String,
+    #[serde(rename = "isAnswerable")]
+    pub(crate) answerable: bool,
+}
+
+impl DiscussionCategory {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_slug(&self) -> String {
+        self.slug.clone()
+    }
+
+    pub fn is_answerable(&self) -> bool {
+        self.answerable
+    }
+}
+
+impl FmtDisplay for DiscussionCategory {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{slug}", slug = {
+            self.slug.clone()
+        })
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Discussion {
+    pub(crate) id: String,
+    pub(crate) number: Number,
+    pub(crate) author: Option<User>,
+    pub(crate) category: DiscussionCategory,
+    pub(crate) title: String,
+    pub(crate) body: String,
+    #[serde(rename = "isAnswered")]
+    pub(crate) answered: Option<bool>,
+}
+
+impl Discussion {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_author(&self) -> Option<User> {
+        self.author.clone()
+    }
+
+    pub fn get_category(&self) -> DiscussionCategory {
+        self.category.clone()
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_body(&self) -> String {
+        self.body.clone()
+    }
+
+    pub fn is_answered(&self) -> bool {
+        self.answered.unwrap_or_default()
+    }
+}
+
+impl FmtDisplay for Discussion {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{number}", number = {
+            self.number.clone()
+        })
+    }
+}
+
+impl Into<Number> for Discussion {
+    fn into(self) -> Number {
+        self.number.clone()
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct DiscussionComment {
+    pub(crate) id: String,
+    pub(crate) author: Option<User>,
+    pub(crate) body: String,
+    #[serde(rename = "isAnswer")]
+    pub(crate) answer: bool,
+}
+
+impl DiscussionComment {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_author(&self) -> Option<User> {
+        self.author.clone()
+    }
+
+    pub fn get_body(&self) -> String {
+        self.body.clone()
+    }
+
+    pub fn is_answer(&self) -> bool {
+        self.answer
+    }
+}
+
+impl FmtDisplay for DiscussionComment {
+    fn fmt(&self, fmt: &mut FmtFormatter) -> FmtResult {
+        write!(fmt, "{id}", id = {
+            self.id.clone()
+        })
+    }
+}
@@ -0,0 +1,229 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{Number};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ImportVcs {
+    #[serde(rename = "subversion")]
+    Subversion,
+    #[serde(rename = "git")]
+    Git,
+    #[serde(rename = "mercurial")]
+    Mercurial,
+    #[serde(rename = "tfvc")]
+    Tfvc,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ImportLfs {
+    #[serde(rename = "opt_in")]
+    OptIn,
+    #[serde(rename = "opt_out")]
+    OptOut,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ImportStatus {
+    #[serde(rename = "auth")]
+    Auth,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "detecting")]
+    Detecting,
+    #[serde(rename = "choose")]
+    Choose,
+    #[serde(rename = "auth_failed")]
+    AuthFailed,
+    #[serde(rename = "importing")]
+    Importing,
+    #[serde(rename = "mapping")]
+    Mapping,
+    #[serde(rename = "waiting_to_push")]
+    WaitingToPush,
+    #[serde(rename = "pushing")]
+    Pushing,
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "setup")]
+    Setup,
+    #[serde(rename = "detection_found_multiple")]
+    DetectionFoundMultiple,
+    #[serde(rename = "detection_found_nothing")]
+    DetectionFoundNothing,
+    #[serde(rename = "detection_needs_auth")]
+    DetectionNeedsAuth,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ImportStatus {
+    pub fn is_complete(&self) -> bool {
+        match self {
+            ImportStatus::Complete => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        match self {
+            ImportStatus::Error |
+            ImportStatus::AuthFailed |
+            ImportStatus::DetectionFoundNothing => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Import {
+    #[serde(default)]
+    pub(crate) vcs: Option<ImportVcs>,
+    #[serde(default)]
+    pub(crate) vcs_url: Option<String>,
+    #[serde(default)]
+    pub(crate) use_lfs: Option<bool>,
+    pub(crate) status: ImportStatus,
+    #[serde(default)]
+    pub(crate) status_text: Option<String>,
+    #[serde(default)]
+    pub(crate) failed_step: Option<String>,
+    #[serde(default)]
+    pub(crate) error_message: Option<String>,
+    #[serde(default)]
+    pub(crate) percent: Option<Number>,
+    #[serde(default)]
+    pub(crate) commit_count: Option<Number>,
+    #[serde(default)]
+    pub(crate) push_percent: Option<Number>,
+    #[serde(default)]
+    pub(crate) has_large_files: bool,
+    #[serde(default)]
+    pub(crate) large_files_size: Option<Number>,
+    #[serde(default)]
+    pub(crate) large_files_count: Option<Number>,
+    #[serde(default)]
+    pub(crate) authors_count: Option<Number>,
+}
+
+impl Import {
+    pub fn get_vcs(&self) -> Option<ImportVcs> {
+        self.vcs
+    }
+
+    pub fn get_vcs_url(&self) -> Option<String> {
+        self.vcs_url.clone()
+    }
+
+    pub fn get_status(&self) -> ImportStatus {
+        self.status
+    }
+
+    pub fn get_status_text(&self) -> Option<String> {
+        self.status_text.clone()
+    }
+
+    pub fn get_error_message(&self) -> Option<String> {
+        self.error_message.clone()
+    }
+
+    pub fn get_percent(&self) -> Option<Number> {
+        self.percent.clone()
+    }
+
+    pub fn get_push_percent(&self) -> Option<Number> {
+        self.push_percent.clone()
+    }
+
+    pub fn get_commit_count(&self) -> Option<Number> {
+        self.commit_count.clone()
+    }
+
+    pub fn get_authors_count(&self) -> Option<Number> {
+        self.authors_count.clone()
+    }
+
+    pub fn has_large_files(&self) -> bool {
+        self.has_large_files
+    }
+
+    pub fn get_large_files_size(&self) -> Option<Number> {
+        self.large_files_size.clone()
+    }
+
+    pub fn get_large_files_count(&self) -> Option<Number> {
+        self.large_files_count.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ImportAuthor {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) remote_id: String,
+    pub(crate) remote_name: String,
+    pub(crate) email: String,
+    pub(crate) name: String,
+}
+
+impl ImportAuthor {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_remote_id(&self) -> String {
+        self.remote_id.clone()
+    }
+
+    pub fn get_remote_name(&self) -> String {
+        self.remote_name.clone()
+    }
+
+    pub fn get_email(&self) -> String {
+        self.email.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ImportLargeFile {
+    pub(crate) ref_name: String,
+    pub(crate) path: String,
+    pub(crate) oid: String,
+    pub(crate) size: Number,
+}
+
+impl ImportLargeFile {
+    pub fn get_ref_name(&self) -> String {
+        self.ref_name.clone()
+    }
+
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn get_oid(&self) -> String {
+        self.oid.clone()
+    }
+
+    pub fn get_size(&self) -> Number {
+        self.size.clone()
+    }
+}
@@ -13,7 +13,18 @@ use serde::{
 
 use crate::{
 
-    models::common::user::{User},
+    models::common::{
+
+        user::{
+
+            AuthorAssociation,
+            User,
+        },
+
+        reaction::{Reactions},
+    },
+
+    common::{Date},
 
     Number,
 };
@@ -26,6 +37,18 @@ pub struct Comment {
     pub(crate) author: User,
     #[serde(rename = "id")]
     pub(crate) number: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) created_at: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) updated_at: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) html_url: Option<String>,
+    #[serde(rename = "url", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) author_association: Option<AuthorAssociation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) reactions: Option<Reactions>,
 }
 
 impl Comment {
@@ -37,6 +60,31 @@ impl Comment {
         self.number
     }
 
+    pub fn get_created(&self) -> Option<Date> {
+        self.created_at.clone()
+    }
+
+    pub fn get_updated(&self) -> Option<Date> {
+        self.updated_at.clone()
+    }
+
+    pub fn get_html_url(&self) -> Option<String> {
+        self.html_url.clone()
+    }
+
+    pub fn get_api_url(&self) -> Option<String> {
+        self.api_url.clone()
+    }
+
+    pub fn get_author_association(&self) -> Option<AuthorAssociation> {
+        self.author_association
+    }
+
+    pub fn get_reactions(&self) -> Reactions {
+        self.reactions.clone()
+            .unwrap_or_default()
+    }
+
     pub fn get_body(&self) -> String {
         self.body.clone()
     }
@@ -64,4 +112,27 @@ impl Into<Number> for Comment {
     fn into(self) -> Number {
         self.number.clone()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::fixtures::{round_trip};
+
+    use crate::models::common::user::{AuthorAssociation};
+
+    use super::{Comment};
+
+    #[test]
+    fn test_round_trip() {
+        let comment: Comment = round_trip(include_str!("../test_data/comment.json"));
+
+        assert_eq!(comment.get_body(), "Me too");
+        assert_eq!(comment.get_author().get_name(), "octocat");
+        assert_eq!(comment.get_created().map(|date| date.to_rfc3339()), Some("2011-04-14T16:00:49+00:00".to_owned()));
+        assert_eq!(comment.get_author_association(), Some(AuthorAssociation::Collaborator));
+        assert_eq!(comment.get_html_url().as_deref(), Some("https://github.com/octocat/Hello-World/issues/1347#issuecomment-1"));
+        assert_eq!(comment.get_reactions().get_total(), 5);
+        assert_eq!(comment.get_reactions().get_score(), 3);
+    }
+}
@@ -22,10 +22,20 @@ use serde::{
     Serialize, 
 };
 
-use crate::{Number};
+use crate::{
 
-use super::user::{User};
+    common::{Date},
 
+    Number,
+};
+
+use super::user::{
+
+    AuthorAssociation,
+    User,
+};
+
+pub mod template;
 pub mod comment;
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -51,6 +61,15 @@ impl IssueState {
     }
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")] OffTopic,
+    #[serde(rename = "too heated")] TooHeated,
+    #[serde(rename = "resolved")] Resolved,
+    #[serde(rename = "spam")] Spam,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 #[derive(Serialize)]
 #[serde(untagged)]
@@ -169,7 +188,17 @@ pub struct IssueContent {
     pub(crate) author: User,
     pub(crate) title: String,
     pub(crate) body: String,
-    pub(crate) state: IssueState 
+    pub(crate) state: IssueState,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) created_at: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) updated_at: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) html_url: Option<String>,
+    #[serde(rename = "url", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) author_association: Option<AuthorAssociation>,
 }
 
 impl IssueContent {
@@ -181,6 +210,26 @@ impl IssueContent {
         self.number
     }
 
+    pub fn get_author_association(&self) -> Option<AuthorAssociation> {
+        self.author_association
+    }
+
+    pub fn get_html_url(&self) -> Option<String> {
+        self.html_url.clone()
+    }
+
+    pub fn get_api_url(&self) -> Option<String> {
+        self.api_url.clone()
+    }
+
+    pub fn get_created(&self) -> Option<Date> {
+        self.created_at.clone()
+    }
+
+    pub fn get_updated(&self) -> Option<Date> {
+        self.updated_at.clone()
+    }
+
     pub fn get_title(&self) -> String {
         self.title.clone()
     }
@@ -286,11 +335,171 @@ impl Into<Number> for IssueContent {
     }
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkedPullRequest {
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    pub(crate) url: String,
+    #[serde(rename = "repository")]
+    pub(crate) repository: LinkedRepository,
+    #[serde(default)]
+    pub(crate) merged: bool,
+    #[serde(default)]
+    pub(crate) state: Option<String>,
+}
+
+impl LinkedPullRequest {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    pub fn get_repository(&self) -> String {
+        self.repository.name.clone()
+    }
+
+    pub fn get_state(&self) -> Option<String> {
+        self.state.clone()
+    }
+
+    pub fn is_merged(&self) -> bool {
+        self.merged
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct TrackedIssue {
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    pub(crate) url: String,
+    #[serde(rename = "repository")]
+    pub(crate) repository: LinkedRepository,
+    #[serde(default)]
+    pub(crate) state: Option<String>,
+}
+
+impl TrackedIssue {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    pub fn get_repository(&self) -> String {
+        self.repository.name.clone()
+    }
+
+    pub fn get_state(&self) -> Option<String> {
+        self.state.clone()
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkedRepository {
+    #[serde(rename = "nameWithOwner")]
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct ProjectMembership {
+    pub(crate) id: String,
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    pub(crate) url: String,
+    #[serde(default)]
+    pub(crate) closed: bool,
+}
+
+impl ProjectMembership {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IssueExtended {
+    pub(crate) issue: Issue,
+    pub(crate) linked_pulls: Vec<LinkedPullRequest>,
+    pub(crate) projects: Vec<ProjectMembership>,
+    pub(crate) tracked_by: Vec<TrackedIssue>,
+    pub(crate) tracking: Vec<TrackedIssue>,
+}
+
+impl IssueExtended {
+    pub fn get_issue(&self) -> Issue {
+        self.issue.clone()
+    }
+
+    pub fn get_linked_pulls(&self) -> Vec<LinkedPullRequest> {
+        self.linked_pulls.clone()
+    }
+
+    pub fn get_projects(&self) -> Vec<ProjectMembership> {
+        self.projects.clone()
+    }
+
+    pub fn get_tracked_by(&self) -> Vec<TrackedIssue> {
+        self.tracked_by.clone()
+    }
+
+    pub fn get_tracking(&self) -> Vec<TrackedIssue> {
+        self.tracking.clone()
+    }
+
+    pub fn has_linked_pulls(&self) -> bool {
+        !(self.linked_pulls.is_empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
+    use crate::models::fixtures::{round_trip};
+
     use super::{Issue};
 
+    #[test]
+    fn test_round_trip() {
+        let issue: Issue = round_trip(include_str!("test_data/plain.json"));
+        assert_eq!(issue.is_plain(), true);
+
+        let issue: Issue = round_trip(include_str!("test_data/pull.json"));
+        assert_eq!(issue.is_pull_request(), true);
+    }
+
     #[test]
     fn test_serialize() {
         let plain = include_str!("test_data/plain.json");
@@ -0,0 +1,312 @@
+use std::{
+
+    collections::{BTreeMap},
+    path::{PathBuf},
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueFormAttributes {
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) placeholder: Option<String>,
+    #[serde(default)]
+    pub(crate) value: Option<String>,
+    #[serde(default)]
+    pub(crate) options: Vec<serde_yaml::Value>,
+}
+
+impl IssueFormAttributes {
+    pub fn get_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_placeholder(&self) -> Option<String> {
+        self.placeholder.clone()
+    }
+
+    pub fn get_value(&self) -> Option<String> {
+        self.value.clone()
+    }
+
+    pub fn get_options(&self) -> Vec<String> {
+        self.options.iter().filter_map(|option| match option {
+            serde_yaml::Value::String(option) => Some(option.clone()),
+            serde_yaml::Value::Mapping(mapping) => mapping.get("label")
+                .and_then(serde_yaml::Value::as_str)
+                .map(str::to_owned),
+            _ => None,
+        }).collect()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueFormValidations {
+    #[serde(default)]
+    pub(crate) required: bool,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum IssueFormElement {
+    #[serde(rename = "markdown")]
+    Markdown {
+        #[serde(default)]
+        attributes: IssueFormAttributes,
+    },
+    #[serde(rename = "textarea")]
+    Textarea {
+        id: Option<String>,
+        #[serde(default)]
+        attributes: IssueFormAttributes,
+        #[serde(default)]
+        validations: IssueFormValidations,
+    },
+    #[serde(rename = "input")]
+    Input {
+        id: Option<String>,
+        #[serde(default)]
+        attributes: IssueFormAttributes,
+        #[serde(default)]
+        validations: IssueFormValidations,
+    },
+    #[serde(rename = "dropdown")]
+    Dropdown {
+        id: Option<String>,
+        #[serde(default)]
+        attributes: IssueFormAttributes,
+        #[serde(default)]
+        validations: IssueFormValidations,
+    },
+    #[serde(rename = "checkboxes")]
+    Checkboxes {
+        id: Option<String>,
+        #[serde(default)]
+        attributes: IssueFormAttributes,
+        #[serde(default)]
+        validations: IssueFormValidations,
+    },
+}
+
+impl IssueFormElement {
+    pub fn get_id(&self) -> Option<String> {
+        match self {
+            IssueFormElement::Markdown { .. } => None,
+            IssueFormElement::Textarea { id, .. } |
+            IssueFormElement::Input { id, .. } |
+            IssueFormElement::Dropdown { id, .. } |
+            IssueFormElement::Checkboxes { id, .. } => id.clone(),
+        }
+    }
+
+    pub fn get_attributes(&self) -> IssueFormAttributes {
+        match self {
+            IssueFormElement::Markdown { attributes } |
+            IssueFormElement::Textarea { attributes, .. } |
+            IssueFormElement::Input { attributes, .. } |
+            IssueFormElement::Dropdown { attributes, .. } |
+            IssueFormElement::Checkboxes { attributes, .. } => attributes.clone(),
+        }
+    }
+
+    pub fn get_label(&self) -> Option<String> {
+        match self {
+            IssueFormElement::Markdown { .. } => None,
+            _ => self.get_attributes()
+                .get_label(),
+        }
+    }
+
+    pub fn is_required(&self) -> bool {
+        match self {
+            IssueFormElement::Markdown { .. } => false,
+            IssueFormElement::Textarea { validations, .. } |
+            IssueFormElement::Input { validations, .. } |
+            IssueFormElement::Dropdown { validations, .. } |
+            IssueFormElement::Checkboxes { validations, .. } => validations.required,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct IssueForm {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    #[serde(default)]
+    pub(crate) title: Option<String>,
+    #[serde(default)]
+    pub(crate) labels: Vec<String>,
+    #[serde(default)]
+    pub(crate) assignees: Vec<String>,
+    pub(crate) body: Vec<IssueFormElement>,
+}
+
+impl IssueForm {
+    pub fn try_parse(content: impl AsRef<str>) -> Result<IssueForm, serde_yaml::Error> {
+        serde_yaml::from_str(content.as_ref())
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_description(&self) -> String {
+        self.description.clone()
+    }
+
+    pub fn get_title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+    pub fn get_labels(&self) -> Vec<String> {
+        self.labels.clone()
+    }
+
+    pub fn get_assignees(&self) -> Vec<String> {
+        self.assignees.clone()
+    }
+
+    pub fn get_elements(&self) -> &[IssueFormElement] {
+        self.body.as_slice()
+    }
+
+    pub fn get_answers(&self, body: impl AsRef<str>) -> BTreeMap<String, Option<String>> {
+        let sections = IssueForm::parse_sections(body);
+
+        self.body.iter().filter_map(|element| {
+            let label = element.get_label()?;
+            let key = element.get_id()
+                .unwrap_or_else(|| label.clone());
+
+            Some((key, sections.get(&(label))
+                .cloned()
+                .flatten()))
+        }).collect()
+    }
+
+    pub fn parse_sections(body: impl AsRef<str>) -> BTreeMap<String, Option<String>> {
+        let mut sections = BTreeMap::new();
+        let mut current: Option<(String, Vec<&str>)> = None;
+
+        let mut flush = |current: Option<(String, Vec<&str>)>| {
+            if let Some((label, lines)) = current {
+                let answer = lines.join("\n")
+                    .trim()
+                    .to_owned();
+
+                sections.insert(label, match answer.as_str() {
+                    "" | "_No response_" => None,
+                    _ => Some(answer),
+                });
+            }
+        };
+
+        for line in body.as_ref().lines() {
+            match line.strip_prefix("### ") {
+                Some(label) => flush(current.replace((label.trim().to_owned(), Vec::new()))),
+                None => if let Some((_, ref mut lines)) = current {
+                    lines.push(line);
+                },
+            }
+        }
+
+        flush(current);
+
+        sections
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum IssueTemplate {
+    Form { path: PathBuf, form: IssueForm },
+    Markdown { path: PathBuf, content: String },
+}
+
+impl IssueTemplate {
+    pub fn get_path(&self) -> PathBuf {
+        match self {
+            IssueTemplate::Form { path, .. } |
+            IssueTemplate::Markdown { path, .. } => path.clone(),
+        }
+    }
+
+    pub fn get_form(&self) -> Option<IssueForm> {
+        match self {
+            IssueTemplate::Form { form, .. } => Some(form.clone()),
+            IssueTemplate::Markdown { .. } => None,
+        }
+    }
+
+    pub fn is_form(&self) -> bool {
+        match self {
+            IssueTemplate::Form { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_markdown(&self) -> bool {
+        match self {
+            IssueTemplate::Markdown { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let form = IssueForm::try_parse(r#"
+name: Bug Report
+description: File a bug report
+labels: ["bug", "triage"]
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: input
+    id: contact
+    attributes:
+      label: Contact Details
+  - type: textarea
+    id: what-happened
+    attributes:
+      label: What happened?
+    validations:
+      required: true
+  - type: dropdown
+    attributes:
+      label: Version
+      options:
+        - 1.0.2 (Default)
+        - 1.0.3 (Edge)
+"#).unwrap();
+
+        assert_eq!(form.get_labels(), vec!["bug", "triage"]);
+        assert_eq!(form.get_elements().len(), 4);
+
+        let answers = form.get_answers("### Contact Details\n\n_No response_\n\n### What happened?\n\nIt broke.\n\nBadly.\n\n### Version\n\n1.0.3 (Edge)\n");
+
+        assert_eq!(answers.get("contact"), Some(&(None)));
+        assert_eq!(answers.get("what-happened"), Some(&(Some("It broke.\n\nBadly.".to_owned()))));
+        assert_eq!(answers.get("Version"), Some(&(Some("1.0.3 (Edge)".to_owned()))));
+    }
+}
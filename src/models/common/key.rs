@@ -0,0 +1,124 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct SshSigningKey {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    pub(crate) key: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+}
+
+impl SshSigningKey {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct GpgKeyEmail {
+    pub(crate) email: String,
+    pub(crate) verified: bool,
+}
+
+impl GpgKeyEmail {
+    pub fn get_email(&self) -> String {
+        self.email.clone()
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct GpgKey {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    pub(crate) key_id: String,
+    #[serde(default)]
+    pub(crate) public_key: Option<String>,
+    #[serde(default)]
+    pub(crate) emails: Vec<GpgKeyEmail>,
+    #[serde(default)]
+    pub(crate) subkeys: Vec<GpgKey>,
+    #[serde(default)]
+    pub(crate) can_sign: bool,
+    #[serde(default)]
+    pub(crate) revoked: bool,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) expires_at: Option<Date>,
+}
+
+impl GpgKey {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_key_id(&self) -> String {
+        self.key_id.clone()
+    }
+
+    pub fn get_public_key(&self) -> Option<String> {
+        self.public_key.clone()
+    }
+
+    pub fn get_emails(&self) -> Vec<GpgKeyEmail> {
+        self.emails.clone()
+    }
+
+    pub fn get_subkeys(&self) -> Vec<GpgKey> {
+        self.subkeys.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn get_expires(&self) -> Option<Date> {
+        self.expires_at.clone()
+    }
+
+    pub fn can_sign(&self) -> bool {
+        self.can_sign
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked
+    }
+}
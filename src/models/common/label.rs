@@ -0,0 +1,42 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{Number};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Label {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    pub(crate) color: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) default: bool,
+}
+
+impl Label {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_color(&self) -> String {
+        self.color.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.default
+    }
+}
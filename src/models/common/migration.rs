@@ -0,0 +1,99 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MigrationState {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "exporting")]
+    Exporting,
+    #[serde(rename = "exported")]
+    Exported,
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+impl MigrationState {
+    pub fn is_done(&self) -> bool {
+        match self {
+            MigrationState::Exported |
+            MigrationState::Failed => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct MigrationRepository {
+    pub(crate) full_name: String,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Migration {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) guid: String,
+    pub(crate) state: MigrationState,
+    #[serde(default)]
+    pub(crate) lock_repositories: bool,
+    #[serde(default)]
+    pub(crate) exclude_attachments: bool,
+    #[serde(default)]
+    pub(crate) repositories: Vec<MigrationRepository>,
+    #[serde(rename = "created_at")]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) date_created: Option<Date>,
+    #[serde(rename = "updated_at")]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) date_updated: Option<Date>,
+}
+
+impl Migration {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_guid(&self) -> String {
+        self.guid.clone()
+    }
+
+    pub fn get_state(&self) -> MigrationState {
+        self.state
+    }
+
+    pub fn has_lock_repositories(&self) -> bool {
+        self.lock_repositories
+    }
+
+    pub fn has_exclude_attachments(&self) -> bool {
+        self.exclude_attachments
+    }
+
+    pub fn get_repositories(&self) -> Vec<String> {
+        self.repositories.iter()
+            .map(|repository| repository.full_name.clone())
+            .collect()
+    }
+
+    pub fn get_date_created(&self) -> Option<Date> {
+        self.date_created.clone()
+    }
+
+    pub fn get_date_updated(&self) -> Option<Date> {
+        self.date_updated.clone()
+    }
+}
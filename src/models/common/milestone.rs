@@ -0,0 +1,237 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::issue::{IssueState},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Milestone {
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    pub(crate) state: IssueState,
+    pub(crate) open_issues: Number,
+    pub(crate) closed_issues: Number,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) due_on: Option<Date>,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) closed_at: Option<Date>,
+}
+
+impl Milestone {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_state(&self) -> IssueState {
+        self.state.clone()
+    }
+
+    pub fn get_open_issues(&self) -> Number {
+        self.open_issues.clone()
+    }
+
+    pub fn get_closed_issues(&self) -> Number {
+        self.closed_issues.clone()
+    }
+
+    pub fn get_due(&self) -> Option<Date> {
+        self.due_on.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn get_closed(&self) -> Option<Date> {
+        self.closed_at.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct MilestoneCount {
+    pub(crate) open: Number,
+    pub(crate) closed: Number,
+}
+
+impl MilestoneCount {
+    pub fn get_open(&self) -> Number {
+        self.open
+    }
+
+    pub fn get_closed(&self) -> Number {
+        self.closed
+    }
+
+    pub fn get_total(&self) -> Number {
+        self.open + self.closed
+    }
+
+    pub fn get_percentage(&self) -> f64 {
+        match self.get_total() {
+            0 => 0.0,
+            total => self.closed as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct MilestoneBurndown {
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) date: Date,
+    pub(crate) open: Number,
+}
+
+impl MilestoneBurndown {
+    pub fn get_date(&self) -> Date {
+        self.date.clone()
+    }
+
+    pub fn get_open(&self) -> Number {
+        self.open
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct MilestoneProgress {
+    pub(crate) milestone: Milestone,
+    pub(crate) total: MilestoneCount,
+    pub(crate) labels: BTreeMap<String, MilestoneCount>,
+    pub(crate) burndown: Vec<MilestoneBurndown>,
+}
+
+impl MilestoneProgress {
+    pub(crate) fn compute(milestone: Milestone, issues: impl IntoIterator<Item = (IssueState, Vec<String>, Date, Option<Date>)>) -> MilestoneProgress {
+        let mut total = MilestoneCount::default();
+        let mut labels: BTreeMap<String, MilestoneCount> = BTreeMap::new();
+        let mut events = Vec::new();
+
+        for (state, names, created, closed) in issues {
+            let open = state.is_open();
+
+            let count = |count: &mut MilestoneCount| match open {
+                true => count.open += 1,
+                false => count.closed += 1,
+            };
+
+            count(&mut total);
+            for name in names {
+                count(labels.entry(name).or_default());
+            }
+
+            events.push((created, true));
+            if let (false, Some(closed)) = (open, closed) {
+                events.push((closed, false));
+            }
+        }
+
+        events.sort_by_key(|(date, _)| date.clone());
+
+        let mut open: Number = 0;
+        let mut burndown: Vec<MilestoneBurndown> = Vec::new();
+        for (date, opened) in events {
+            open = match opened {
+                true => open + 1,
+                false => open.saturating_sub(1),
+            };
+
+            match burndown.last_mut() {
+                Some(point) if point.date == date => point.open = open,
+                _ => burndown.push(MilestoneBurndown { date, open }),
+            }
+        }
+
+        MilestoneProgress { milestone, total, labels, burndown }
+    }
+
+    pub fn get_milestone(&self) -> Milestone {
+        self.milestone.clone()
+    }
+
+    pub fn get_total(&self) -> MilestoneCount {
+        self.total.clone()
+    }
+
+    pub fn get_labels(&self) -> BTreeMap<String, MilestoneCount> {
+        self.labels.clone()
+    }
+
+    pub fn get_burndown(&self) -> Vec<MilestoneBurndown> {
+        self.burndown.clone()
+    }
+
+    pub fn get_percentage(&self) -> f64 {
+        self.total.get_percentage()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.total.open == 0 && self.total.closed > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute() {
+        let date = |day: u32| -> Date {
+            format!("2024-03-{day:02}T00:00:00Z").parse().unwrap()
+        };
+
+        let milestone: Milestone = serde_json::from_value(serde_json::json!({
+            "number": 1,
+            "title": "v1.0",
+            "state": "open",
+            "open_issues": 1,
+            "closed_issues": 2,
+            "created_at": "2024-03-01T00:00:00Z",
+        })).unwrap();
+
+        let progress = MilestoneProgress::compute(milestone, [
+            (IssueState::Closed, vec!["bug".to_owned()], date(1), Some(date(3))),
+            (IssueState::Closed, vec!["bug".to_owned(), "docs".to_owned()], date(2), Some(date(4))),
+            (IssueState::Open, vec!["docs".to_owned()], date(2), None),
+        ]);
+
+        assert_eq!(progress.get_total().get_total(), 3);
+        assert_eq!(progress.get_labels()["bug"], MilestoneCount { open: 0, closed: 2 });
+        assert_eq!(progress.get_labels()["docs"], MilestoneCount { open: 1, closed: 1 });
+
+        let burndown: Vec<Number> = progress.get_burndown().iter()
+            .map(MilestoneBurndown::get_open)
+            .collect();
+
+        assert_eq!(burndown, vec![1, 3, 2, 1]);
+        assert!(!progress.is_complete());
+    }
+}
@@ -1,5 +1,29 @@
 pub mod repository;
+pub mod statistics;
+pub mod run;
+pub mod workflow;
+pub mod actions;
+pub mod ruleset;
+pub mod allowlist;
+pub mod search;
+pub mod discussion;
+pub mod import;
+pub mod migration;
 pub mod commit;
+pub mod tag;
+pub mod release;
+pub mod key;
+pub mod label;
+pub mod pull;
+pub mod reaction;
 pub mod issue;
 pub mod team;
+pub mod organization;
+pub mod token;
+pub mod rate;
+pub mod app;
+pub mod activity;
+pub mod milestone;
+pub mod scanning;
+pub mod contributor;
 pub mod user;
\ No newline at end of file
@@ -0,0 +1,193 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::repository::{Permission},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct OrganizationPlan {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) space: Option<Number>,
+    #[serde(default)]
+    pub(crate) private_repos: Option<Number>,
+    #[serde(default)]
+    pub(crate) seats: Option<Number>,
+    #[serde(default)]
+    pub(crate) filled_seats: Option<Number>,
+}
+
+impl OrganizationPlan {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_space(&self) -> Option<Number> {
+        self.space.clone()
+    }
+
+    pub fn get_private_repositories(&self) -> Option<Number> {
+        self.private_repos.clone()
+    }
+
+    pub fn get_seats(&self) -> Option<Number> {
+        self.seats.clone()
+    }
+
+    pub fn get_filled_seats(&self) -> Option<Number> {
+        self.filled_seats.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct OrganizationDomain {
+    pub(crate) domain: String,
+    #[serde(rename = "isVerified")]
+    pub(crate) verified: bool,
+    #[serde(rename = "isApproved")]
+    pub(crate) approved: bool,
+}
+
+impl OrganizationDomain {
+    pub fn get_domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+
+    pub fn is_approved(&self) -> bool {
+        self.approved
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Organization {
+    #[serde(rename = "login")]
+    pub(crate) name: String,
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default, rename = "name")]
+    pub(crate) display_name: Option<String>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) email: Option<String>,
+    #[serde(default)]
+    pub(crate) billing_email: Option<String>,
+    #[serde(default)]
+    pub(crate) plan: Option<OrganizationPlan>,
+    #[serde(default, rename = "two_factor_requirement_enabled")]
+    pub(crate) two_factor: Option<bool>,
+    #[serde(default, rename = "default_repository_permission")]
+    pub(crate) default_permission: Option<Permission>,
+    #[serde(default, rename = "is_verified")]
+    pub(crate) verified: bool,
+    #[serde(default)]
+    pub(crate) public_repos: Number,
+    #[serde(default)]
+    pub(crate) total_private_repos: Option<Number>,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) updated_at: Date,
+}
+
+impl Organization {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_display_name(&self) -> Option<String> {
+        self.display_name.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_email(&self) -> Option<String> {
+        self.email.clone()
+    }
+
+    pub fn get_billing_email(&self) -> Option<String> {
+        self.billing_email.clone()
+    }
+
+    pub fn get_plan(&self) -> Option<OrganizationPlan> {
+        self.plan.clone()
+    }
+
+    pub fn get_default_permission(&self) -> Option<Permission> {
+        self.default_permission
+    }
+
+    pub fn get_public_repositories(&self) -> Number {
+        self.public_repos.clone()
+    }
+
+    pub fn get_private_repositories(&self) -> Option<Number> {
+        self.total_private_repos.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn get_updated(&self) -> Date {
+        self.updated_at.clone()
+    }
+
+    pub fn has_two_factor_requirement(&self) -> Option<bool> {
+        self.two_factor
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let organization: Organization = serde_json::from_value(serde_json::json!({
+            "login": "github",
+            "id": 1,
+            "name": "GitHub",
+            "description": null,
+            "billing_email": "billing@github.com",
+            "plan": { "name": "team", "space": 976562499, "private_repos": 999999, "seats": 10, "filled_seats": 4 },
+            "two_factor_requirement_enabled": true,
+            "default_repository_permission": "read",
+            "is_verified": true,
+            "public_repos": 2,
+            "created_at": "2008-01-14T04:33:35Z",
+            "updated_at": "2014-03-03T18:58:10Z",
+        })).unwrap();
+
+        assert_eq!(organization.get_display_name(), Some("GitHub".to_owned()));
+        assert_eq!(organization.get_default_permission(), Some(Permission::Read));
+        assert_eq!(organization.has_two_factor_requirement(), Some(true));
+        assert_eq!(organization.get_plan().and_then(|plan| plan.get_seats()), Some(10));
+        assert!(organization.is_verified());
+    }
+}
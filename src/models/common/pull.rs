@@ -0,0 +1,135 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::{
+
+        issue::{IssueState},
+        label::{Label},
+        user::{
+
+            AuthorAssociation,
+            User,
+        },
+    },
+
+    repository::sha::{Sha},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PullRequestBranch {
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    pub(crate) sha: Sha<'static>,
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+}
+
+impl PullRequestBranch {
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.sha.clone()
+    }
+
+    pub fn get_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PullRequest {
+    pub(crate) number: Number,
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) body: Option<String>,
+    pub(crate) state: IssueState,
+    #[serde(default)]
+    pub(crate) draft: bool,
+    #[serde(default)]
+    pub(crate) merged: bool,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) merged_at: Option<Date>,
+    #[serde(rename = "user")]
+    pub(crate) author: User,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) html_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) author_association: Option<AuthorAssociation>,
+    #[serde(default)]
+    pub(crate) labels: Vec<Label>,
+    pub(crate) head: PullRequestBranch,
+    pub(crate) base: PullRequestBranch,
+}
+
+impl PullRequest {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn get_body(&self) -> Option<String> {
+        self.body.clone()
+    }
+
+    pub fn get_state(&self) -> IssueState {
+        self.state.clone()
+    }
+
+    pub fn get_merged(&self) -> Option<Date> {
+        self.merged_at.clone()
+    }
+
+    pub fn get_author(&self) -> User {
+        self.author.clone()
+    }
+
+    pub fn get_author_association(&self) -> Option<AuthorAssociation> {
+        self.author_association
+    }
+
+    pub fn get_html_url(&self) -> Option<String> {
+        self.html_url.clone()
+    }
+
+    pub fn get_labels(&self) -> Vec<Label> {
+        self.labels.clone()
+    }
+
+    pub fn get_head(&self) -> PullRequestBranch {
+        self.head.clone()
+    }
+
+    pub fn get_base(&self) -> PullRequestBranch {
+        self.base.clone()
+    }
+
+    pub fn has_label(&self, name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+
+        self.labels.iter()
+            .any(|label| label.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_draft(&self) -> bool {
+        self.draft
+    }
+
+    pub fn is_merged(&self) -> bool {
+        self.merged || self.merged_at.is_some()
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+
+    collections::{BTreeMap},
+    time::{Duration},
+};
+
+use chrono::{Utc};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct RateLimit {
+    pub(crate) limit: Number,
+    pub(crate) remaining: Number,
+    #[serde(default)]
+    pub(crate) used: Number,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) reset: Date,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) resource: Option<String>,
+}
+
+impl RateLimit {
+    pub fn get_limit(&self) -> Number {
+        self.limit
+    }
+
+    pub fn get_remaining(&self) -> Number {
+        self.remaining
+    }
+
+    pub fn get_used(&self) -> Number {
+        self.used
+    }
+
+    pub fn get_reset(&self) -> Date {
+        self.reset
+    }
+
+    pub fn get_resource(&self) -> Option<String> {
+        self.resource.clone()
+    }
+
+    pub fn get_wait(&self) -> Duration {
+        (self.reset - Utc::now()).to_std()
+            .unwrap_or_default()
+    }
+
+    pub fn get_pacing(&self) -> Duration {
+        self.get_wait() / u32::try_from(self.remaining)
+            .unwrap_or(u32::MAX)
+            .saturating_add(1)
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct RateLimits {
+    #[serde(default)]
+    pub(crate) resources: BTreeMap<String, RateLimit>,
+    pub(crate) rate: RateLimit,
+}
+
+impl RateLimits {
+    pub fn get_rate(&self) -> RateLimit {
+        self.rate.clone()
+    }
+
+    pub fn get_resource(&self, resource: impl AsRef<str>) -> Option<RateLimit> {
+        self.resources.get(resource.as_ref())
+            .cloned()
+    }
+
+    pub fn get_core(&self) -> Option<RateLimit> {
+        self.get_resource("core")
+    }
+
+    pub fn get_search(&self) -> Option<RateLimit> {
+        self.get_resource("search")
+    }
+
+    pub fn get_graphql(&self) -> Option<RateLimit> {
+        self.get_resource("graphql")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let limits: RateLimits = serde_json::from_value(serde_json::json!({
+            "resources": {
+                "core": { "limit": 5000, "used": 1, "remaining": 4999, "reset": 1691591363 },
+                "search": { "limit": 30, "used": 30, "remaining": 0, "reset": 1691591091 },
+            },
+            "rate": { "limit": 5000, "used": 1, "remaining": 4999, "reset": 1372700873 },
+        })).unwrap();
+
+        let core = limits.get_core().unwrap();
+
+        assert_eq!(core.get_remaining(), 4999);
+        assert_eq!(core.get_reset().timestamp(), 1691591363);
+        assert!(limits.get_search().unwrap().is_exhausted());
+        assert!(limits.get_graphql().is_none());
+        assert_eq!(limits.get_rate().get_wait(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum ReactionKind {
+    #[serde(rename = "+1")]
+    PlusOne,
+    #[serde(rename = "-1")]
+    MinusOne,
+    #[serde(rename = "laugh")]
+    Laugh,
+    #[serde(rename = "confused")]
+    Confused,
+    #[serde(rename = "heart")]
+    Heart,
+    #[serde(rename = "hooray")]
+    Hooray,
+    #[serde(rename = "rocket")]
+    Rocket,
+    #[serde(rename = "eyes")]
+    Eyes,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Reaction {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) user: Option<User>,
+    pub(crate) content: ReactionKind,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+}
+
+impl Reaction {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_user(&self) -> Option<User> {
+        self.user.clone()
+    }
+
+    pub fn get_kind(&self) -> ReactionKind {
+        self.content
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct Reactions {
+    #[serde(rename = "total_count")]
+    pub(crate) total: Number,
+    #[serde(rename = "+1")]
+    pub(crate) plus_one: Number,
+    #[serde(rename = "-1")]
+    pub(crate) minus_one: Number,
+    pub(crate) laugh: Number,
+    pub(crate) hooray: Number,
+    pub(crate) confused: Number,
+    pub(crate) heart: Number,
+    pub(crate) rocket: Number,
+    pub(crate) eyes: Number,
+}
+
+impl Reactions {
+    pub fn get_total(&self) -> Number {
+        self.total
+    }
+
+    pub fn get_plus_one(&self) -> Number {
+        self.plus_one
+    }
+
+    pub fn get_minus_one(&self) -> Number {
+        self.minus_one
+    }
+
+    pub fn get_positive(&self) -> Number {
+        self.plus_one + self.laugh + self.hooray + self.heart + self.rocket
+    }
+
+    pub fn get_negative(&self) -> Number {
+        self.minus_one + self.confused
+    }
+
+    pub fn get_score(&self) -> isize {
+        self.get_positive() as isize - self.get_negative() as isize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+    pub(crate) state: String,
+    pub(crate) content_type: String,
+    pub(crate) size: usize,
+    pub(crate) download_count: usize,
+    pub(crate) url: String,
+    pub(crate) browser_download_url: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) updated_at: Date,
+    #[serde(default)]
+    pub(crate) uploader: Option<User>,
+}
+
+impl ReleaseAsset {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    pub fn get_state(&self) -> String {
+        self.state.clone()
+    }
+
+    pub fn get_content_type(&self) -> String {
+        self.content_type.clone()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get_download_count(&self) -> usize {
+        self.download_count
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    pub fn get_browser_download_url(&self) -> String {
+        self.browser_download_url.clone()
+    }
+
+    pub fn get_created_at(&self) -> Date {
+        self.created_at
+    }
+
+    pub fn get_updated_at(&self) -> Date {
+        self.updated_at
+    }
+
+    pub fn get_uploader(&self) -> Option<User> {
+        self.uploader.clone()
+    }
+
+    pub fn is_uploaded(&self) -> bool {
+        self.state == "uploaded"
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Release {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) tag_name: String,
+    pub(crate) target_commitish: String,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(default)]
+    pub(crate) body: Option<String>,
+    pub(crate) draft: bool,
+    pub(crate) prerelease: bool,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) published_at: Option<Date>,
+    #[serde(default)]
+    pub(crate) author: Option<User>,
+    #[serde(default)]
+    pub(crate) assets: Vec<ReleaseAsset>,
+    pub(crate) url: String,
+    pub(crate) html_url: String,
+    pub(crate) upload_url: String,
+    #[serde(default)]
+    pub(crate) tarball_url: Option<String>,
+    #[serde(default)]
+    pub(crate) zipball_url: Option<String>,
+}
+
+impl Release {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_tag(&self) -> String {
+        self.tag_name.clone()
+    }
+
+    pub fn get_target_commitish(&self) -> String {
+        self.target_commitish.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_body(&self) -> Option<String> {
+        self.body.clone()
+    }
+
+    pub fn get_created_at(&self) -> Date {
+        self.created_at
+    }
+
+    pub fn get_published_at(&self) -> Option<Date> {
+        self.published_at
+    }
+
+    pub fn get_author(&self) -> Option<User> {
+        self.author.clone()
+    }
+
+    pub fn get_assets(&self) -> Vec<ReleaseAsset> {
+        self.assets.clone()
+    }
+
+    pub fn get_asset(&self, name: impl AsRef<str>) -> Option<ReleaseAsset> {
+        self.assets.iter()
+            .find(|asset| asset.name == name.as_ref())
+            .cloned()
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+
+    pub fn get_html_url(&self) -> String {
+        self.html_url.clone()
+    }
+
+    pub fn get_upload_url(&self) -> String {
+        self.upload_url.clone()
+    }
+
+    pub fn get_tarball_url(&self) -> Option<String> {
+        self.tarball_url.clone()
+    }
+
+    pub fn get_zipball_url(&self) -> Option<String> {
+        self.zipball_url.clone()
+    }
+
+    pub fn is_draft(&self) -> bool {
+        self.draft
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        self.prerelease
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::fixtures::{round_trip};
+
+    use super::{ReleaseAsset, Release};
+
+    #[test]
+    fn test_round_trip() {
+        let release: Release = round_trip(include_str!("test_data/release.json"));
+
+        assert_eq!(release.get_tag(), "v1.0.0");
+        assert_eq!(release.get_target_commitish(), "master");
+        assert_eq!(release.get_author().map(|author| author.get_name()).as_deref(), Some("octocat"));
+        assert!(!(release.is_draft()));
+        assert!(release.is_prerelease());
+
+        let asset = release.get_asset("example.zip").unwrap();
+
+        assert!(asset.is_uploaded());
+        assert_eq!(asset.get_size(), 1024);
+        assert_eq!(asset.get_label(), None);
+
+        let draft: Release = round_trip(include_str!("test_data/release_draft.json"));
+
+        assert!(draft.is_draft());
+        assert!(draft.get_published_at().is_none());
+        assert!(draft.get_assets().is_empty());
+
+        let asset: ReleaseAsset = round_trip(include_str!("test_data/release_asset.json"));
+
+        assert_eq!(asset.get_label().as_deref(), Some("Checksums"));
+        assert_eq!(asset.get_download_count(), 42);
+    }
+}
@@ -7,6 +7,7 @@ use serde::{
 };
 
 use crate::models::common::user::{User};
+use crate::{Number};
 
 use std::{
 
@@ -29,6 +30,42 @@ pub enum Visibility {
     Internal,
 }
 
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Serialize, Deserialize)]
+pub enum Permission {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "triage")]
+    Triage,
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "maintain")]
+    Maintain,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Permission {
+    pub fn parse(permission: impl AsRef<str>) -> Option<Permission> {
+        match permission.as_ref() {
+            "none" => Some(Permission::None),
+            "read" | "pull" => Some(Permission::Read),
+            "triage" => Some(Permission::Triage),
+            "write" | "push" => Some(Permission::Write),
+            "maintain" => Some(Permission::Maintain),
+            "admin" => Some(Permission::Admin),
+            _ => None,
+        }
+    }
+
+    pub fn satisfies(&self, required: Permission) -> bool {
+        *self >= required
+    }
+}
+
 #[derive(Debug, Clone)]
 #[derive(Serialize, Deserialize)]
 pub enum Status {
@@ -127,16 +164,37 @@ pub struct Repository {
 
     archived: bool,
 
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    fork: bool,
+
+    #[serde(rename = "forks_count")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    forks: Number,
+
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    html_url: Option<String>,
+
+    #[serde(rename = "url")]
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    api_url: Option<String>,
+
     #[serde(rename = "created_at")]
     #[serde(skip_serializing)]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
     date_created: Option<Date>,
 
     #[serde(rename = "updated_at")]
     #[serde(skip_serializing)]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
     date_updated: Option<Date>,
 
     #[serde(rename = "pushed_at")]
     #[serde(skip_serializing)]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
     date_pushed: Option<Date>,
 }
 
@@ -329,6 +387,22 @@ impl Repository {
         self.archived = archived;
     }
 
+    pub fn get_html_url(&self) -> Option<String> {
+        self.html_url.clone()
+    }
+
+    pub fn get_api_url(&self) -> Option<String> {
+        self.api_url.clone()
+    }
+
+    pub fn is_fork(&self) -> bool {
+        self.fork.clone()
+    }
+
+    pub fn get_forks(&self) -> Number {
+        self.forks.clone()
+    }
+
     pub fn get_date_created(&self) -> Option<Date> {
         self.date_created.clone()
     }
@@ -346,4 +420,21 @@ impl FmtDisplay for Repository {
     fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
         write!(fmt, "{name}", name = self.name)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::fixtures::{round_trip};
+
+    use super::{Repository};
+
+    #[test]
+    fn test_round_trip() {
+        let repository: Repository = round_trip(include_str!("test_data/repository.json"));
+
+        assert_eq!(repository.get_name(), "Hello-World");
+        assert_eq!(repository.get_default_branch(), "master");
+        assert_eq!(repository.get_html_url().as_deref(), Some("https://github.com/octocat/Hello-World"));
+    }
+}
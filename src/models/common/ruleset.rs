@@ -0,0 +1,348 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use glob::{Pattern};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{Number};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum RulesetEnforcement {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "evaluate")]
+    Evaluate,
+    #[serde(rename = "disabled")]
+    Disabled,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct RequiredWorkflow {
+    pub(crate) path: String,
+    pub(crate) repository_id: Number,
+    #[serde(default, rename = "ref")]
+    pub(crate) reference: Option<String>,
+    #[serde(default)]
+    pub(crate) sha: Option<String>,
+}
+
+impl RequiredWorkflow {
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn get_repository_id(&self) -> Number {
+        self.repository_id.clone()
+    }
+
+    pub fn get_reference(&self) -> Option<String> {
+        self.reference.clone()
+    }
+
+    pub fn get_sha(&self) -> Option<String> {
+        self.sha.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct RequiredWorkflowsParameters {
+    #[serde(default)]
+    pub(crate) workflows: Vec<RequiredWorkflow>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RulesetRule {
+    #[serde(rename = "workflows")]
+    Workflows { parameters: RequiredWorkflowsParameters },
+    #[serde(rename = "creation")]
+    Creation,
+    #[serde(rename = "update")]
+    Update,
+    #[serde(rename = "deletion")]
+    Deletion,
+    #[serde(rename = "non_fast_forward")]
+    NonFastForward,
+    #[serde(rename = "required_signatures")]
+    RequiredSignatures,
+    #[serde(other)]
+    Other,
+}
+
+impl RulesetRule {
+    pub fn blocks(&self, operation: RuleOperation) -> bool {
+        match (self, operation) {
+            (RulesetRule::Creation, RuleOperation::Creation) => true,
+            (RulesetRule::Update, RuleOperation::Update) => true,
+            (RulesetRule::Update, RuleOperation::ForcePush) => true,
+            (RulesetRule::Deletion, RuleOperation::Deletion) => true,
+            (RulesetRule::NonFastForward, RuleOperation::ForcePush) => true,
+            (RulesetRule::RequiredSignatures, RuleOperation::UnsignedCommit) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(PartialEq, Eq)]
+pub enum RuleOperation {
+    Creation,
+    Update,
+    Deletion,
+    ForcePush,
+    UnsignedCommit,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct BranchRule {
+    #[serde(flatten)]
+    pub(crate) rule: RulesetRule,
+    #[serde(default)]
+    pub(crate) ruleset_id: Option<Number>,
+    #[serde(default)]
+    pub(crate) ruleset_source: Option<String>,
+}
+
+impl BranchRule {
+    pub fn get_rule(&self) -> RulesetRule {
+        self.rule.clone()
+    }
+
+    pub fn get_ruleset_id(&self) -> Option<Number> {
+        self.ruleset_id.clone()
+    }
+
+    pub fn get_ruleset_source(&self) -> Option<String> {
+        self.ruleset_source.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RuleViolation {
+    pub(crate) operation: RuleOperation,
+    pub(crate) rule: BranchRule,
+}
+
+impl RuleViolation {
+    pub fn get_operation(&self) -> RuleOperation {
+        self.operation
+    }
+
+    pub fn get_rule(&self) -> BranchRule {
+        self.rule.clone()
+    }
+}
+
+impl FmtDisplay for RuleViolation {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let reason = match self.operation {
+            RuleOperation::Creation => "creating this branch is restricted",
+            RuleOperation::Update => "updating this branch is restricted",
+            RuleOperation::Deletion => "deleting this branch is restricted",
+            RuleOperation::ForcePush => "force pushing to this branch is not allowed",
+            RuleOperation::UnsignedCommit => "commits to this branch must be signed",
+        };
+
+        match (self.rule.ruleset_source.as_ref(), self.rule.ruleset_id) {
+            (Some(source), Some(id)) => write!(fmt, "{reason} (ruleset {id} from '{source}')"),
+            (None, Some(id)) => write!(fmt, "{reason} (ruleset {id})"),
+            _ => write!(fmt, "{reason}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RulePreview {
+    pub(crate) branch: String,
+    pub(crate) rules: Vec<BranchRule>,
+}
+
+impl RulePreview {
+    pub fn get_branch(&self) -> String {
+        self.branch.clone()
+    }
+
+    pub fn get_rules(&self) -> Vec<BranchRule> {
+        self.rules.clone()
+    }
+
+    pub fn evaluate(&self, operation: RuleOperation) -> Vec<RuleViolation> {
+        self.rules.iter()
+            .filter(|rule| rule.rule.blocks(operation))
+            .map(|rule| RuleViolation { operation, rule: rule.clone() })
+            .collect()
+    }
+
+    pub fn is_blocked(&self, operation: RuleOperation) -> bool {
+        self.rules.iter()
+            .any(|rule| rule.rule.blocks(operation))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct RulesetNameCondition {
+    #[serde(default)]
+    pub(crate) include: Vec<String>,
+    #[serde(default)]
+    pub(crate) exclude: Vec<String>,
+}
+
+impl RulesetNameCondition {
+    pub fn get_include(&self) -> Vec<String> {
+        self.include.clone()
+    }
+
+    pub fn get_exclude(&self) -> Vec<String> {
+        self.exclude.clone()
+    }
+
+    pub fn matches(&self, name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+
+        let matches = |pattern: &String| match pattern.as_str() {
+            "~ALL" => true,
+            pattern => Pattern::new(pattern)
+                .map(|pattern| pattern.matches(name))
+                .unwrap_or(false),
+        };
+
+        self.include.iter().any(matches) && !(self.exclude.iter().any(matches))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct RulesetConditions {
+    #[serde(default)]
+    pub(crate) repository_name: Option<RulesetNameCondition>,
+    #[serde(default)]
+    pub(crate) ref_name: Option<RulesetNameCondition>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Ruleset {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) target: Option<String>,
+    pub(crate) enforcement: RulesetEnforcement,
+    #[serde(default)]
+    pub(crate) conditions: Option<RulesetConditions>,
+    #[serde(default)]
+    pub(crate) rules: Vec<RulesetRule>,
+}
+
+impl Ruleset {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_target(&self) -> Option<String> {
+        self.target.clone()
+    }
+
+    pub fn get_enforcement(&self) -> RulesetEnforcement {
+        self.enforcement
+    }
+
+    pub fn get_conditions(&self) -> Option<RulesetConditions> {
+        self.conditions.clone()
+    }
+
+    pub fn get_required_workflows(&self) -> Vec<RequiredWorkflow> {
+        self.rules.iter().flat_map(|rule| match rule {
+            RulesetRule::Workflows { parameters } => parameters.workflows.clone(),
+            _ => Vec::new(),
+        }).collect()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.enforcement == RulesetEnforcement::Active
+    }
+
+    pub fn covers(&self, repository: impl AsRef<str>) -> bool {
+        self.conditions.as_ref()
+            .and_then(|conditions| conditions.repository_name.as_ref())
+            .map_or(false, |condition| condition.matches(repository))
+    }
+
+    pub fn requires(&self, repository: impl AsRef<str>, path: impl AsRef<str>) -> bool {
+        self.is_active() && self.covers(repository) && self.get_required_workflows().iter()
+            .any(|workflow| workflow.path == path.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_workflows() {
+        let ruleset: Ruleset = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "name": "mandated-ci",
+            "target": "branch",
+            "enforcement": "active",
+            "conditions": {
+                "repository_name": { "include": ["~ALL"], "exclude": ["sandbox-*"] },
+                "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": [] },
+            },
+            "rules": [
+                { "type": "deletion" },
+                { "type": "workflows", "parameters": { "workflows": [
+                    { "path": ".github/workflows/ci.yml", "repository_id": 7, "ref": "refs/heads/main" },
+                ] } },
+            ],
+        })).unwrap();
+
+        assert_eq!(ruleset.get_required_workflows().len(), 1);
+        assert!(ruleset.requires("service", ".github/workflows/ci.yml"));
+        assert!(!(ruleset.requires("sandbox-test", ".github/workflows/ci.yml")));
+        assert!(!(ruleset.requires("service", ".github/workflows/lint.yml")));
+    }
+
+    #[test]
+    fn test_preview() {
+        let rules: Vec<BranchRule> = serde_json::from_value(serde_json::json!([
+            { "type": "deletion", "ruleset_source_type": "Repository", "ruleset_source": "octo/service", "ruleset_id": 5 },
+            { "type": "non_fast_forward", "ruleset_id": 6 },
+            { "type": "pull_request", "parameters": { "required_approving_review_count": 1 }, "ruleset_id": 6 },
+        ])).unwrap();
+
+        let preview = RulePreview { branch: "main".to_owned(), rules };
+
+        assert!(preview.is_blocked(RuleOperation::Deletion));
+        assert!(preview.is_blocked(RuleOperation::ForcePush));
+        assert!(!(preview.is_blocked(RuleOperation::UnsignedCommit)));
+
+        let violations = preview.evaluate(RuleOperation::Deletion);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].to_string(), "deleting this branch is restricted (ruleset 5 from 'octo/service')");
+    }
+}
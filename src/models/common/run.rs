@@ -0,0 +1,269 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    repository::sha::{Sha},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum WorkflowRunStatus {
+    #[serde(rename = "requested")]
+    Requested,
+    #[serde(rename = "waiting")]
+    Waiting,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "completed")]
+    Completed,
+}
+
+impl WorkflowRunStatus {
+    pub fn is_active(&self) -> bool {
+        match self {
+            WorkflowRunStatus::Completed => false,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum WorkflowRunConclusion {
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "neutral")]
+    Neutral,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[serde(rename = "timed_out")]
+    TimedOut,
+    #[serde(rename = "action_required")]
+    ActionRequired,
+    #[serde(rename = "stale")]
+    Stale,
+    #[serde(rename = "startup_failure")]
+    StartupFailure,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowRun {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    pub(crate) workflow_id: Number,
+    #[serde(default)]
+    pub(crate) path: Option<String>,
+    #[serde(default)]
+    pub(crate) head_branch: Option<String>,
+    pub(crate) head_sha: Sha<'static>,
+    pub(crate) event: String,
+    pub(crate) status: WorkflowRunStatus,
+    #[serde(default)]
+    pub(crate) conclusion: Option<WorkflowRunConclusion>,
+    #[serde(default)]
+    pub(crate) run_attempt: Option<Number>,
+    #[serde(rename = "run_started_at")]
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) date_started: Option<Date>,
+    #[serde(rename = "created_at")]
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) date_created: Date,
+    #[serde(rename = "updated_at")]
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) date_updated: Date,
+}
+
+impl WorkflowRun {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_workflow(&self) -> Number {
+        self.workflow_id.clone()
+    }
+
+    pub fn get_path(&self) -> Option<String> {
+        self.path.clone()
+    }
+
+    pub fn get_branch(&self) -> Option<String> {
+        self.head_branch.clone()
+    }
+
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.head_sha.clone()
+    }
+
+    pub fn get_event(&self) -> String {
+        self.event.clone()
+    }
+
+    pub fn get_status(&self) -> WorkflowRunStatus {
+        self.status
+    }
+
+    pub fn get_conclusion(&self) -> Option<WorkflowRunConclusion> {
+        self.conclusion
+    }
+
+    pub fn get_attempt(&self) -> Option<Number> {
+        self.run_attempt.clone()
+    }
+
+    pub fn get_date_started(&self) -> Option<Date> {
+        self.date_started.clone()
+    }
+
+    pub fn get_date_created(&self) -> Date {
+        self.date_created.clone()
+    }
+
+    pub fn get_date_updated(&self) -> Date {
+        self.date_updated.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowRunBillable {
+    #[serde(default)]
+    pub(crate) total_ms: Number,
+    #[serde(default)]
+    pub(crate) jobs: Number,
+}
+
+impl WorkflowRunBillable {
+    pub fn get_total_ms(&self) -> Number {
+        self.total_ms.clone()
+    }
+
+    pub fn get_jobs(&self) -> Number {
+        self.jobs.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowRunTiming {
+    #[serde(default)]
+    pub(crate) billable: BTreeMap<String, WorkflowRunBillable>,
+    #[serde(default)]
+    pub(crate) run_duration_ms: Option<Number>,
+}
+
+impl WorkflowRunTiming {
+    pub fn get_billable(&self) -> BTreeMap<String, WorkflowRunBillable> {
+        self.billable.clone()
+    }
+
+    pub fn get_billable_ms(&self) -> Number {
+        self.billable.values()
+            .map(|billable| billable.total_ms)
+            .sum()
+    }
+
+    pub fn get_duration_ms(&self) -> Option<Number> {
+        self.run_duration_ms.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+pub struct WorkflowDurationStatistics {
+    pub(crate) workflow: Number,
+    pub(crate) name: Option<String>,
+    pub(crate) runs: Number,
+    pub(crate) total_ms: Number,
+    pub(crate) maximum_ms: Number,
+    pub(crate) billable: BTreeMap<String, Number>,
+}
+
+impl WorkflowDurationStatistics {
+    pub fn aggregate(runs: impl IntoIterator<Item = (WorkflowRun, WorkflowRunTiming)>) -> Vec<WorkflowDurationStatistics> {
+        let mut statistics: BTreeMap<Number, WorkflowDurationStatistics> = BTreeMap::new();
+
+        for (run, timing) in runs {
+            let entry = statistics.entry(run.workflow_id).or_insert_with(|| WorkflowDurationStatistics {
+                workflow: run.workflow_id,
+                name: run.name.clone(),
+                .. Default::default()
+            });
+
+            let duration = timing.run_duration_ms
+                .unwrap_or_default();
+
+            entry.runs = { entry.runs + 1 };
+            entry.total_ms = { entry.total_ms + duration };
+            entry.maximum_ms = entry.maximum_ms.max(duration);
+
+            for (platform, billable) in timing.billable {
+                *entry.billable.entry(platform).or_default() += billable.total_ms;
+            }
+        }
+
+        statistics.into_values()
+            .collect()
+    }
+
+    pub fn get_workflow(&self) -> Number {
+        self.workflow.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_runs(&self) -> Number {
+        self.runs.clone()
+    }
+
+    pub fn get_total_ms(&self) -> Number {
+        self.total_ms.clone()
+    }
+
+    pub fn get_average_ms(&self) -> Number {
+        if self.runs > 0 { self.total_ms / self.runs } else { 0 }
+    }
+
+    pub fn get_maximum_ms(&self) -> Number {
+        self.maximum_ms.clone()
+    }
+
+    pub fn get_billable(&self) -> BTreeMap<String, Number> {
+        self.billable.clone()
+    }
+
+    pub fn get_billable_ms(&self) -> Number {
+        self.billable.values()
+            .sum()
+    }
+}
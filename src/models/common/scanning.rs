@@ -0,0 +1,227 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassRequestStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "denied")]
+    Denied,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "expired")]
+    Expired,
+    #[serde(rename = "deleted")]
+    Deleted,
+    #[serde(rename = "open")]
+    Open,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassTimePeriod {
+    #[serde(rename = "hour")]
+    Hour,
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+    #[serde(rename = "month")]
+    Month,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassReview {
+    #[serde(rename = "approve")]
+    Approve,
+    #[serde(rename = "deny")]
+    Deny,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum BypassReason {
+    #[serde(rename = "false_positive")]
+    FalsePositive,
+    #[serde(rename = "used_in_tests")]
+    UsedInTests,
+    #[serde(rename = "will_fix_later")]
+    WillFixLater,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct BypassRequester {
+    #[serde(rename = "actor_id")]
+    pub(crate) id: Number,
+    #[serde(rename = "actor_name")]
+    pub(crate) name: String,
+}
+
+impl BypassRequester {
+    pub fn get_id(&self) -> Number {
+        self.id.clone()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct BypassRequestSecret {
+    pub(crate) secret_type: String,
+    #[serde(default)]
+    pub(crate) bypass_reason: Option<BypassReason>,
+    #[serde(default)]
+    pub(crate) path: Option<String>,
+}
+
+impl BypassRequestSecret {
+    pub fn get_secret_type(&self) -> String {
+        self.secret_type.clone()
+    }
+
+    pub fn get_reason(&self) -> Option<BypassReason> {
+        self.bypass_reason
+    }
+
+    pub fn get_path(&self) -> Option<String> {
+        self.path.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct BypassRequest {
+    pub(crate) id: Number,
+    pub(crate) number: Number,
+    pub(crate) status: BypassRequestStatus,
+    pub(crate) requester: BypassRequester,
+    #[serde(default)]
+    pub(crate) requester_comment: Option<String>,
+    #[serde(default)]
+    pub(crate) data: Vec<BypassRequestSecret>,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) expires_at: Option<Date>,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+}
+
+impl BypassRequest {
+    pub fn get_id(&self) -> Number {
+        self.id.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_status(&self) -> BypassRequestStatus {
+        self.status
+    }
+
+    pub fn get_requester(&self) -> BypassRequester {
+        self.requester.clone()
+    }
+
+    pub fn get_comment(&self) -> Option<String> {
+        self.requester_comment.clone()
+    }
+
+    pub fn get_secrets(&self) -> Vec<BypassRequestSecret> {
+        self.data.clone()
+    }
+
+    pub fn get_expires(&self) -> Option<Date> {
+        self.expires_at.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == BypassRequestStatus::Pending
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct PushProtectionBypass {
+    pub(crate) reason: BypassReason,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) expire_at: Date,
+    pub(crate) token_type: String,
+}
+
+impl PushProtectionBypass {
+    pub fn get_reason(&self) -> BypassReason {
+        self.reason
+    }
+
+    pub fn get_expires(&self) -> Date {
+        self.expire_at.clone()
+    }
+
+    pub fn get_token_type(&self) -> String {
+        self.token_type.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let request: BypassRequest = serde_json::from_value(serde_json::json!({
+            "id": 21,
+            "number": 42,
+            "status": "pending",
+            "requester": {
+                "actor_id": 12,
+                "actor_name": "monalisa",
+            },
+            "requester_comment": "Test token used in the readme as an example",
+            "data": [
+                {
+                    "secret_type": "adafruit_io_key",
+                    "bypass_reason": "used_in_tests",
+                    "path": "/tests/README.md:16:0",
+                },
+            ],
+            "expires_at": "2024-07-08T08:43:03Z",
+            "created_at": "2024-07-01T08:43:03Z",
+            "request_type": "secret_scanning_closure",
+        })).unwrap();
+
+        assert!(request.is_pending());
+        assert_eq!(request.get_number(), 42);
+        assert_eq!(request.get_requester().get_name(), "monalisa");
+        assert_eq!(request.get_secrets()[0].get_reason(), Some(BypassReason::UsedInTests));
+    }
+}
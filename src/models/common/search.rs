@@ -0,0 +1,98 @@
+use std::{
+
+    path::{PathBuf},
+};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{repository::sha::{Sha}};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct TextMatchSpan {
+    pub(crate) text: String,
+    pub(crate) indices: (usize, usize),
+}
+
+impl TextMatchSpan {
+    pub fn get_text(&self) -> String {
+        self.text.clone()
+    }
+
+    pub fn get_indices(&self) -> (usize, usize) {
+        self.indices
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct TextMatch {
+    #[serde(default)]
+    pub(crate) property: Option<String>,
+    #[serde(default)]
+    pub(crate) fragment: String,
+    #[serde(default)]
+    pub(crate) matches: Vec<TextMatchSpan>,
+}
+
+impl TextMatch {
+    pub fn get_property(&self) -> Option<String> {
+        self.property.clone()
+    }
+
+    pub fn get_fragment(&self) -> String {
+        self.fragment.clone()
+    }
+
+    pub fn get_matches(&self) -> Vec<TextMatchSpan> {
+        self.matches.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct CodeSearchRepository {
+    pub(crate) full_name: String,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct CodeSearchMatch {
+    pub(crate) name: String,
+    pub(crate) path: PathBuf,
+    pub(crate) sha: Sha<'static>,
+    pub(crate) html_url: String,
+    pub(crate) repository: CodeSearchRepository,
+    #[serde(default)]
+    pub(crate) text_matches: Vec<TextMatch>,
+}
+
+impl CodeSearchMatch {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.sha.clone()
+    }
+
+    pub fn get_html_url(&self) -> String {
+        self.html_url.clone()
+    }
+
+    pub fn get_repository(&self) -> String {
+        self.repository.full_name.clone()
+    }
+
+    pub fn get_text_matches(&self) -> Vec<TextMatch> {
+        self.text_matches.clone()
+    }
+}
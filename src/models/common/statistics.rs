@@ -0,0 +1,277 @@
+use std::{
+
+    collections::{
+
+        BTreeMap,
+        HashMap,
+    },
+};
+
+use chrono::{TimeZone, Utc};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ContributorWeek {
+    #[serde(rename = "w", with = "chrono::serde::ts_seconds")]
+    pub(crate) week: Date,
+    #[serde(rename = "a")]
+    pub(crate) additions: Number,
+    #[serde(rename = "d")]
+    pub(crate) deletions: Number,
+    #[serde(rename = "c")]
+    pub(crate) commits: Number,
+}
+
+impl ContributorWeek {
+    pub fn get_week(&self) -> Date {
+        self.week.clone()
+    }
+
+    pub fn get_additions(&self) -> Number {
+        self.additions.clone()
+    }
+
+    pub fn get_deletions(&self) -> Number {
+        self.deletions.clone()
+    }
+
+    pub fn get_commits(&self) -> Number {
+        self.commits.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct ContributorStatistics {
+    pub(crate) author: Option<User>,
+    pub(crate) total: Number,
+    pub(crate) weeks: Vec<ContributorWeek>,
+}
+
+impl ContributorStatistics {
+    pub fn get_author(&self) -> Option<User> {
+        self.author.clone()
+    }
+
+    pub fn get_total(&self) -> Number {
+        self.total.clone()
+    }
+
+    pub fn get_weeks(&self) -> Vec<ContributorWeek> {
+        self.weeks.clone()
+    }
+
+    pub fn leaderboard(statistics: impl AsRef<[ContributorStatistics]>, since: Option<Date>) -> Vec<ContributorRank> {
+        let mut ranks: HashMap<String, ContributorRank> = HashMap::new();
+
+        for ContributorStatistics { author, weeks, .. } in statistics.as_ref() {
+            let author = author.as_ref()
+                .map(User::get_name)
+                .unwrap_or_else(|| "ghost".to_owned());
+
+            let rank = ranks.entry(author.clone()).or_insert_with(|| ContributorRank {
+                author, .. Default::default()
+            });
+
+            for week in weeks.iter().filter(|week| {
+                since.map_or(true, |since| week.week >= since)
+            }) {
+                rank.additions = rank.additions + week.additions;
+                rank.deletions = rank.deletions + week.deletions;
+                rank.commits = rank.commits + week.commits;
+            }
+        }
+
+        let mut ranks: Vec<ContributorRank> = ranks.into_values()
+            .filter(|rank| rank.commits > 0)
+            .collect();
+
+        ranks.sort_by(|one, two| {
+            two.commits.cmp(&(one.commits))
+                .then_with(|| (two.additions + two.deletions).cmp(&(one.additions + one.deletions)))
+                .then_with(|| one.author.cmp(&(two.author)))
+        });
+
+        ranks
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+#[derive(PartialEq, Eq)]
+pub struct ContributorRank {
+    pub(crate) author: String,
+    pub(crate) additions: Number,
+    pub(crate) deletions: Number,
+    pub(crate) commits: Number,
+}
+
+impl ContributorRank {
+    pub fn get_author(&self) -> String {
+        self.author.clone()
+    }
+
+    pub fn get_additions(&self) -> Number {
+        self.additions.clone()
+    }
+
+    pub fn get_deletions(&self) -> Number {
+        self.deletions.clone()
+    }
+
+    pub fn get_commits(&self) -> Number {
+        self.commits.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(try_from = "(i64, i64, i64)")]
+#[serde(into = "(i64, i64, i64)")]
+pub struct CodeFrequency {
+    pub(crate) week: Date,
+    pub(crate) additions: Number,
+    pub(crate) deletions: Number,
+}
+
+impl CodeFrequency {
+    pub fn get_week(&self) -> Date {
+        self.week.clone()
+    }
+
+    pub fn get_additions(&self) -> Number {
+        self.additions.clone()
+    }
+
+    pub fn get_deletions(&self) -> Number {
+        self.deletions.clone()
+    }
+}
+
+impl TryFrom<(i64, i64, i64)> for CodeFrequency {
+    type Error = String;
+
+    fn try_from((week, additions, deletions): (i64, i64, i64)) -> Result<Self, Self::Error> {
+        Ok(CodeFrequency {
+            week: Utc.timestamp_opt(week, 0).single().ok_or_else(|| {
+                format!("invalid week timestamp: {week}")
+            })?,
+            additions: additions.unsigned_abs() as Number,
+            deletions: deletions.unsigned_abs() as Number,
+        })
+    }
+}
+
+impl From<CodeFrequency> for (i64, i64, i64) {
+    fn from(CodeFrequency { week, additions, deletions }: CodeFrequency) -> Self {
+        (week.timestamp(), additions as i64, -(deletions as i64))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Languages {
+    pub(crate) bytes: BTreeMap<String, u64>,
+}
+
+impl Languages {
+    pub fn get_bytes(&self) -> BTreeMap<String, u64> {
+        self.bytes.clone()
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.bytes.values().sum()
+    }
+
+    pub fn get_primary(&self) -> Option<String> {
+        self.bytes.iter()
+            .max_by_key(|(_, bytes)| **bytes)
+            .map(|(language, _)| language.clone())
+    }
+
+    pub fn get_percentages(&self) -> Vec<(String, f64)> {
+        let total = self.get_total();
+
+        if total == 0 {
+            return Vec::new()
+        }
+
+        let mut percentages: Vec<(String, f64)> = self.bytes.iter()
+            .map(|(language, bytes)| (language.clone(), (*bytes as f64) * 100.0 / (total as f64)))
+            .collect();
+
+        percentages.sort_by(|(a, x), (b, y)| y.total_cmp(x).then_with(|| a.cmp(b)));
+        percentages
+    }
+
+    pub fn merge(languages: impl IntoIterator<Item = Languages>) -> Languages {
+        let mut bytes = BTreeMap::new();
+
+        for Languages { bytes: other } in languages {
+            for (language, count) in other {
+                *bytes.entry(language).or_insert(0) += count;
+            }
+        }
+
+        Languages { bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaderboard() {
+        let ref statistics: Vec<ContributorStatistics> = serde_json::from_str(r#"[
+            { "author": null, "total": 1, "weeks": [{ "w": 1367712000, "a": 5, "d": 1, "c": 1 }] },
+            { "author": null, "total": 2, "weeks": [{ "w": 1367712000, "a": 1, "d": 0, "c": 1 }, { "w": 1368316800, "a": 2, "d": 2, "c": 1 }] }
+        ]"#).unwrap();
+
+        let ranks = ContributorStatistics::leaderboard(statistics, None);
+
+        assert_eq!(ranks.len(), 1);
+        assert_eq!(ranks[0].get_commits(), 3);
+        assert_eq!(ranks[0].get_additions(), 8);
+
+        let since = Utc.timestamp_opt(1368316800, 0)
+            .unwrap();
+
+        let ranks = ContributorStatistics::leaderboard(statistics, Some(since));
+
+        assert_eq!(ranks[0].get_commits(), 1);
+        assert_eq!(ranks[0].get_deletions(), 2);
+
+        let frequency: Vec<CodeFrequency> = serde_json::from_str("[[1302998400, 1124, -435]]")
+            .unwrap();
+
+        assert_eq!(frequency[0].get_deletions(), 435);
+    }
+
+    #[test]
+    fn test_languages() {
+        let languages: Languages = serde_json::from_str(r#"{ "Rust": 300, "Shell": 100 }"#).unwrap();
+
+        assert_eq!(languages.get_total(), 400);
+        assert_eq!(languages.get_primary(), Some("Rust".to_owned()));
+        assert_eq!(languages.get_percentages(), vec![
+            ("Rust".to_owned(), 75.0),
+            ("Shell".to_owned(), 25.0),
+        ]);
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{repository::sha::{Sha}};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct TagCommit {
+    pub(crate) sha: Sha<'static>,
+    pub(crate) url: String,
+}
+
+impl TagCommit {
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.sha.clone()
+    }
+
+    pub fn get_url(&self) -> String {
+        self.url.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct Tag {
+    pub(crate) name: String,
+    pub(crate) commit: TagCommit,
+    pub(crate) zipball_url: String,
+    pub(crate) tarball_url: String,
+    pub(crate) node_id: String,
+}
+
+impl Tag {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_commit(&self) -> TagCommit {
+        self.commit.clone()
+    }
+
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.commit.get_sha()
+    }
+
+    pub fn get_zipball_url(&self) -> String {
+        self.zipball_url.clone()
+    }
+
+    pub fn get_tarball_url(&self) -> String {
+        self.tarball_url.clone()
+    }
+
+    pub fn get_node_id(&self) -> String {
+        self.node_id.clone()
+    }
+}
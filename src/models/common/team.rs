@@ -74,4 +74,19 @@ impl Into<Number> for Team {
     fn into(self) -> Number {
         self.number.clone()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::fixtures::{round_trip};
+
+    use super::{Team};
+
+    #[test]
+    fn test_round_trip() {
+        let team: Team = round_trip(include_str!("test_data/team.json"));
+
+        assert_eq!(team.get_slug(), "justice-league");
+    }
+}
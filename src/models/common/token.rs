@@ -0,0 +1,184 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TokenRepositorySelection {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "subset")]
+    Subset,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum TokenReview {
+    #[serde(rename = "approve")]
+    Approve,
+    #[serde(rename = "deny")]
+    Deny,
+}
+
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+pub struct TokenPermissions {
+    #[serde(default)]
+    pub(crate) organization: BTreeMap<String, String>,
+    #[serde(default)]
+    pub(crate) repository: BTreeMap<String, String>,
+    #[serde(default)]
+    pub(crate) other: BTreeMap<String, String>,
+}
+
+impl TokenPermissions {
+    pub fn get_organization(&self) -> BTreeMap<String, String> {
+        self.organization.clone()
+    }
+
+    pub fn get_repository(&self) -> BTreeMap<String, String> {
+        self.repository.clone()
+    }
+
+    pub fn get_other(&self) -> BTreeMap<String, String> {
+        self.other.clone()
+    }
+
+    pub fn has_write(&self) -> bool {
+        self.organization.values()
+            .chain(self.repository.values())
+            .chain(self.other.values())
+            .any(|access| access == "write" || access == "admin")
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct TokenRequest {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    #[serde(default)]
+    pub(crate) reason: Option<String>,
+    pub(crate) owner: User,
+    pub(crate) repository_selection: TokenRepositorySelection,
+    #[serde(default)]
+    pub(crate) permissions: TokenPermissions,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(default)]
+    pub(crate) token_expired: bool,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) token_expires_at: Option<Date>,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) token_last_used_at: Option<Date>,
+}
+
+impl TokenRequest {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_reason(&self) -> Option<String> {
+        self.reason.clone()
+    }
+
+    pub fn get_owner(&self) -> User {
+        self.owner.clone()
+    }
+
+    pub fn get_repository_selection(&self) -> TokenRepositorySelection {
+        self.repository_selection
+    }
+
+    pub fn get_permissions(&self) -> TokenPermissions {
+        self.permissions.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn get_expires(&self) -> Option<Date> {
+        self.token_expires_at.clone()
+    }
+
+    pub fn get_last_used(&self) -> Option<Date> {
+        self.token_last_used_at.clone()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.token_expired
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct TokenGrant {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) owner: User,
+    pub(crate) repository_selection: TokenRepositorySelection,
+    #[serde(default)]
+    pub(crate) permissions: TokenPermissions,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) access_granted_at: Date,
+    #[serde(default)]
+    pub(crate) token_expired: bool,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) token_expires_at: Option<Date>,
+    #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+    pub(crate) token_last_used_at: Option<Date>,
+}
+
+impl TokenGrant {
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_owner(&self) -> User {
+        self.owner.clone()
+    }
+
+    pub fn get_repository_selection(&self) -> TokenRepositorySelection {
+        self.repository_selection
+    }
+
+    pub fn get_permissions(&self) -> TokenPermissions {
+        self.permissions.clone()
+    }
+
+    pub fn get_granted(&self) -> Date {
+        self.access_granted_at.clone()
+    }
+
+    pub fn get_expires(&self) -> Option<Date> {
+        self.token_expires_at.clone()
+    }
+
+    pub fn get_last_used(&self) -> Option<Date> {
+        self.token_last_used_at.clone()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.token_expired
+    }
+}
@@ -137,4 +137,52 @@ impl AsRef<str> for User {
             User::Bot { name, .. } => name.as_ref(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuthorAssociation {
+    None,
+    Mannequin,
+    FirstTimer,
+    FirstTimeContributor,
+    Contributor,
+    Collaborator,
+    Member,
+    Owner,
+}
+
+impl AuthorAssociation {
+    pub fn satisfies(&self, required: AuthorAssociation) -> bool {
+        *self >= required
+    }
+
+    pub fn is_maintainer(&self) -> bool {
+        self.satisfies(AuthorAssociation::Collaborator)
+    }
+
+    pub fn is_first_time(&self) -> bool {
+        match self {
+            AuthorAssociation::FirstTimeContributor |
+            AuthorAssociation::FirstTimer => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::models::fixtures::{round_trip};
+
+    use super::{User};
+
+    #[test]
+    fn test_round_trip() {
+        let user: User = round_trip(include_str!("test_data/user.json"));
+
+        assert_eq!(user.is_user(), true);
+        assert_eq!(user.get_name(), "octocat");
+    }
+}
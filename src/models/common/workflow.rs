@@ -0,0 +1,400 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use serde::{
+
+    Deserializer,
+    Deserialize,
+    Serialize,
+};
+
+use serde_yaml::{Value};
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WorkflowTriggers {
+    Event(String),
+    Events(Vec<String>),
+    Configured(BTreeMap<String, Value>),
+}
+
+impl WorkflowTriggers {
+    pub fn get_events(&self) -> Vec<String> {
+        match self {
+            WorkflowTriggers::Event(event) => vec![event.clone()],
+            WorkflowTriggers::Events(events) => events.clone(),
+            WorkflowTriggers::Configured(events) => events.keys()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn get_config(&self, event: impl AsRef<str>) -> Option<Value> {
+        match self {
+            WorkflowTriggers::Configured(events) => events.get(event.as_ref())
+                .cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn has_event(&self, event: impl AsRef<str>) -> bool {
+        let event = event.as_ref();
+
+        self.get_events().iter()
+            .any(|candidate| candidate == event)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WorkflowPermissions {
+    Preset(String),
+    Scoped(BTreeMap<String, String>),
+}
+
+impl WorkflowPermissions {
+    pub fn get_level(&self, scope: impl AsRef<str>) -> Option<String> {
+        match self {
+            WorkflowPermissions::Preset(preset) => match preset.as_str() {
+                "read-all" => Some("read".to_owned()),
+                "write-all" => Some("write".to_owned()),
+                _ => None,
+            },
+            WorkflowPermissions::Scoped(scopes) => scopes.get(scope.as_ref())
+                .cloned(),
+        }
+    }
+
+    pub fn is_read_all(&self) -> bool {
+        matches!(self, WorkflowPermissions::Preset(preset) if preset == "read-all")
+    }
+
+    pub fn is_write_all(&self) -> bool {
+        matches!(self, WorkflowPermissions::Preset(preset) if preset == "write-all")
+    }
+
+    pub fn has_write(&self) -> bool {
+        match self {
+            WorkflowPermissions::Preset(preset) => preset == "write-all",
+            WorkflowPermissions::Scoped(scopes) => scopes.values()
+                .any(|level| level == "write"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RunsOn {
+    Label(String),
+    Labels(Vec<String>),
+    Group {
+        #[serde(default)]
+        group: Option<String>,
+        #[serde(default)]
+        labels: Option<Box<RunsOn>>,
+    },
+}
+
+impl RunsOn {
+    pub fn get_labels(&self) -> Vec<String> {
+        match self {
+            RunsOn::Label(label) => vec![label.clone()],
+            RunsOn::Labels(labels) => labels.clone(),
+            RunsOn::Group { labels, .. } => labels.as_ref()
+                .map(|labels| labels.get_labels())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn get_group(&self) -> Option<String> {
+        match self {
+            RunsOn::Group { group, .. } => group.clone(),
+            _ => None,
+        }
+    }
+
+    pub fn is_self_hosted(&self) -> bool {
+        self.get_labels().iter()
+            .any(|label| label == "self-hosted")
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowStep {
+    #[serde(default)]
+    pub(crate) id: Option<String>,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(default)]
+    pub(crate) uses: Option<String>,
+    #[serde(default)]
+    pub(crate) run: Option<String>,
+    #[serde(default)]
+    pub(crate) with: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub(crate) env: BTreeMap<String, Value>,
+    #[serde(default, rename = "if")]
+    pub(crate) condition: Option<Value>,
+}
+
+impl WorkflowStep {
+    pub fn get_id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_uses(&self) -> Option<String> {
+        self.uses.clone()
+    }
+
+    pub fn get_run(&self) -> Option<String> {
+        self.run.clone()
+    }
+
+    pub fn get_with(&self) -> BTreeMap<String, Value> {
+        self.with.clone()
+    }
+
+    pub fn get_env(&self) -> BTreeMap<String, Value> {
+        self.env.clone()
+    }
+
+    pub fn get_condition(&self) -> Option<Value> {
+        self.condition.clone()
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.uses.as_deref()
+            .map_or(false, is_pinned)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowJob {
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(default, rename = "runs-on")]
+    pub(crate) runs_on: Option<RunsOn>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub(crate) needs: Vec<String>,
+    #[serde(default)]
+    pub(crate) permissions: Option<WorkflowPermissions>,
+    #[serde(default)]
+    pub(crate) uses: Option<String>,
+    #[serde(default)]
+    pub(crate) environment: Option<Value>,
+    #[serde(default, rename = "if")]
+    pub(crate) condition: Option<Value>,
+    #[serde(default)]
+    pub(crate) steps: Vec<WorkflowStep>,
+}
+
+impl WorkflowJob {
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_runs_on(&self) -> Option<RunsOn> {
+        self.runs_on.clone()
+    }
+
+    pub fn get_needs(&self) -> Vec<String> {
+        self.needs.clone()
+    }
+
+    pub fn get_permissions(&self) -> Option<WorkflowPermissions> {
+        self.permissions.clone()
+    }
+
+    pub fn get_uses(&self) -> Option<String> {
+        self.uses.clone()
+    }
+
+    pub fn get_environment(&self) -> Option<Value> {
+        self.environment.clone()
+    }
+
+    pub fn get_condition(&self) -> Option<Value> {
+        self.condition.clone()
+    }
+
+    pub fn get_steps(&self) -> &[WorkflowStep] {
+        self.steps.as_slice()
+    }
+
+    pub fn is_reusable(&self) -> bool {
+        self.uses.is_some()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(default, rename = "run-name")]
+    pub(crate) run_name: Option<String>,
+    #[serde(rename = "on")]
+    pub(crate) triggers: WorkflowTriggers,
+    #[serde(default)]
+    pub(crate) permissions: Option<WorkflowPermissions>,
+    #[serde(default)]
+    pub(crate) env: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub(crate) jobs: BTreeMap<String, WorkflowJob>,
+}
+
+impl WorkflowDefinition {
+    pub fn try_parse(content: impl AsRef<str>) -> Result<WorkflowDefinition, serde_yaml::Error> {
+        serde_yaml::from_str(content.as_ref())
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn get_run_name(&self) -> Option<String> {
+        self.run_name.clone()
+    }
+
+    pub fn get_triggers(&self) -> WorkflowTriggers {
+        self.triggers.clone()
+    }
+
+    pub fn get_permissions(&self) -> Option<WorkflowPermissions> {
+        self.permissions.clone()
+    }
+
+    pub fn get_env(&self) -> BTreeMap<String, Value> {
+        self.env.clone()
+    }
+
+    pub fn get_jobs(&self) -> BTreeMap<String, WorkflowJob> {
+        self.jobs.clone()
+    }
+
+    pub fn get_job(&self, id: impl AsRef<str>) -> Option<WorkflowJob> {
+        self.jobs.get(id.as_ref())
+            .cloned()
+    }
+
+    pub fn get_actions(&self) -> Vec<String> {
+        let mut actions: Vec<String> = self.jobs.values().flat_map(|job| {
+            job.uses.iter()
+                .chain(job.steps.iter().filter_map(|step| step.uses.as_ref()))
+                .cloned()
+        }).collect();
+
+        actions.sort();
+        actions.dedup();
+        actions
+    }
+
+    pub fn get_unpinned_actions(&self) -> Vec<String> {
+        self.get_actions().into_iter()
+            .filter(|action| !(action.starts_with("./")))
+            .filter(|action| !(action.starts_with("docker://")))
+            .filter(|action| !(is_pinned(action)))
+            .collect()
+    }
+}
+
+fn is_pinned(uses: &str) -> bool {
+    match uses.rsplit_once('@') {
+        Some((_, version)) => version.len() == 40 && version.chars().all(|character| {
+            character.is_ascii_hexdigit()
+        }),
+        None => false,
+    }
+}
+
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Capsule {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Capsule::deserialize(deserializer)? {
+        Capsule::One(one) => vec![one],
+        Capsule::Many(many) => many,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let definition = WorkflowDefinition::try_parse(r#"
+name: CI
+on:
+  push:
+    branches: [main]
+  workflow_dispatch:
+permissions:
+  contents: read
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@b4ffde65f46336ab88eb53be808477a3936bae11
+      - uses: actions/setup-node@v4
+        with:
+          node-version: 20
+      - run: npm test
+  deploy:
+    needs: build
+    runs-on: [self-hosted, linux]
+    permissions: write-all
+    steps:
+      - uses: ./.github/actions/deploy
+  shared:
+    needs: [build, deploy]
+    uses: dev-bio/workflows/.github/workflows/release.yml@main
+"#).unwrap();
+
+        assert_eq!(definition.get_name().as_deref(), Some("CI"));
+        assert!(definition.get_triggers().has_event("workflow_dispatch"));
+        assert!(definition.get_triggers().get_config("push").is_some());
+        assert!(!(definition.get_permissions().unwrap().has_write()));
+
+        let build = definition.get_job("build").unwrap();
+
+        assert_eq!(build.get_runs_on().unwrap().get_labels(), vec!["ubuntu-latest"]);
+        assert!(build.get_steps()[0].is_pinned());
+        assert!(!(build.get_steps()[1].is_pinned()));
+
+        let deploy = definition.get_job("deploy").unwrap();
+
+        assert_eq!(deploy.get_needs(), vec!["build"]);
+        assert!(deploy.get_runs_on().unwrap().is_self_hosted());
+        assert!(deploy.get_permissions().unwrap().is_write_all());
+
+        let shared = definition.get_job("shared").unwrap();
+
+        assert!(shared.is_reusable());
+        assert_eq!(shared.get_needs(), vec!["build", "deploy"]);
+
+        assert_eq!(definition.get_unpinned_actions(), vec![
+            "actions/setup-node@v4",
+            "dev-bio/workflows/.github/workflows/release.yml@main",
+        ]);
+
+        let definition = WorkflowDefinition::try_parse("on: [push, pull_request]\njobs: {}").unwrap();
+
+        assert_eq!(definition.get_triggers().get_events(), vec!["push", "pull_request"]);
+    }
+}
@@ -9,6 +9,16 @@ pub use payloads::{
     
     EventIssueComment,
     EventIssue, 
+    EventPullRequest,
+    EventMilestone,
+    EventWatch,
+    EventStar,
+    EventFork,
+    EventLabel,
+    EventCreate,
+    EventDelete,
+    EventPush,
+    RefKind,
 };
 
 #[derive(Debug, Clone)]
@@ -19,6 +29,24 @@ pub enum Event {
     IssueComment(EventIssueComment),
     #[serde(rename = "issues")]
     Issue(EventIssue),
+    #[serde(rename = "pull_request")]
+    PullRequest(EventPullRequest),
+    #[serde(rename = "push")]
+    Push(EventPush),
+    #[serde(rename = "create")]
+    Create(EventCreate),
+    #[serde(rename = "delete")]
+    Delete(EventDelete),
+    #[serde(rename = "label")]
+    Label(EventLabel),
+    #[serde(rename = "milestone")]
+    Milestone(EventMilestone),
+    #[serde(rename = "star")]
+    Star(EventStar),
+    #[serde(rename = "watch")]
+    Watch(EventWatch),
+    #[serde(rename = "fork")]
+    Fork(EventFork),
     #[serde(rename = "schedule")]
     Schedule,
 }
\ No newline at end of file
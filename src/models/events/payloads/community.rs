@@ -0,0 +1,216 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::user::{User},
+    common::{Date},
+
+    Number,
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventRepository {
+    #[serde(rename = "id")]
+    pub(crate) number: Number,
+    pub(crate) name: String,
+    pub(crate) full_name: String,
+    pub(crate) owner: User,
+    #[serde(default)]
+    pub(crate) private: bool,
+    #[serde(default)]
+    pub(crate) fork: bool,
+    #[serde(default)]
+    pub(crate) stargazers_count: Number,
+    #[serde(default)]
+    pub(crate) watchers_count: Number,
+    #[serde(default)]
+    pub(crate) forks_count: Number,
+}
+
+impl EventRepository {
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_full_name(&self) -> String {
+        self.full_name.clone()
+    }
+
+    pub fn get_owner(&self) -> User {
+        self.owner.clone()
+    }
+
+    pub fn get_stargazers(&self) -> Number {
+        self.stargazers_count
+    }
+
+    pub fn get_watchers(&self) -> Number {
+        self.watchers_count
+    }
+
+    pub fn get_forks(&self) -> Number {
+        self.forks_count
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+
+    pub fn is_fork(&self) -> bool {
+        self.fork
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum EventStar {
+    #[serde(rename = "created")]
+    Created { 
+        #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+        starred_at: Option<Date>,
+        repository: EventRepository, 
+        sender: User,
+    },
+    #[serde(rename = "deleted")]
+    Deleted { 
+        repository: EventRepository, 
+        sender: User,
+    },
+}
+
+impl EventStar {
+    pub fn get_repository(&self) -> EventRepository {
+        match self {
+            EventStar::Created { repository, .. } |
+            EventStar::Deleted { repository, .. } => repository.clone(),
+        }
+    }
+
+    pub fn get_sender(&self) -> User {
+        match self {
+            EventStar::Created { sender, .. } |
+            EventStar::Deleted { sender, .. } => sender.clone(),
+        }
+    }
+
+    pub fn get_starred(&self) -> Option<Date> {
+        match self {
+            EventStar::Created { starred_at, .. } => starred_at.clone(),
+            EventStar::Deleted { .. } => None,
+        }
+    }
+
+    pub fn is_starred(&self) -> bool {
+        match self {
+            EventStar::Created { .. } => true,
+            EventStar::Deleted { .. } => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum EventWatch {
+    #[serde(rename = "started")]
+    Started { 
+        repository: EventRepository, 
+        sender: User,
+    },
+}
+
+impl EventWatch {
+    pub fn get_repository(&self) -> EventRepository {
+        match self {
+            EventWatch::Started { repository, .. } => repository.clone(),
+        }
+    }
+
+    pub fn get_sender(&self) -> User {
+        match self {
+            EventWatch::Started { sender, .. } => sender.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventFork {
+    pub(crate) forkee: EventRepository,
+    pub(crate) repository: EventRepository,
+    pub(crate) sender: User,
+}
+
+impl EventFork {
+    pub fn get_fork(&self) -> EventRepository {
+        self.forkee.clone()
+    }
+
+    pub fn get_repository(&self) -> EventRepository {
+        self.repository.clone()
+    }
+
+    pub fn get_sender(&self) -> User {
+        self.sender.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository(name: &str, owner: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": 1296269,
+            "name": name,
+            "full_name": format!("{owner}/{name}"),
+            "owner": { "login": owner, "id": 1, "type": "User" },
+            "private": false,
+            "description": null,
+            "stargazers_count": 80,
+            "watchers_count": 80,
+            "forks_count": 9,
+        })
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let event: EventStar = serde_json::from_value(serde_json::json!({
+            "action": "created",
+            "starred_at": "2024-03-05T10:20:30Z",
+            "repository": repository("hello-world", "octocat"),
+            "sender": { "login": "monalisa", "id": 2, "type": "User" },
+        })).unwrap();
+
+        assert!(event.is_starred());
+        assert_eq!(event.get_repository().get_stargazers(), 80);
+        assert_eq!(event.get_sender().get_name(), "monalisa");
+
+        let event: EventWatch = serde_json::from_value(serde_json::json!({
+            "action": "started",
+            "repository": repository("hello-world", "octocat"),
+            "sender": { "login": "monalisa", "id": 2, "type": "User" },
+        })).unwrap();
+
+        assert_eq!(event.get_repository().get_full_name(), "octocat/hello-world");
+
+        let event: EventFork = serde_json::from_value(serde_json::json!({
+            "forkee": repository("hello-world", "monalisa"),
+            "repository": repository("hello-world", "octocat"),
+            "sender": { "login": "monalisa", "id": 2, "type": "User" },
+        })).unwrap();
+
+        assert_eq!(event.get_fork().get_full_name(), "monalisa/hello-world");
+        assert_eq!(event.get_repository().get_forks(), 9);
+    }
+}
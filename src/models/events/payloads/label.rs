@@ -0,0 +1,179 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::models::common::{
+
+    milestone::{Milestone},
+    label::{Label},
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventChange {
+    pub(crate) from: String,
+}
+
+impl EventChange {
+    pub fn get_from(&self) -> String {
+        self.from.clone()
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventChanges {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<EventChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<EventChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) color: Option<EventChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<EventChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) due_on: Option<EventChange>,
+}
+
+impl EventChanges {
+    pub fn get_name(&self) -> Option<String> {
+        self.name.as_ref()
+            .map(EventChange::get_from)
+    }
+
+    pub fn get_title(&self) -> Option<String> {
+        self.title.as_ref()
+            .map(EventChange::get_from)
+    }
+
+    pub fn get_color(&self) -> Option<String> {
+        self.color.as_ref()
+            .map(EventChange::get_from)
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.as_ref()
+            .map(EventChange::get_from)
+    }
+
+    pub fn get_due(&self) -> Option<String> {
+        self.due_on.as_ref()
+            .map(EventChange::get_from)
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum EventLabel {
+    #[serde(rename = "created")]
+    Created { label: Label },
+    #[serde(rename = "edited")]
+    Edited { 
+        label: Label, 
+        #[serde(default)]
+        changes: EventChanges,
+    },
+    #[serde(rename = "deleted")]
+    Deleted { label: Label },
+}
+
+impl EventLabel {
+    pub fn get_label(&self) -> Label {
+        match self {
+            EventLabel::Created { label, .. } |
+            EventLabel::Edited { label, .. } |
+            EventLabel::Deleted { label, .. } => label.clone(),
+        }
+    }
+
+    pub fn get_changes(&self) -> Option<EventChanges> {
+        match self {
+            EventLabel::Edited { changes, .. } => Some(changes.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum EventMilestone {
+    #[serde(rename = "created")]
+    Created { milestone: Milestone },
+    #[serde(rename = "opened")]
+    Opened { milestone: Milestone },
+    #[serde(rename = "closed")]
+    Closed { milestone: Milestone },
+    #[serde(rename = "edited")]
+    Edited { 
+        milestone: Milestone, 
+        #[serde(default)]
+        changes: EventChanges,
+    },
+    #[serde(rename = "deleted")]
+    Deleted { milestone: Milestone },
+}
+
+impl EventMilestone {
+    pub fn get_milestone(&self) -> Milestone {
+        match self {
+            EventMilestone::Created { milestone, .. } |
+            EventMilestone::Opened { milestone, .. } |
+            EventMilestone::Closed { milestone, .. } |
+            EventMilestone::Edited { milestone, .. } |
+            EventMilestone::Deleted { milestone, .. } => milestone.clone(),
+        }
+    }
+
+    pub fn get_changes(&self) -> Option<EventChanges> {
+        match self {
+            EventMilestone::Edited { changes, .. } => Some(changes.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let event: EventLabel = serde_json::from_value(serde_json::json!({
+            "action": "edited",
+            "label": {
+                "id": 208045946,
+                "name": "bug",
+                "color": "f29513",
+                "description": "Something isn't working",
+                "default": true,
+            },
+            "changes": {
+                "name": { "from": "defect" },
+            },
+        })).unwrap();
+
+        assert_eq!(event.get_label().get_name(), "bug");
+        assert_eq!(event.get_changes().and_then(|changes| changes.get_name()).as_deref(), Some("defect"));
+
+        let event: EventMilestone = serde_json::from_value(serde_json::json!({
+            "action": "closed",
+            "milestone": {
+                "number": 3,
+                "title": "v1.0",
+                "state": "closed",
+                "open_issues": 0,
+                "closed_issues": 8,
+                "due_on": null,
+                "created_at": "2024-03-01T00:00:00Z",
+                "closed_at": "2024-04-01T00:00:00Z",
+            },
+        })).unwrap();
+
+        assert_eq!(event.get_milestone().get_number(), 3);
+        assert!(event.get_changes().is_none());
+    }
+}
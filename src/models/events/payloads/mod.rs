@@ -2,4 +2,36 @@ pub mod issue_comment;
 pub use issue_comment::{EventIssueComment};
 
 pub mod issue;
-pub use issue::{EventIssue};
\ No newline at end of file
+pub use issue::{EventIssue};
+
+pub mod label;
+pub use label::{
+
+    EventMilestone,
+    EventChanges,
+    EventChange,
+    EventLabel,
+};
+
+pub mod pull_request;
+pub use pull_request::{EventPullRequest};
+
+pub mod community;
+pub use community::{
+
+    EventRepository,
+    EventWatch,
+    EventStar,
+    EventFork,
+};
+
+pub mod push;
+pub use push::{
+
+    EventPushCommit,
+    EventPushAuthor,
+    EventCreate,
+    EventDelete,
+    EventPush,
+    RefKind,
+};
\ No newline at end of file
@@ -0,0 +1,159 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    models::common::{
+
+        pull::{PullRequest},
+        label::{Label},
+    },
+
+    repository::sha::{Sha},
+
+    Number,
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum EventPullRequest {
+    #[serde(rename = "opened")]
+    Opened { pull_request: PullRequest },
+    #[serde(rename = "reopened")]
+    Reopened { pull_request: PullRequest },
+    #[serde(rename = "edited")]
+    Edited { pull_request: PullRequest },
+    #[serde(rename = "synchronize")]
+    Synchronize { 
+        pull_request: PullRequest, 
+        before: Sha<'static>, 
+        after: Sha<'static>,
+    },
+    #[serde(rename = "labeled")]
+    Labeled { pull_request: PullRequest, label: Label },
+    #[serde(rename = "unlabeled")]
+    Unlabeled { pull_request: PullRequest, label: Label },
+    #[serde(rename = "ready_for_review")]
+    ReadyForReview { pull_request: PullRequest },
+    #[serde(rename = "converted_to_draft")]
+    ConvertedToDraft { pull_request: PullRequest },
+    #[serde(rename = "closed")]
+    Closed { pull_request: PullRequest },
+    #[serde(other)]
+    Other,
+}
+
+impl EventPullRequest {
+    pub fn get_pull_request(&self) -> Option<PullRequest> {
+        match self {
+            EventPullRequest::Opened { pull_request, .. } |
+            EventPullRequest::Reopened { pull_request, .. } |
+            EventPullRequest::Edited { pull_request, .. } |
+            EventPullRequest::Synchronize { pull_request, .. } |
+            EventPullRequest::Labeled { pull_request, .. } |
+            EventPullRequest::Unlabeled { pull_request, .. } |
+            EventPullRequest::ReadyForReview { pull_request, .. } |
+            EventPullRequest::ConvertedToDraft { pull_request, .. } |
+            EventPullRequest::Closed { pull_request, .. } => Some(pull_request.clone()),
+            EventPullRequest::Other => None,
+        }
+    }
+
+    pub fn get_number(&self) -> Option<Number> {
+        self.get_pull_request()
+            .map(|pull_request| pull_request.get_number())
+    }
+
+    pub fn get_label(&self) -> Option<Label> {
+        match self {
+            EventPullRequest::Labeled { label, .. } |
+            EventPullRequest::Unlabeled { label, .. } => Some(label.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_before(&self) -> Option<Sha<'static>> {
+        match self {
+            EventPullRequest::Synchronize { before, .. } => Some(before.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_after(&self) -> Option<Sha<'static>> {
+        match self {
+            EventPullRequest::Synchronize { after, .. } => Some(after.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_merged(&self) -> bool {
+        match self {
+            EventPullRequest::Closed { pull_request } => pull_request.is_merged(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pull_request() -> serde_json::Value {
+        serde_json::json!({
+            "number": 42,
+            "title": "Add feature",
+            "body": null,
+            "state": "open",
+            "draft": false,
+            "merged": false,
+            "merged_at": null,
+            "user": { "login": "octocat", "id": 1, "type": "User" },
+            "labels": [],
+            "head": { "ref": "feature", "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e" },
+            "base": { "ref": "main", "sha": "9049f1265b7d61be4a8904a9a27120d2064dab3b" },
+        })
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let event: EventPullRequest = serde_json::from_value(serde_json::json!({
+            "action": "synchronize",
+            "before": "9049f1265b7d61be4a8904a9a27120d2064dab3b",
+            "after": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            "pull_request": pull_request(),
+        })).unwrap();
+
+        assert_eq!(event.get_number(), Some(42));
+        assert_eq!(event.get_after().map(|sha| sha.to_string()).as_deref(), Some("6dcb09b5b57875f334f61aebed695e2e4193db5e"));
+
+        let mut merged = pull_request();
+        merged["state"] = serde_json::json!("closed");
+        merged["merged"] = serde_json::json!(true);
+
+        let event: EventPullRequest = serde_json::from_value(serde_json::json!({
+            "action": "closed",
+            "pull_request": merged,
+        })).unwrap();
+
+        assert!(event.is_merged());
+
+        let event: EventPullRequest = serde_json::from_value(serde_json::json!({
+            "action": "labeled",
+            "label": { "id": 1, "name": "ready", "color": "00ff00" },
+            "pull_request": pull_request(),
+        })).unwrap();
+
+        assert_eq!(event.get_label().map(|label| label.get_name()).as_deref(), Some("ready"));
+
+        let event: EventPullRequest = serde_json::from_value(serde_json::json!({
+            "action": "review_requested",
+            "pull_request": pull_request(),
+        })).unwrap();
+
+        assert!(event.get_pull_request().is_none());
+    }
+}
@@ -0,0 +1,309 @@
+use serde::{
+    
+    Deserialize,
+    Serialize, 
+};
+
+use crate::{
+
+    repository::sha::{Sha},
+    common::{Date},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+
+    repository::{
+
+        reference::{
+
+            ReferenceError,
+            HandleReference,
+        },
+
+        commit::{HandleCommit},
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    GitHubResult,
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventPushAuthor {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) email: Option<String>,
+    #[serde(default)]
+    pub(crate) username: Option<String>,
+}
+
+impl EventPushAuthor {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_email(&self) -> Option<String> {
+        self.email.clone()
+    }
+
+    pub fn get_username(&self) -> Option<String> {
+        self.username.clone()
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventPushCommit {
+    #[serde(rename = "id")]
+    pub(crate) sha: Sha<'static>,
+    pub(crate) message: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) timestamp: Date,
+    pub(crate) author: EventPushAuthor,
+    #[serde(default)]
+    pub(crate) distinct: bool,
+    #[serde(default)]
+    pub(crate) added: Vec<String>,
+    #[serde(default)]
+    pub(crate) removed: Vec<String>,
+    #[serde(default)]
+    pub(crate) modified: Vec<String>,
+}
+
+impl EventPushCommit {
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.sha.clone()
+    }
+
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn get_timestamp(&self) -> Date {
+        self.timestamp.clone()
+    }
+
+    pub fn get_author(&self) -> EventPushAuthor {
+        self.author.clone()
+    }
+
+    pub fn get_added(&self) -> Vec<String> {
+        self.added.clone()
+    }
+
+    pub fn get_removed(&self) -> Vec<String> {
+        self.removed.clone()
+    }
+
+    pub fn get_modified(&self) -> Vec<String> {
+        self.modified.clone()
+    }
+
+    pub fn is_distinct(&self) -> bool {
+        self.distinct
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventPush {
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    pub(crate) before: Sha<'static>,
+    pub(crate) after: Sha<'static>,
+    #[serde(default)]
+    pub(crate) base_ref: Option<String>,
+    #[serde(default)]
+    pub(crate) created: bool,
+    #[serde(default)]
+    pub(crate) deleted: bool,
+    #[serde(default)]
+    pub(crate) forced: bool,
+    #[serde(default)]
+    pub(crate) commits: Vec<EventPushCommit>,
+    #[serde(default)]
+    pub(crate) head_commit: Option<EventPushCommit>,
+    pub(crate) pusher: EventPushAuthor,
+}
+
+impl EventPush {
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_before(&self) -> Sha<'static> {
+        self.before.clone()
+    }
+
+    pub fn get_after(&self) -> Sha<'static> {
+        self.after.clone()
+    }
+
+    pub fn get_base_reference(&self) -> Option<String> {
+        self.base_ref.clone()
+    }
+
+    pub fn get_commits(&self) -> &[EventPushCommit] {
+        self.commits.as_slice()
+    }
+
+    pub fn get_head_commit(&self) -> Option<EventPushCommit> {
+        self.head_commit.clone()
+    }
+
+    pub fn get_pusher(&self) -> EventPushAuthor {
+        self.pusher.clone()
+    }
+
+    pub fn get_kind(&self) -> Option<RefKind> {
+        RefKind::from_reference(self.reference.as_str())
+    }
+
+    pub fn is_created(&self) -> bool {
+        self.created
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    pub fn is_forced(&self) -> bool {
+        self.forced
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_get_handle(&self, repository: &HandleRepository) -> GitHubResult<HandleReference, ReferenceError> {
+        HandleReference::try_parse(repository, self.reference.as_str())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_get_commit(&self, repository: &HandleRepository) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+        if self.deleted { Ok(None) } else {
+            Ok(Some(repository.try_get_commit(self.after.clone())?))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum RefKind {
+    #[serde(rename = "branch")]
+    Branch,
+    #[serde(rename = "tag")]
+    Tag,
+}
+
+impl RefKind {
+    pub fn from_reference(reference: impl AsRef<str>) -> Option<RefKind> {
+        let reference = reference.as_ref();
+
+        if reference.starts_with("refs/heads/") { Some(RefKind::Branch) }
+            else if reference.starts_with("refs/tags/") { Some(RefKind::Tag) }
+            else { None }
+    }
+
+    pub fn get_qualified(&self, name: impl AsRef<str>) -> String {
+        match self {
+            RefKind::Branch => format!("refs/heads/{name}", name = name.as_ref()),
+            RefKind::Tag => format!("refs/tags/{name}", name = name.as_ref()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventCreate {
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    #[serde(rename = "ref_type")]
+    pub(crate) kind: RefKind,
+    #[serde(default)]
+    pub(crate) master_branch: Option<String>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+}
+
+impl EventCreate {
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_kind(&self) -> RefKind {
+        self.kind
+    }
+
+    pub fn get_default_branch(&self) -> Option<String> {
+        self.master_branch.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_get_handle(&self, repository: &HandleRepository) -> GitHubResult<HandleReference, ReferenceError> {
+        HandleReference::try_parse(repository, self.kind.get_qualified(self.reference.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct EventDelete {
+    #[serde(rename = "ref")]
+    pub(crate) reference: String,
+    #[serde(rename = "ref_type")]
+    pub(crate) kind: RefKind,
+}
+
+impl EventDelete {
+    pub fn get_reference(&self) -> String {
+        self.reference.clone()
+    }
+
+    pub fn get_kind(&self) -> RefKind {
+        self.kind
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_get_handle(&self, repository: &HandleRepository) -> GitHubResult<HandleReference, ReferenceError> {
+        HandleReference::try_parse(repository, self.kind.get_qualified(self.reference.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let push: EventPush = serde_json::from_value(serde_json::json!({
+            "ref": "refs/heads/main",
+            "before": "6113728f27ae82c7b1a177c8d03f9e96e0adf246",
+            "after": "0000000000000000000000000000000000000000",
+            "created": false,
+            "deleted": true,
+            "forced": true,
+            "base_ref": null,
+            "commits": [],
+            "head_commit": null,
+            "pusher": { "name": "octocat", "email": "octocat@github.com" },
+        })).unwrap();
+
+        assert_eq!(push.get_kind(), Some(RefKind::Branch));
+        assert!(push.is_deleted() && push.is_forced());
+
+        let create: EventCreate = serde_json::from_value(serde_json::json!({
+            "ref": "v1.0.0",
+            "ref_type": "tag",
+            "master_branch": "main",
+            "description": null,
+            "pusher_type": "user",
+        })).unwrap();
+
+        assert_eq!(create.get_kind(), RefKind::Tag);
+        assert_eq!(create.get_kind().get_qualified(create.get_reference()), "refs/tags/v1.0.0");
+    }
+}
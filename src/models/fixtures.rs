@@ -0,0 +1,51 @@
+use std::fmt::{Debug as FmtDebug};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Serialize,
+};
+
+use serde_json::{Value};
+
+pub(crate) fn round_trip<T>(raw: &str) -> T
+where T: DeserializeOwned + Serialize + FmtDebug {
+    let ref fixture: Value = serde_json::from_str(raw)
+        .unwrap();
+
+    let model: T = serde_json::from_value(fixture.clone())
+        .unwrap();
+
+    let ref serialized = serde_json::to_value(&(model))
+        .unwrap();
+
+    assert_subset(serialized, fixture, "$");
+
+    model
+}
+
+fn assert_subset(serialized: &Value, fixture: &Value, path: &str) {
+    match (serialized, fixture) {
+        (Value::Object(serialized), Value::Object(fixture)) => {
+            for (key, value) in serialized.iter() {
+                let ref path = format!("{path}.{key}");
+
+                match fixture.get(key) {
+                    Some(other) => assert_subset(value, other, path),
+                    None => assert!(value.is_null(), "unexpected field: {path}"),
+                }
+            }
+        },
+        (Value::Array(serialized), Value::Array(fixture)) => {
+            assert_eq!(serialized.len(), fixture.len(), "length mismatch: {path}");
+
+            for (index, (value, other)) in serialized.iter().zip(fixture.iter()).enumerate() {
+                assert_subset(value, other, format!("{path}[{index}]").as_str());
+            }
+        },
+        (serialized, fixture) => {
+            assert_eq!(serialized, fixture, "value mismatch: {path}");
+        },
+    }
+}
@@ -1,2 +1,5 @@
 pub mod common;
-pub mod events;
\ No newline at end of file
+pub mod events;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
\ No newline at end of file
@@ -0,0 +1,69 @@
+use thiserror::{Error};
+
+use crate::{
+
+    repository::{HandleRepository},
+    client::{ClientError},
+
+    models::common::actions::{
+
+        WorkflowPermissions,
+        TokenPermission,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+#[derive(Error, Debug)]
+pub enum ActionsError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleActions {
+    pub(crate) repository: HandleRepository,
+}
+
+impl HandleActions {
+    pub(crate) fn from(repository: &HandleRepository) -> HandleActions {
+        HandleActions { repository: repository.clone() }
+    }
+
+    pub fn try_get_workflow_permissions(&self) -> GitHubResult<WorkflowPermissions, ActionsError> {
+        let HandleActions { repository } = { self };
+
+        Ok(repository.get_client()
+            .get(format!("repos/{repository}/actions/permissions/workflow"))?
+            .send()?.json()?)
+    }
+
+    pub fn try_set_workflow_permissions(&self, ref permissions: WorkflowPermissions) -> GitHubResult<&HandleActions, ActionsError> {
+        let HandleActions { repository } = { self };
+
+        let _ = {
+
+            repository.get_client()
+                .put(format!("repos/{repository}/actions/permissions/workflow"))?
+                .json(permissions)
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_set_default_token_permission(&self, permission: TokenPermission) -> GitHubResult<&HandleActions, ActionsError> {
+        let permissions = self.try_get_workflow_permissions()?
+            .with_default(permission);
+
+        self.try_set_workflow_permissions(permissions)
+    }
+
+    pub fn try_set_allow_approve(&self, approve: bool) -> GitHubResult<&HandleActions, ActionsError> {
+        let permissions = self.try_get_workflow_permissions()?
+            .with_approve(approve);
+
+        self.try_set_workflow_permissions(permissions)
+    }
+}
@@ -0,0 +1,160 @@
+use serde::{Serialize};
+
+use crate::{
+
+    repository::{HandleRepository},
+    client::{ClientError},
+
+    models::common::activity::{
+
+        ActivityType,
+        Activity,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize)]
+pub enum ActivityPeriod {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "quarter")]
+    Quarter,
+    #[serde(rename = "year")]
+    Year,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ActivityFilter {
+    reference: Option<String>,
+    actor: Option<String>,
+    period: Option<ActivityPeriod>,
+    kind: Option<ActivityType>,
+    ascending: bool,
+}
+
+impl ActivityFilter {
+    pub fn new() -> ActivityFilter {
+        Default::default()
+    }
+
+    pub fn with_reference(mut self, reference: impl AsRef<str>) -> ActivityFilter {
+        self.reference = Some(reference.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_actor(mut self, actor: impl AsRef<str>) -> ActivityFilter {
+        self.actor = Some(actor.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_period(mut self, period: ActivityPeriod) -> ActivityFilter {
+        self.period = Some(period);
+        self
+    }
+
+    pub fn with_activity_type(mut self, kind: ActivityType) -> ActivityFilter {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn with_ascending(mut self, ascending: bool) -> ActivityFilter {
+        self.ascending = ascending;
+        self
+    }
+
+    pub(crate) fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(ref reference) = self.reference {
+            query.push(("ref", reference.clone()));
+        }
+
+        if let Some(ref actor) = self.actor {
+            query.push(("actor", actor.clone()));
+        }
+
+        if let Some(period) = self.period {
+            if let Ok(serde_json::Value::String(period)) = serde_json::to_value(period) {
+                query.push(("time_period", period));
+            }
+        }
+
+        if let Some(kind) = self.kind {
+            if let Ok(serde_json::Value::String(kind)) = serde_json::to_value(kind) {
+                query.push(("activity_type", kind));
+            }
+        }
+
+        query.push(("direction", match self.ascending {
+            true => "asc".to_owned(),
+            false => "desc".to_owned(),
+        }));
+
+        query
+    }
+}
+
+pub(crate) fn try_fetch_all(repository: &HandleRepository, filter: &ActivityFilter) -> GitHubResult<Vec<Activity>, ClientError> {
+    let mut collection = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+
+        let response = {
+            let mut query = filter.get_query();
+
+            query.push(("per_page", 100.to_string()));
+
+            if let Some(cursor) = cursor.take() {
+                query.push(("after", cursor));
+            }
+
+            repository.get_client()
+                .get(format!("repos/{repository}/activity"))?
+                .query(&(query)).send()?
+        };
+
+        cursor = response.get_links()
+            .get_next_query("after");
+
+        let activities: Vec<Activity> = response.json()?;
+
+        collection.extend(activities);
+
+        if cursor.is_none() {
+            break
+        }
+    }
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_get_query() {
+        let query = ActivityFilter::new()
+            .with_reference("refs/heads/main")
+            .with_period(ActivityPeriod::Week)
+            .with_activity_type(ActivityType::BranchDeletion)
+            .get_query();
+
+        assert_eq!(query, vec![
+            ("ref", "refs/heads/main".to_owned()),
+            ("time_period", "week".to_owned()),
+            ("activity_type", "branch_deletion".to_owned()),
+            ("direction", "desc".to_owned()),
+        ]);
+    }
+}
@@ -0,0 +1,302 @@
+use std::{
+
+    path::{Path},
+};
+
+use crate::{
+
+    repository::{
+
+        tree::{
+
+            TreeEntryMode,
+            TreeEntry,
+        },
+
+        blob::{
+
+            BlobError,
+            Blob,
+        },
+
+        run::{
+
+            WorkflowRunFilter,
+            HandleWorkflowRun,
+        },
+
+        commit::{HandleCommit},
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    models::common::run::{
+
+        WorkflowRunConclusion,
+        WorkflowRunStatus,
+    },
+
+    GitHubResult,
+};
+
+const MARKER_START: &str = "<!-- octo:badges:start -->";
+const MARKER_END: &str = "<!-- octo:badges:end -->";
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq)]
+pub struct Badge {
+    label: String,
+    message: String,
+    color: String,
+    link: Option<String>,
+}
+
+impl Badge {
+    pub fn new(label: impl AsRef<str>, message: impl AsRef<str>, color: impl AsRef<str>) -> Badge {
+        Badge {
+            label: label.as_ref().to_owned(),
+            message: message.as_ref().to_owned(),
+            color: color.as_ref().to_owned(),
+            link: None,
+        }
+    }
+
+    pub fn build(conclusion: Option<WorkflowRunConclusion>) -> Badge {
+        let (message, color) = match conclusion {
+            Some(WorkflowRunConclusion::Success) => ("passing", "brightgreen"),
+            Some(WorkflowRunConclusion::Failure) => ("failing", "red"),
+            Some(WorkflowRunConclusion::TimedOut) => ("timed out", "red"),
+            Some(WorkflowRunConclusion::StartupFailure) => ("failing", "red"),
+            Some(WorkflowRunConclusion::Cancelled) => ("cancelled", "lightgrey"),
+            Some(_) => ("unknown", "lightgrey"),
+            None => ("pending", "yellow"),
+        };
+
+        Badge::new("build", message, color)
+    }
+
+    pub fn coverage(percentage: f64) -> Badge {
+        let color = match percentage {
+            value if value >= 90.0 => "brightgreen",
+            value if value >= 75.0 => "green",
+            value if value >= 60.0 => "yellow",
+            value if value >= 40.0 => "orange",
+            _ => "red",
+        };
+
+        Badge::new("coverage", format!("{percentage:.1}%"), color)
+    }
+
+    pub fn release(version: Option<&str>) -> Badge {
+        match version {
+            Some(version) => Badge::new("release", version, "blue"),
+            None => Badge::new("release", "none", "lightgrey"),
+        }
+    }
+
+    pub fn with_link(mut self, link: impl AsRef<str>) -> Badge {
+        self.link = Some(link.as_ref().to_owned());
+        self
+    }
+
+    pub fn get_label(&self) -> String {
+        self.label.clone()
+    }
+
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn get_color(&self) -> String {
+        self.color.clone()
+    }
+
+    pub fn get_link(&self) -> Option<String> {
+        self.link.clone()
+    }
+
+    pub fn get_image_url(&self) -> String {
+        let escape = |value: &str| {
+            value.replace('-', "--")
+                .replace('_', "__")
+                .replace(' ', "_")
+                .replace('%', "%25")
+                .replace('/', "%2F")
+        };
+
+        format!("https://img.shields.io/badge/{label}-{message}-{color}", 
+            label = escape(&self.label), 
+            message = escape(&self.message), 
+            color = escape(&self.color))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let image = format!("![{label}: {message}]({url})", 
+            label = self.label, 
+            message = self.message, 
+            url = self.get_image_url());
+
+        match self.link {
+            Some(ref link) => format!("[{image}]({link})"),
+            None => image,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StatusSummary {
+    badges: Vec<Badge>,
+}
+
+impl StatusSummary {
+    pub fn new() -> StatusSummary {
+        Default::default()
+    }
+
+    pub fn with_badge(mut self, badge: Badge) -> StatusSummary {
+        self.badges.push(badge);
+        self
+    }
+
+    pub fn get_badges(&self) -> Vec<Badge> {
+        self.badges.clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.badges.is_empty()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let badges: Vec<String> = self.badges.iter()
+            .map(Badge::to_markdown)
+            .collect();
+
+        format!("{MARKER_START}\n{badges}\n{MARKER_END}", badges = badges.join(" "))
+    }
+
+    pub fn apply(&self, document: impl AsRef<str>) -> Option<String> {
+        let document = document.as_ref();
+        let block = self.to_markdown();
+
+        let Some(start) = document.find(MARKER_START) else {
+            return match document.is_empty() {
+                true => Some(format!("{block}\n")),
+                false => Some(format!("{block}\n\n{document}")),
+            }
+        };
+
+        let end = document[start..].find(MARKER_END)?;
+        let end = start + end + MARKER_END.len();
+
+        Some(format!("{before}{block}{after}", 
+            before = &document[..start], 
+            after = &document[end..]))
+    }
+}
+
+pub(crate) fn try_get_build_badge(repository: &HandleRepository, branch: impl AsRef<str>) -> GitHubResult<Badge, HandleRepositoryError> {
+    let filter = WorkflowRunFilter::new()
+        .with_branch(branch)
+        .with_status(WorkflowRunStatus::Completed);
+
+    let conclusion = HandleWorkflowRun::try_fetch_latest(repository, &(filter))?
+        .and_then(|run| run.get_conclusion());
+
+    Ok(Badge::build(conclusion))
+}
+
+pub(crate) fn try_commit_summary(repository: &HandleRepository, summary: &StatusSummary, branch: impl AsRef<str>, path: impl AsRef<Path>, message: impl AsRef<str>) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+    let path = path.as_ref();
+
+    let reference = repository.try_get_branch(branch)?;
+    let parent = reference.try_get_commit()?;
+
+    let current = match Blob::try_fetch_for_path(repository, parent.get_sha().to_string(), path) {
+        Err(HandleRepositoryError::Blob(BlobError::Nothing { .. })) => String::new(),
+        Err(error) => return Err(error),
+        Ok(blob) => blob.as_text_lossy()
+            .into_owned(),
+    };
+
+    let Some(updated) = summary.apply(&current) else {
+        return Err(HandleRepositoryError::UnterminatedBadges { 
+            path: path.to_owned() 
+        })
+    };
+
+    if updated == current {
+        return Ok(None)
+    }
+
+    let blob = repository.try_create_text_blob(updated)?;
+    let entry = TreeEntry::blob(blob)
+        .with_mode(TreeEntryMode::file())
+        .with_path(path);
+
+    let tree = repository.try_create_tree_with_base(parent.clone(), [entry])?;
+    let commit = repository.try_create_commit([parent], tree, message)?;
+
+    reference.try_fast_forward(commit.clone())?;
+
+    Ok(Some(commit))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let summary = StatusSummary::new()
+            .with_badge(Badge::build(Some(WorkflowRunConclusion::Success)))
+            .with_badge(Badge::coverage(87.3));
+
+        let document = "# Title\n\n<!-- octo:badges:start -->\nstale\n<!-- octo:badges:end -->\n\nBody\n";
+        let updated = summary.apply(document).unwrap();
+
+        assert!(updated.starts_with("# Title\n\n<!-- octo:badges:start -->\n"));
+        assert!(updated.contains("https://img.shields.io/badge/build-passing-brightgreen"));
+        assert!(updated.contains("https://img.shields.io/badge/coverage-87.3%25-green"));
+        assert!(updated.ends_with("<!-- octo:badges:end -->\n\nBody\n"));
+        assert_eq!(summary.apply(&updated).unwrap(), updated);
+        assert!(summary.apply("<!-- octo:badges:start -->").is_none());
+    }
+
+    #[test]
+    fn test_build_badge() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "repos/dev-bio/octo/actions/runs?branch=main&status=completed&per_page=1&page=1", serde_json::json!({
+                "total_count": 42,
+                "workflow_runs": [{
+                    "id": 7,
+                    "workflow_id": 3,
+                    "head_sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "event": "push",
+                    "status": "completed",
+                    "conclusion": "failure",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:05:00Z",
+                }],
+            }));
+
+        let repository = fixtures::repository(&(transport));
+
+        let badge = try_get_build_badge(&(repository), "main").unwrap();
+
+        assert_eq!(badge, Badge::build(Some(WorkflowRunConclusion::Failure)));
+        assert_eq!(transport.get_requests().len(), 1);
+    }
+}
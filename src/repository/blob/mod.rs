@@ -9,6 +9,16 @@ use serde::{
     Serialize,
 };
 
+use std::{
+
+    borrow::{Cow},
+    path::{
+
+        PathBuf,
+        Path,
+    },
+};
+
 use thiserror::{Error};
 
 use crate::{
@@ -23,8 +33,10 @@ use crate::{
 
     repository::{
         
+        tree::{TreeEntry},
         sha::{Sha},
 
+        HandleRepositoryError,
         HandleRepository,
     },
 };
@@ -33,6 +45,8 @@ use crate::{
 pub enum BlobError {
     #[error("Client error!")]
     Client(#[from] ClientError),
+    #[error("Blob not found: '{path:?}'")]
+    Nothing { path: PathBuf },
 }
 
 #[derive(Clone, Debug)]
@@ -134,6 +148,63 @@ impl Blob {
         Ok(Blob::Binary { content: binary.to_owned(), sha })
     }
 
+    pub fn try_fetch_for_path(repository: &HandleRepository, reference: impl AsRef<str>, path: impl AsRef<Path>) -> GitHubResult<Blob, HandleRepositoryError> {
+        let path = path.as_ref();
+
+        let tree = {
+
+            repository.try_resolve_commit(reference)?
+                .try_get_tree(true)?
+        };
+
+        match tree.get_entry(path) {
+            Some(TreeEntry::Blob { sha, .. }) => Ok(Blob::try_fetch(repository, sha.clone())?),
+            _ => Err(HandleRepositoryError::Blob(BlobError::Nothing { 
+                path: path.to_owned() 
+            })),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Blob::Binary { content, .. } => content.as_slice(),
+            Blob::Text { content, .. } => content.as_bytes(),
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Blob::Binary { content, .. } => std::str::from_utf8(content).ok(),
+            Blob::Text { content, .. } => Some(content.as_str()),
+        }
+    }
+
+    pub fn as_text_lossy(&self) -> Cow<'_, str> {
+        match self {
+            Blob::Binary { content, .. } => String::from_utf8_lossy(content),
+            Blob::Text { content, .. } => Cow::Borrowed(content.as_str()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_bytes()
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_binary(&self) -> bool {
+        match self {
+            Blob::Binary { content, .. } => {
+                let sample = &content[..content.len().min(8000)];
+                sample.contains(&0) || std::str::from_utf8(content).is_err()
+            },
+            Blob::Text { .. } => false,
+        }
+    }
+
     pub fn get_sha(&self) -> Sha<'_> {
         match self {
             Blob::Binary { sha, .. } => sha.clone(),
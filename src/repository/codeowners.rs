@@ -0,0 +1,279 @@
+use std::{
+
+    collections::{BTreeMap},
+    path::{PathBuf, Path},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+
+use glob::{
+
+    MatchOptions,
+    Pattern,
+};
+
+use crate::{
+
+    repository::{
+
+        blob::{Blob},
+        tree::{TreeEntry},
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    GitHubResult,
+};
+
+const LOCATIONS: [&str; 3] = [
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+];
+
+#[derive(Error, Debug)]
+pub enum CodeOwnersError {
+    #[error("No CODEOWNERS file found at: '{reference}'")]
+    Nothing { reference: String },
+    #[error("CODEOWNERS file is not valid text!")]
+    Encoding,
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum CodeOwner {
+    Team { organization: String, slug: String },
+    User { name: String },
+    Email { email: String },
+}
+
+impl CodeOwner {
+    pub(crate) fn parse(owner: impl AsRef<str>) -> Option<CodeOwner> {
+        let owner = owner.as_ref();
+
+        match owner.strip_prefix('@') {
+            Some(name) => match name.split_once('/') {
+                Some((organization, slug)) => Some(CodeOwner::Team {
+                    organization: organization.to_owned(),
+                    slug: slug.to_owned(),
+                }),
+                None => Some(CodeOwner::User {
+                    name: name.to_owned(),
+                }),
+            },
+            None if owner.contains('@') => Some(CodeOwner::Email {
+                email: owner.to_owned(),
+            }),
+            None => None,
+        }
+    }
+
+    pub fn is_team(&self) -> bool {
+        match self {
+            CodeOwner::Team { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_user(&self) -> bool {
+        match self {
+            CodeOwner::User { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_email(&self) -> bool {
+        match self {
+            CodeOwner::Email { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl FmtDisplay for CodeOwner {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self {
+            CodeOwner::Team { organization, slug } => write!(fmt, "@{organization}/{slug}"),
+            CodeOwner::User { name } => write!(fmt, "@{name}"),
+            CodeOwner::Email { email } => write!(fmt, "{email}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CodeOwnersRule {
+    pattern: String,
+    matchers: Vec<Pattern>,
+    owners: Vec<CodeOwner>,
+}
+
+impl CodeOwnersRule {
+    pub(crate) fn parse(pattern: impl AsRef<str>, owners: Vec<CodeOwner>) -> Option<CodeOwnersRule> {
+        let pattern = pattern.as_ref();
+
+        let directory = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+
+        let anchored = trimmed.contains('/');
+        let trimmed = trimmed.trim_start_matches('/');
+
+        if trimmed.is_empty() {
+            return None
+        }
+
+        let base = if anchored { trimmed.to_owned() } else {
+            format!("**/{trimmed}")
+        };
+
+        let mut candidates = Vec::new();
+
+        if !(directory) {
+            candidates.push(base.clone());
+        }
+
+        if directory || !(trimmed.ends_with('*')) {
+            candidates.push(format!("{base}/**"));
+        }
+
+        let matchers = candidates.iter()
+            .map(|candidate| Pattern::new(candidate).ok())
+            .collect::<Option<Vec<Pattern>>>()?;
+
+        Some(CodeOwnersRule {
+            pattern: pattern.to_owned(),
+            matchers,
+            owners,
+        })
+    }
+
+    pub fn matches(&self, path: impl AsRef<Path>) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            .. MatchOptions::new()
+        };
+
+        self.matchers.iter().any(|matcher| {
+            matcher.matches_path_with(path.as_ref(), options)
+        })
+    }
+
+    pub fn get_pattern(&self) -> &str {
+        self.pattern.as_str()
+    }
+
+    pub fn get_owners(&self) -> &[CodeOwner] {
+        self.owners.as_slice()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CodeOwners {
+    rules: Vec<CodeOwnersRule>,
+}
+
+impl CodeOwners {
+    pub fn parse(content: impl AsRef<str>) -> CodeOwners {
+        let content = content.as_ref();
+
+        let rules = content.lines().filter_map(|line| {
+            let line = match line.find(" #") {
+                Some(index) => &line[..index],
+                None => line,
+            };
+
+            let mut tokens = line.split_whitespace();
+            let pattern = tokens.next()
+                .filter(|pattern| !(pattern.starts_with('#')))?;
+
+            CodeOwnersRule::parse(pattern, tokens
+                .filter_map(CodeOwner::parse)
+                .collect())
+        }).collect();
+
+        CodeOwners { rules }
+    }
+
+    pub(crate) fn try_fetch(repository: &HandleRepository, reference: impl AsRef<str>) -> GitHubResult<CodeOwners, HandleRepositoryError> {
+        let reference = reference.as_ref();
+
+        let tree = {
+
+            repository.try_resolve_commit(reference)?
+                .try_get_tree(true)?
+        };
+
+        for location in LOCATIONS {
+            if let Some(TreeEntry::Blob { sha, .. }) = tree.get_entry(location) {
+                let blob = Blob::try_fetch(repository, sha.clone())?;
+                let content = blob.as_text().ok_or_else(|| {
+                    CodeOwnersError::Encoding
+                })?;
+
+                return Ok(CodeOwners::parse(content))
+            }
+        }
+
+        Err(HandleRepositoryError::CodeOwners({
+            CodeOwnersError::Nothing {
+                reference: reference.to_owned()
+            }
+        }))
+    }
+
+    pub fn get_rules(&self) -> &[CodeOwnersRule] {
+        self.rules.as_slice()
+    }
+
+    pub fn get_owners(&self, path: impl AsRef<Path>) -> Vec<CodeOwner> {
+        let path = path.as_ref();
+
+        self.rules.iter().rev()
+            .find(|rule| rule.matches(path))
+            .map(|rule| rule.owners.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn resolve(&self, paths: impl IntoIterator<Item = impl AsRef<Path>>) -> BTreeMap<PathBuf, Vec<CodeOwner>> {
+        paths.into_iter().map(|path| {
+            (path.as_ref().to_owned(), self.get_owners(path))
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owners() {
+        let owners = CodeOwners::parse(r#"
+            # Default owners.
+            *                   @global-owner
+            *.js                @js-owner # inline comment
+            /build/logs/        @doctocat
+            docs/*              docs@example.com
+            apps/               @octo-org/octocats
+            /scripts/           @doctocat @octocat
+            /apps/github
+        "#);
+
+        let user = |name: &str| CodeOwner::User { name: name.to_owned() };
+
+        assert_eq!(owners.get_owners("README.md"), vec![user("global-owner")]);
+        assert_eq!(owners.get_owners("src/index.js"), vec![user("js-owner")]);
+        assert_eq!(owners.get_owners("build/logs/today/out.txt"), vec![user("doctocat")]);
+        assert_eq!(owners.get_owners("docs/getting-started.md"), vec![CodeOwner::Email { email: "docs@example.com".to_owned() }]);
+        assert_eq!(owners.get_owners("docs/build-app/troubleshooting.md"), vec![user("global-owner")]);
+        assert_eq!(owners.get_owners("nested/apps/main.rs"), vec![CodeOwner::Team { organization: "octo-org".to_owned(), slug: "octocats".to_owned() }]);
+        assert_eq!(owners.get_owners("scripts/deploy.sh"), vec![user("doctocat"), user("octocat")]);
+        assert_eq!(owners.get_owners("apps/github/main.rs"), vec![]);
+    }
+}
@@ -29,8 +29,23 @@ use crate::{
 
     GitHubProperties, 
     GitHubResult, 
+    Number,
 };
 
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[derive(Deserialize)]
+pub enum CompareStatus {
+    #[serde(rename = "identical")]
+    Identical,
+    #[serde(rename = "ahead")]
+    Ahead,
+    #[serde(rename = "behind")]
+    Behind,
+    #[serde(rename = "diverged")]
+    Diverged,
+}
+
 #[derive(Debug, Clone)]
 #[derive(Deserialize)]
 #[serde(tag = "status")]
@@ -88,6 +103,9 @@ pub enum CompareError {
 #[derive(Clone, Debug)]
 pub struct Compare {
     files: Vec<CompareFile>,
+    status: CompareStatus,
+    ahead_by: Number,
+    behind_by: Number,
     base: HandleCommit,
     head: HandleCommit,
 }
@@ -97,10 +115,14 @@ impl Compare {
         #[derive(Debug)]
         #[derive(Deserialize)]
         struct Capsule {
+            #[serde(default)]
             files: Vec<CompareFile>,
+            status: CompareStatus,
+            ahead_by: Number,
+            behind_by: Number,
         }
 
-        let Capsule { files } = {
+        let Capsule { files, status, ahead_by, behind_by } = {
 
             repository.get_client()
                 .get(format!("repos/{repository}/compare/{base}...{head}"))?
@@ -111,6 +133,9 @@ impl Compare {
         Ok(Compare { 
 
             files,
+            status,
+            ahead_by,
+            behind_by,
 
             base: base.clone(),
             head: head.clone(),
@@ -121,6 +146,26 @@ impl Compare {
         self.files.as_ref()
     }
 
+    pub fn get_status(&self) -> CompareStatus {
+        self.status
+    }
+
+    pub fn get_ahead_by(&self) -> Number {
+        self.ahead_by
+    }
+
+    pub fn get_behind_by(&self) -> Number {
+        self.behind_by
+    }
+
+    pub fn is_fast_forward(&self) -> bool {
+        match self.status {
+            CompareStatus::Identical |
+            CompareStatus::Ahead => true,
+            _ => false,
+        }
+    }
+
     pub fn get_base(&self) -> HandleCommit {
         self.base.clone()
     }
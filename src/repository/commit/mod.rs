@@ -11,14 +11,30 @@ use std::{
         Result as FmtResult,
     }, 
 
-    io::{Cursor}, 
+    io::{
+
+        Result as IoResult,
+        Cursor,
+        Read,
+    }, 
 };
 
 use serde::{Deserialize};
 
+pub mod status;
+pub use status::{
+
+    CommitCheckSource,
+    CommitCheckReport,
+    CommitVerdict,
+    CommitCheck,
+    CommitState,
+};
+
 pub mod compare;
 pub use compare::{
     
+    CompareStatus,
     CompareError,
     CompareFile,
     Compare, 
@@ -26,6 +42,14 @@ pub use compare::{
 
 use thiserror::{Error};
 use zip::{ZipArchive};
+use flate2::read::{GzDecoder};
+use tar::{Archive};
+
+use sha2::{
+
+    Digest,
+    Sha256,
+};
 
 use crate::{
 
@@ -46,6 +70,8 @@ use crate::{
 
         ClientError,
         Client, ClientResponseError, 
+
+        media,
     },
 
     models::common::commit::{Commit},
@@ -66,6 +92,28 @@ pub enum CommitError {
     Nothing { commit: Sha<'static> },
 }
 
+#[derive(Clone, Debug)]
+pub struct UnverifiedCommit {
+    commit: HandleCommit,
+    reason: String,
+}
+
+impl UnverifiedCommit {
+    pub fn get_commit(&self) -> HandleCommit {
+        self.commit.clone()
+    }
+
+    pub fn get_reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+impl FmtDisplay for UnverifiedCommit {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{commit} ({reason})", commit = self.commit, reason = self.reason)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HandleCommit {
     pub(crate) repository: HandleRepository,
@@ -117,6 +165,49 @@ impl HandleCommit {
         })
     }
 
+    pub(crate) fn try_fetch_latest(repository: &HandleRepository, reference: impl AsRef<str>, path: impl AsRef<Path>) -> GitHubResult<Option<HandleCommit>, CommitError> {
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleAuthor {
+            date: Date,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleCommit {
+            author: CapsuleAuthor,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            commit: CapsuleCommit,
+            sha: Sha<'static>,
+        }
+
+        let reference = reference.as_ref();
+        let path = path.as_ref()
+            .to_string_lossy();
+
+        let ref query = [
+            ("sha", reference),
+            ("path", path.trim_start_matches('/')),
+            ("per_page", "1"),
+        ];
+
+        let commits: Vec<Capsule> = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/commits"))?
+                .query(query).send()?.json()?
+        };
+
+        Ok(commits.into_iter().next().map(|Capsule { sha, commit: CapsuleCommit { author: CapsuleAuthor { date } } }| {
+            HandleCommit { repository: repository.clone(), date, sha }
+        }))
+    }
+
     pub(crate) fn try_create(repository: &HandleRepository, parents: impl AsRef<[HandleCommit]>, tree: Tree, message: impl AsRef<str>) -> GitHubResult<HandleCommit, CommitError> {
 
         #[derive(Debug)]
@@ -167,6 +258,17 @@ impl HandleCommit {
         Ok(Compare::try_from_base_head(self.get_parent(), self.clone(), head)?)
     }
 
+    pub fn try_is_ancestor_of(&self, other: &HandleCommit) -> GitHubResult<bool, CommitError> {
+        let compare = self.try_compare(other.clone())?;
+
+        Ok(match compare.get_status() {
+            CompareStatus::Identical |
+            CompareStatus::Ahead => true,
+            CompareStatus::Behind |
+            CompareStatus::Diverged => false,
+        })
+    }
+
     pub fn try_get_parents(&self) -> GitHubResult<Vec<HandleCommit>, CommitError> {
 
         let Self { repository, .. } = { self };
@@ -228,6 +330,56 @@ impl HandleCommit {
         Ok(Tree::try_fetch(repository, sha, recursive)?)
     }
 
+    pub fn try_get_verification(&self) -> GitHubResult<Option<UnverifiedCommit>, CommitError> {
+        let Self { repository, .. } = { self };
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleVerification {
+            verified: bool,
+            reason: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            verification: CapsuleVerification,
+        }
+
+        let Capsule { verification: CapsuleVerification { verified, reason } } = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/git/commits/{self}"))?
+                .send()?
+                .json()?
+        };
+
+        if verified { Ok(None) } else {
+            Ok(Some(UnverifiedCommit {
+                commit: self.clone(),
+                reason,
+            }))
+        }
+    }
+
+    pub fn try_get_diff(&self) -> GitHubResult<String, CommitError> {
+        self.try_get_as(media::DIFF)
+    }
+
+    pub fn try_get_patch(&self) -> GitHubResult<String, CommitError> {
+        self.try_get_as(media::PATCH)
+    }
+
+    fn try_get_as(&self, media_type: &str) -> GitHubResult<String, CommitError> {
+        let Self { repository, .. } = { self };
+
+        Ok(repository.get_client()
+            .get(format!("repos/{repository}/commits/{self}"))?
+            .accept(media_type)
+            .send()?
+            .text()?)
+    }
+
     pub fn try_get_date(&self) -> GitHubResult<Date, CommitError> {
         let repository = self.get_parent();
 
@@ -272,6 +424,33 @@ impl HandleCommit {
             .extract(path.as_ref())?)
     }
 
+    pub fn try_download_tarball_with_digest(&self, path: impl AsRef<Path>) -> GitHubResult<String, HandleRepositoryError> {
+        let Self { repository, .. } = { self };
+
+        let path = path.as_ref();
+
+        let reader = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/tarball/{self}"))?
+                .send_reader()?
+        };
+
+        let mut reader = DigestReader { inner: reader, hasher: Sha256::new() };
+
+        Archive::new(GzDecoder::new(&mut reader)).unpack(path).map_err(|error| {
+            HandleRepositoryError::Tarball { path: path.to_owned(), reason: error.to_string() }
+        })?;
+
+        std::io::copy(&mut reader, &mut std::io::sink()).map_err(|error| {
+            HandleRepositoryError::Tarball { path: path.to_owned(), reason: error.to_string() }
+        })?;
+
+        Ok(reader.hasher.finalize().iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
     pub fn get_date(&self) -> Date {
         self.date.clone()
     }
@@ -279,6 +458,25 @@ impl HandleCommit {
     pub fn get_sha(&self) -> Sha {
         self.sha.clone()
     }
+
+    pub fn get_html_url(&self) -> String {
+        format!("{repository}/commit/{sha}", repository = self.repository.get_html_url(), sha = self.sha)
+    }
+}
+
+struct DigestReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Read for DigestReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
+        let count = self.inner.read(buffer)?;
+
+        self.hasher.update(&(buffer[.. count]));
+
+        Ok(count)
+    }
 }
 
 impl<'a> GitHubProperties<'a> for HandleCommit {
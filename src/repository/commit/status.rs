@@ -0,0 +1,391 @@
+use std::{
+
+    collections::{HashSet},
+    time::{Duration, Instant},
+    thread::{self},
+};
+
+use serde::{Deserialize};
+
+use crate::{
+
+    repository::{
+
+        commit::{
+
+            CommitError,
+            HandleCommit,
+        },
+    },
+
+    client::{Paginated},
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum CommitState {
+    Pending,
+    Success,
+    Failure,
+}
+
+impl CommitState {
+    pub fn is_pending(&self) -> bool {
+        *self == CommitState::Pending
+    }
+
+    pub fn is_success(&self) -> bool {
+        *self == CommitState::Success
+    }
+
+    pub fn is_failure(&self) -> bool {
+        *self == CommitState::Failure
+    }
+
+    pub(crate) fn from_status(state: &str) -> CommitState {
+        match state {
+            "success" => CommitState::Success,
+            "pending" => CommitState::Pending,
+            _ => CommitState::Failure,
+        }
+    }
+
+    pub(crate) fn from_run(status: &str, conclusion: Option<&str>) -> CommitState {
+        match (status, conclusion) {
+            ("completed", Some("success" | "neutral" | "skipped")) => CommitState::Success,
+            ("completed", _) => CommitState::Failure,
+            _ => CommitState::Pending,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum CommitCheckSource {
+    Status,
+    CheckRun,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitCheck {
+    pub(crate) context: String,
+    pub(crate) state: CommitState,
+    pub(crate) source: CommitCheckSource,
+    pub(crate) url: Option<String>,
+}
+
+impl CommitCheck {
+    pub fn get_context(&self) -> String {
+        self.context.clone()
+    }
+
+    pub fn get_state(&self) -> CommitState {
+        self.state
+    }
+
+    pub fn get_source(&self) -> CommitCheckSource {
+        self.source
+    }
+
+    pub fn get_url(&self) -> Option<String> {
+        self.url.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitVerdict {
+    pub(crate) state: CommitState,
+    pub(crate) checks: Vec<CommitCheck>,
+}
+
+impl CommitVerdict {
+    pub(crate) fn from_checks(checks: Vec<CommitCheck>) -> CommitVerdict {
+        let state = if checks.iter().any(|check| check.state.is_failure()) { CommitState::Failure }
+            else if checks.iter().any(|check| check.state.is_pending()) { CommitState::Pending }
+            else { CommitState::Success };
+
+        CommitVerdict { state, checks }
+    }
+
+    pub fn get_state(&self) -> CommitState {
+        self.state
+    }
+
+    pub fn get_checks(&self) -> &[CommitCheck] {
+        self.checks.as_slice()
+    }
+
+    pub fn get_check(&self, context: impl AsRef<str>) -> Option<&CommitCheck> {
+        self.checks.iter()
+            .find(|check| check.context == context.as_ref())
+    }
+
+    pub fn get_failing(&self) -> Vec<String> {
+        self.checks.iter()
+            .filter(|check| check.state.is_failure())
+            .map(|check| check.context.clone())
+            .collect()
+    }
+
+    pub fn get_pending(&self) -> Vec<String> {
+        self.checks.iter()
+            .filter(|check| check.state.is_pending())
+            .map(|check| check.context.clone())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitCheckReport {
+    pub(crate) state: CommitState,
+    pub(crate) checks: Vec<CommitCheck>,
+    pub(crate) missing: Vec<String>,
+    pub(crate) timed_out: bool,
+}
+
+impl CommitCheckReport {
+    pub(crate) fn from_verdict(verdict: &CommitVerdict, required: &[String], timed_out: bool) -> CommitCheckReport {
+        let checks: Vec<CommitCheck> = required.iter()
+            .filter_map(|context| verdict.get_check(context))
+            .cloned()
+            .collect();
+
+        let missing: Vec<String> = required.iter()
+            .filter(|context| verdict.get_check(context).is_none())
+            .cloned()
+            .collect();
+
+        let state = match CommitVerdict::from_checks(checks.clone()).get_state() {
+            CommitState::Success if !(missing.is_empty()) || checks.is_empty() => CommitState::Pending,
+            state => state,
+        };
+
+        CommitCheckReport { state, checks, missing, timed_out }
+    }
+
+    pub fn get_state(&self) -> CommitState {
+        self.state
+    }
+
+    pub fn get_checks(&self) -> &[CommitCheck] {
+        self.checks.as_slice()
+    }
+
+    pub fn get_missing(&self) -> Vec<String> {
+        self.missing.clone()
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !(self.state.is_pending())
+    }
+}
+
+impl HandleCommit {
+    pub fn try_wait_for_checks(&self, required: impl IntoIterator<Item = impl AsRef<str>>, timeout: Duration, interval: Duration) -> GitHubResult<CommitCheckReport, CommitError> {
+        let required: Vec<String> = required.into_iter()
+            .map(|context| context.as_ref().to_owned())
+            .collect();
+
+        let started = Instant::now();
+
+        loop {
+
+            let verdict = self.try_get_overall_state()?;
+            let required = if required.is_empty() { verdict.checks.iter().map(|check| check.context.clone()).collect() } else {
+                required.clone()
+            };
+
+            let timed_out = started.elapsed() >= timeout;
+            let report = CommitCheckReport::from_verdict(&(verdict), required.as_slice(), timed_out);
+
+            if report.is_complete() || timed_out {
+                return Ok(report)
+            }
+
+            thread::sleep(interval.min(timeout.saturating_sub(started.elapsed())));
+        }
+    }
+
+    pub fn try_get_overall_state(&self) -> GitHubResult<CommitVerdict, CommitError> {
+        let Self { repository, .. } = { self };
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleStatus {
+            context: String,
+            state: String,
+            target_url: Option<String>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRun {
+            name: String,
+            status: String,
+            conclusion: Option<String>,
+            details_url: Option<String>,
+        }
+
+        let mut checks = Vec::new();
+        let mut seen = HashSet::new();
+
+        let statuses: Vec<CapsuleStatus> = {
+            Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/commits/{self}/status"), &[])?
+        };
+
+        for CapsuleStatus { context, state, target_url } in statuses {
+            if !(seen.insert(context.clone())) {
+                continue
+            }
+
+            checks.push(CommitCheck {
+                state: CommitState::from_status(state.as_str()),
+                source: CommitCheckSource::Status,
+                url: target_url,
+                context,
+            });
+        }
+
+        let check_runs: Vec<CapsuleRun> = {
+            Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/commits/{self}/check-runs"), &[])?
+        };
+
+        for CapsuleRun { name, status, conclusion, details_url } in check_runs {
+            if !(seen.insert(name.clone())) {
+                continue
+            }
+
+            checks.push(CommitCheck {
+                context: name,
+                state: CommitState::from_run(status.as_str(), conclusion.as_deref()),
+                source: CommitCheckSource::CheckRun,
+                url: details_url,
+            });
+        }
+
+        Ok(CommitVerdict::from_checks(checks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(context: &str, state: CommitState) -> CommitCheck {
+        CommitCheck {
+            context: context.to_owned(),
+            state,
+            source: CommitCheckSource::Status,
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_state_mapping() {
+        let statuses = [
+            ("success", CommitState::Success),
+            ("pending", CommitState::Pending),
+            ("failure", CommitState::Failure),
+            ("error", CommitState::Failure),
+        ];
+
+        for (state, expected) in statuses {
+            assert_eq!(CommitState::from_status(state), expected, "status: {state}");
+        }
+
+        let runs = [
+            ("completed", Some("success"), CommitState::Success),
+            ("completed", Some("neutral"), CommitState::Success),
+            ("completed", Some("skipped"), CommitState::Success),
+            ("completed", Some("failure"), CommitState::Failure),
+            ("completed", Some("timed_out"), CommitState::Failure),
+            ("completed", None, CommitState::Failure),
+            ("in_progress", None, CommitState::Pending),
+            ("queued", None, CommitState::Pending),
+        ];
+
+        for (status, conclusion, expected) in runs {
+            assert_eq!(CommitState::from_run(status, conclusion), expected, "run: {status} {conclusion:?}");
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        use CommitState::{Success, Pending, Failure};
+
+        let cases: [(&[CommitState], CommitState); 7] = [
+            (&[], Success),
+            (&[Success, Success], Success),
+            (&[Success, Pending], Pending),
+            (&[Pending, Pending], Pending),
+            (&[Success, Failure], Failure),
+            (&[Pending, Failure], Failure),
+            (&[Success, Pending, Failure], Failure),
+        ];
+
+        for (states, expected) in cases {
+            let checks = states.iter().enumerate()
+                .map(|(index, state)| check(format!("check-{index}").as_str(), *state))
+                .collect();
+
+            assert_eq!(CommitVerdict::from_checks(checks).get_state(), expected, "states: {states:?}");
+        }
+
+        let verdict = CommitVerdict::from_checks(vec![
+            check("build", CommitState::Success),
+            check("lint", CommitState::Pending),
+            check("test", CommitState::Failure),
+            check("deploy", CommitState::from_status("error")),
+        ]);
+
+        assert_eq!(verdict.get_failing(), vec!["test".to_owned(), "deploy".to_owned()]);
+        assert_eq!(verdict.get_pending(), vec!["lint".to_owned()]);
+        assert_eq!(verdict.get_check("build").map(CommitCheck::get_state), Some(CommitState::Success));
+        assert!(verdict.get_check("missing").is_none());
+    }
+
+    #[test]
+    fn test_report() {
+        let verdict = CommitVerdict::from_checks(vec![
+            check("build", CommitState::Success),
+            check("lint", CommitState::Pending),
+            check("test", CommitState::Failure),
+            check("extra", CommitState::Failure),
+        ]);
+
+        let required = |contexts: &[&str]| -> Vec<String> {
+            contexts.iter().map(|context| context.to_string()).collect()
+        };
+
+        let cases = [
+            (required(&["build"]), false, CommitState::Success, Vec::new(), true),
+            (required(&["build", "lint"]), false, CommitState::Pending, Vec::new(), false),
+            (required(&["build", "lint"]), true, CommitState::Pending, Vec::new(), false),
+            (required(&["build", "test"]), false, CommitState::Failure, Vec::new(), true),
+            (required(&["build", "docs"]), true, CommitState::Pending, required(&["docs"]), false),
+            (required(&["test", "docs"]), false, CommitState::Failure, required(&["docs"]), true),
+        ];
+
+        for (required, timed_out, state, missing, complete) in cases {
+            let report = CommitCheckReport::from_verdict(&(verdict), required.as_slice(), timed_out);
+
+            assert_eq!(report.get_state(), state, "required: {required:?}");
+            assert_eq!(report.get_missing(), missing, "required: {required:?}");
+            assert_eq!(report.is_complete(), complete, "required: {required:?}");
+            assert_eq!(report.is_timed_out(), timed_out);
+            assert_eq!(report.get_checks().len(), required.len() - missing.len());
+        }
+
+        let report = CommitCheckReport::from_verdict(&(CommitVerdict::from_checks(Vec::new())), &[], false);
+
+        assert_eq!(report.get_state(), CommitState::Pending);
+        assert!(!(report.is_complete()));
+    }
+}
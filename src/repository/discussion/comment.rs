@@ -0,0 +1,341 @@
+use std::{
+
+    fmt::{
+        
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    }, 
+};
+
+use crate::{
+
+    repository::discussion::{
+
+        FRAGMENT_AUTHOR,
+        CapsulePageInfo,
+        HandleDiscussion,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+
+    models::common::discussion::{DiscussionComment},
+    
+    GitHubResult, 
+};
+
+use serde::{Deserialize};
+
+use thiserror::{Error};
+
+pub(crate) const FRAGMENT_COMMENT: &str = r#"
+fragment comment on DiscussionComment {
+    id
+    author { ...author }
+    body
+    isAnswer
+}
+"#;
+
+#[derive(Error, Debug)]
+pub enum DiscussionCommentError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Discussion comment not found: '{id}'")]
+    Nothing { id: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleDiscussionComment {
+    discussion: HandleDiscussion,
+    id: String,
+}
+
+impl HandleDiscussionComment {
+    pub(crate) fn try_fetch_all(discussion: &HandleDiscussion) -> GitHubResult<Vec<HandleDiscussionComment>, DiscussionCommentError> {
+        const QUERY: &str = r#"
+            query($discussion: ID!, $cursor: String) {
+                node(id: $discussion) {
+                    ... on Discussion {
+                        comments(first: 100, after: $cursor) {
+                            pageInfo { hasNextPage endCursor }
+                            nodes { ...comment }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleComments {
+            #[serde(rename = "pageInfo")]
+            page: CapsulePageInfo,
+            nodes: Vec<DiscussionComment>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleNode {
+            comments: CapsuleComments,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            node: CapsuleNode,
+        }
+
+        let ref query = [QUERY, FRAGMENT_COMMENT, FRAGMENT_AUTHOR]
+            .concat();
+
+        let mut collection = Vec::new();
+        let mut cursor = None;
+
+        loop {
+
+            let Capsule { node: CapsuleNode { comments } } = {
+
+                let ref variables = serde_json::json!({
+                    "discussion": discussion.get_id(),
+                    "cursor": cursor,
+                });
+
+                discussion.get_client()
+                    .try_query(query, variables)?
+            };
+
+            let CapsuleComments { page, nodes } = {
+                comments
+            };
+
+            collection.extend(nodes.into_iter().map(|DiscussionComment { id, .. }| {
+                HandleDiscussionComment { discussion: discussion.clone(), id }
+            }));
+
+            match page {
+                CapsulePageInfo { next: true, cursor: Some(next) } => {
+                    cursor = Some(next)
+                },
+                _ => break,
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_answer(discussion: &HandleDiscussion) -> GitHubResult<Option<HandleDiscussionComment>, DiscussionCommentError> {
+        const QUERY: &str = r#"
+            query($discussion: ID!) {
+                node(id: $discussion) {
+                    ... on Discussion {
+                        answer { ...comment }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleNode {
+            answer: Option<DiscussionComment>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            node: CapsuleNode,
+        }
+
+        let Capsule { node: CapsuleNode { answer } } = {
+
+            let ref query = [QUERY, FRAGMENT_COMMENT, FRAGMENT_AUTHOR]
+                .concat();
+
+            let ref variables = serde_json::json!({
+                "discussion": discussion.get_id(),
+            });
+
+            discussion.get_client()
+                .try_query(query, variables)?
+        };
+
+        Ok(answer.map(|DiscussionComment { id, .. }| {
+            HandleDiscussionComment { discussion: discussion.clone(), id }
+        }))
+    }
+
+    pub(crate) fn try_create(discussion: &HandleDiscussion, reply: Option<&HandleDiscussionComment>, content: impl AsRef<str>) -> GitHubResult<HandleDiscussionComment, DiscussionCommentError> {
+        const QUERY: &str = r#"
+            mutation($discussion: ID!, $reply: ID, $body: String!) {
+                addDiscussionComment(input: { discussionId: $discussion, replyToId: $reply, body: $body }) {
+                    comment { ...comment }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleCreate {
+            comment: DiscussionComment,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(rename = "addDiscussionComment")]
+            create: CapsuleCreate,
+        }
+
+        let Capsule { create: CapsuleCreate { comment: DiscussionComment { id, .. } } } = {
+
+            let ref query = [QUERY, FRAGMENT_COMMENT, FRAGMENT_AUTHOR]
+                .concat();
+
+            let ref variables = serde_json::json!({
+                "discussion": discussion.get_id(),
+                "reply": reply.map(|comment| comment.get_id()),
+                "body": content.as_ref(),
+            });
+
+            discussion.get_client()
+                .try_query(query, variables)?
+        };
+
+        Ok(HandleDiscussionComment {
+            discussion: discussion.clone(),
+            id,
+        })
+    }
+
+    pub fn try_get_content(&self) -> GitHubResult<DiscussionComment, DiscussionCommentError> {
+        const QUERY: &str = r#"
+            query($comment: ID!) {
+                node(id: $comment) { ...comment }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            node: Option<DiscussionComment>,
+        }
+
+        let result = {
+
+            let ref query = [QUERY, FRAGMENT_COMMENT, FRAGMENT_AUTHOR]
+                .concat();
+
+            let ref variables = serde_json::json!({
+                "comment": self.get_id(),
+            });
+
+            self.get_client()
+                .try_query(query, variables)
+        };
+
+        match result {
+            Ok(Capsule { node: Some(comment) }) => Ok(comment),
+            Ok(_) | Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                Err(DiscussionCommentError::Nothing { id: self.get_id() })
+            },
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn try_reply(&self, content: impl AsRef<str>) -> GitHubResult<HandleDiscussionComment, DiscussionCommentError> {
+        HandleDiscussionComment::try_create(self.get_parent(), Some(self), content)
+    }
+
+    pub fn try_mark_as_answer(&self) -> GitHubResult<(), DiscussionCommentError> {
+        const QUERY: &str = r#"
+            mutation($comment: ID!) {
+                markDiscussionCommentAsAnswer(input: { id: $comment }) {
+                    discussion { id }
+                }
+            }
+        "#;
+
+        let ref variables = serde_json::json!({
+            "comment": self.get_id(),
+        });
+
+        let _: serde_json::Value = {
+
+            self.get_client()
+                .try_query(QUERY, variables)?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_unmark_as_answer(&self) -> GitHubResult<(), DiscussionCommentError> {
+        const QUERY: &str = r#"
+            mutation($comment: ID!) {
+                unmarkDiscussionCommentAsAnswer(input: { id: $comment }) {
+                    discussion { id }
+                }
+            }
+        "#;
+
+        let ref variables = serde_json::json!({
+            "comment": self.get_id(),
+        });
+
+        let _: serde_json::Value = {
+
+            self.get_client()
+                .try_query(QUERY, variables)?
+        };
+
+        Ok(())
+    }
+
+    pub fn try_delete(&self) -> GitHubResult<(), DiscussionCommentError> {
+        const QUERY: &str = r#"
+            mutation($comment: ID!) {
+                deleteDiscussionComment(input: { id: $comment }) {
+                    comment { id }
+                }
+            }
+        "#;
+
+        let ref variables = serde_json::json!({
+            "comment": self.get_id(),
+        });
+
+        let _: serde_json::Value = {
+
+            self.get_client()
+                .try_query(QUERY, variables)?
+        };
+
+        Ok(())
+    }
+
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_client(&self) -> &Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    pub fn get_parent(&self) -> &HandleDiscussion {
+        &(self.discussion)
+    }
+}
+
+impl FmtDisplay for HandleDiscussionComment {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{id}", id = {
+            self.id.clone()
+        })
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+
+    fmt::{
+    
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    }, 
+};
+
+use crate::{
+
+    repository::{
+
+        discussion::{
+
+            comment::{
+    
+                DiscussionCommentError,
+                HandleDiscussionComment,
+            },
+        },
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+        Client,
+    },
+    
+    models::common::discussion::{
+
+        DiscussionCategory,
+        Discussion,
+    },
+
+    GitHubProperties,
+    GitHubResult, 
+    Number,
+};
+
+use serde::{Deserialize};
+
+use thiserror::{Error};
+
+pub mod comment;
+
+pub(crate) const FRAGMENT_AUTHOR: &str = r#"
+fragment author on Actor {
+    type: __typename
+    login
+    ... on User { id: databaseId }
+    ... on Bot { id: databaseId }
+    ... on Organization { id: databaseId }
+    ... on Mannequin { id: databaseId }
+}
+"#;
+
+pub(crate) const FRAGMENT_DISCUSSION: &str = r#"
+fragment discussion on Discussion {
+    id
+    number
+    author { ...author }
+    category { id name slug isAnswerable }
+    title
+    body
+    isAnswered
+}
+"#;
+
+#[derive(Debug)]
+#[derive(Deserialize)]
+pub(crate) struct CapsulePageInfo {
+    #[serde(rename = "hasNextPage")]
+    pub(crate) next: bool,
+    #[serde(rename = "endCursor")]
+    pub(crate) cursor: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum DiscussionError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Discussion comment error!")]
+    Comment(#[from] DiscussionCommentError),
+    #[error("Discussion not found: {number}")]
+    Nothing { number: Number },
+    #[error("Discussion category not found: '{category}'")]
+    Category { category: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleDiscussion {
+    pub(crate) repository: HandleRepository,
+    pub(crate) number: Number,
+    pub(crate) id: String,
+}
+
+impl HandleDiscussion {
+    pub(crate) fn try_fetch(repository: &HandleRepository, number: Number) -> GitHubResult<HandleDiscussion, DiscussionError> {
+        let Discussion { id, number, .. } = {
+            HandleDiscussion::try_fetch_content(repository, number)?
+        };
+
+        Ok(HandleDiscussion {
+            repository: repository.clone(),
+            number,
+            id,
+        })
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleDiscussion>, DiscussionError> {
+        const QUERY: &str = r#"
+            query($owner: String!, $name: String!, $cursor: String) {
+                repository(owner: $owner, name: $name) {
+                    discussions(first: 100, after: $cursor) {
+                        pageInfo { hasNextPage endCursor }
+                        nodes { ...discussion }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleDiscussions {
+            #[serde(rename = "pageInfo")]
+            page: CapsulePageInfo,
+            nodes: Vec<Discussion>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            discussions: CapsuleDiscussions,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            repository: CapsuleRepository,
+        }
+
+        let ref query = [QUERY, FRAGMENT_DISCUSSION, FRAGMENT_AUTHOR]
+            .concat();
+
+        let mut collection = Vec::new();
+        let mut cursor = None;
+
+        loop {
+
+            let Capsule { repository: CapsuleRepository { discussions } } = {
+
+                let ref variables = serde_json::json!({
+                    "owner": repository.get_parent().to_string(),
+                    "name": repository.name.as_ref(),
+                    "cursor": cursor,
+                });
+
+                repository.get_client()
+                    .try_query(query, variables)?
+            };
+
+            let CapsuleDiscussions { page, nodes } = {
+                discussions
+            };
+
+            collection.extend(nodes.into_iter().map(|Discussion { id, number, .. }| {
+                HandleDiscussion { repository: repository.clone(), number, id }
+            }));
+
+            match page {
+                CapsulePageInfo { next: true, cursor: Some(next) } => {
+                    cursor = Some(next)
+                },
+                _ => break,
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub(crate) fn try_fetch_categories(repository: &HandleRepository) -> GitHubResult<Vec<DiscussionCategory>, DiscussionError> {
+        const QUERY: &str = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    discussionCategories(first: 100) {
+                        nodes { id name slug isAnswerable }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleCategories {
+            nodes: Vec<DiscussionCategory>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            #[serde(rename = "discussionCategories")]
+            categories: CapsuleCategories,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            repository: CapsuleRepository,
+        }
+
+        let Capsule { repository: CapsuleRepository { categories: CapsuleCategories { nodes } } } = {
+
+            let ref variables = serde_json::json!({
+                "owner": repository.get_parent().to_string(),
+                "name": repository.name.as_ref(),
+            });
+
+            repository.get_client()
+                .try_query(QUERY, variables)?
+        };
+
+        Ok(nodes)
+    }
+
+    pub(crate) fn try_create(repository: &HandleRepository, category: impl AsRef<str>, title: impl AsRef<str>, body: impl AsRef<str>) -> GitHubResult<HandleDiscussion, DiscussionError> {
+        const QUERY: &str = r#"
+            mutation($repository: ID!, $category: ID!, $title: String!, $body: String!) {
+                createDiscussion(input: { repositoryId: $repository, categoryId: $category, title: $title, body: $body }) {
+                    discussion { ...discussion }
+                }
+            }
+        "#;
+
+        let category = category.as_ref();
+
+        let DiscussionCategory { id: category_id, .. } = {
+
+            HandleDiscussion::try_fetch_categories(repository)?
+                .into_iter().find(|DiscussionCategory { name, slug, .. }| {
+                    name.eq_ignore_ascii_case(category) || slug.eq_ignore_ascii_case(category)
+                }).ok_or(DiscussionError::Category {
+                    category: category.to_owned()
+                })?
+        };
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleCreate {
+            discussion: Discussion,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(rename = "createDiscussion")]
+            create: CapsuleCreate,
+        }
+
+        let Capsule { create: CapsuleCreate { discussion: Discussion { id, number, .. } } } = {
+
+            let ref query = [QUERY, FRAGMENT_DISCUSSION, FRAGMENT_AUTHOR]
+                .concat();
+
+            let ref variables = serde_json::json!({
+                "repository": repository.try_get_node_id()?,
+                "category": category_id,
+                "title": title.as_ref(),
+                "body": body.as_ref(),
+            });
+
+            repository.get_client()
+                .try_query(query, variables)?
+        };
+
+        Ok(HandleDiscussion {
+            repository: repository.clone(),
+            number,
+            id,
+        })
+    }
+
+    fn try_fetch_content(repository: &HandleRepository, number: Number) -> GitHubResult<Discussion, DiscussionError> {
+        const QUERY: &str = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    discussion(number: $number) { ...discussion }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            discussion: Option<Discussion>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            repository: CapsuleRepository,
+        }
+
+        let result = {
+
+            let ref query = [QUERY, FRAGMENT_DISCUSSION, FRAGMENT_AUTHOR]
+                .concat();
+
+            let ref variables = serde_json::json!({
+                "owner": repository.get_parent().to_string(),
+                "name": repository.name.as_ref(),
+                "number": number,
+            });
+
+            repository.get_client()
+                .try_query(query, variables)
+        };
+
+        match result {
+            Ok(Capsule { repository: CapsuleRepository { discussion: Some(discussion) } }) => Ok(discussion),
+            Ok(_) | Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                Err(DiscussionError::Nothing { number })
+            },
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn try_get_content(&self) -> GitHubResult<Discussion, DiscussionError> {
+        HandleDiscussion::try_fetch_content(self.get_parent(), self.number)
+    }
+
+    pub fn try_get_all_comments(&self) -> GitHubResult<Vec<HandleDiscussionComment>, DiscussionError> {
+        Ok(HandleDiscussionComment::try_fetch_all(self)?)
+    }
+
+    pub fn try_create_comment(&self, content: impl AsRef<str>) -> GitHubResult<HandleDiscussionComment, DiscussionError> {
+        Ok(HandleDiscussionComment::try_create(self, None, content)?)
+    }
+
+    pub fn try_get_answer(&self) -> GitHubResult<Option<HandleDiscussionComment>, DiscussionError> {
+        Ok(HandleDiscussionComment::try_fetch_answer(self)?)
+    }
+
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number
+    }
+
+    pub fn get_client(&self) -> &Client {
+        self.get_parent()
+            .get_client()
+    }
+
+    pub fn get_parent(&self) -> &HandleRepository {
+        &(self.repository)
+    }
+}
+
+impl Into<Number> for &HandleDiscussion {
+    fn into(self) -> Number {
+        self.number.clone()
+    }
+}
+
+impl Into<Number> for HandleDiscussion {
+    fn into(self) -> Number {
+        self.number.clone()
+    }
+}
+
+impl FmtDisplay for HandleDiscussion {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = {
+            self.number.clone()
+        })
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+
+    account::{
+
+        user::{HandleUser},
+
+        Account,
+    },
+
+    client::{
+
+        MockTransport,
+        Client,
+    },
+
+    repository::{HandleRepository},
+};
+
+pub(crate) fn client(transport: &MockTransport) -> Client {
+    Client::builder()
+        .with_transport(transport.clone())
+        .build()
+        .unwrap()
+}
+
+pub(crate) fn repository(transport: &MockTransport) -> HandleRepository {
+    HandleRepository {
+        owner: Account::User(HandleUser { client: client(transport), name: "dev-bio".into() }),
+        name: "octo".into(),
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+        Debug as FmtDebug,
+    },
+};
+
+use thiserror::{Error};
+
+use serde::{Serialize};
+
+use crate::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientError,
+        Client,
+    },
+
+    models::common::import::{
+
+        ImportLargeFile,
+        ImportAuthor,
+        ImportVcs,
+        ImportLfs,
+        Import,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Default)]
+#[derive(Serialize)]
+pub struct ImportSource {
+    vcs_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs: Option<ImportVcs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tfvc_project: Option<String>,
+}
+
+impl ImportSource {
+    pub fn new(url: impl AsRef<str>) -> ImportSource {
+        ImportSource {
+            vcs_url: url.as_ref().to_owned(),
+            .. Default::default()
+        }
+    }
+
+    pub fn with_vcs(mut self, vcs: ImportVcs) -> ImportSource {
+        self.vcs = Some(vcs);
+        self
+    }
+
+    pub fn with_credentials(mut self, username: impl AsRef<str>, password: impl AsRef<str>) -> ImportSource {
+        self.vcs_username = Some(username.as_ref().to_owned());
+        self.vcs_password = Some(password.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_tfvc_project(mut self, project: impl AsRef<str>) -> ImportSource {
+        self.tfvc_project = Some(project.as_ref().to_owned());
+        self
+    }
+}
+
+impl FmtDebug for ImportSource {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        fmt.debug_struct("ImportSource")
+            .field("vcs_url", &(self.vcs_url))
+            .field("vcs", &(self.vcs))
+            .field("vcs_username", &(self.vcs_username))
+            .field("vcs_password", &(self.vcs_password.as_ref().map(|_| "[REDACTED]")))
+            .field("tfvc_project", &(self.tfvc_project))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleImport {
+    pub(crate) repository: HandleRepository,
+}
+
+impl HandleImport {
+    pub(crate) fn from(repository: &HandleRepository) -> HandleImport {
+        HandleImport { repository: repository.clone() }
+    }
+
+    pub fn try_start(&self, ref source: ImportSource) -> GitHubResult<Import, ImportError> {
+        Ok(self.get_client()
+            .put(self.get_endpoint())?
+            .json(source)
+            .send()?.json()?)
+    }
+
+    pub fn try_get_progress(&self) -> GitHubResult<Import, ImportError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    pub fn try_cancel(&self) -> GitHubResult<(), ImportError> {
+        self.get_client()
+            .delete(self.get_endpoint())?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_authors(&self) -> GitHubResult<Vec<ImportAuthor>, ImportError> {
+        Ok(self.get_client()
+            .get(format!("{endpoint}/authors", endpoint = self.get_endpoint()))?
+            .send()?.json()?)
+    }
+
+    pub fn try_map_author(&self, author: Number, email: impl AsRef<str>, name: impl AsRef<str>) -> GitHubResult<ImportAuthor, ImportError> {
+        let ref payload = serde_json::json!({
+            "email": email.as_ref(),
+            "name": name.as_ref(),
+        });
+
+        Ok(self.get_client()
+            .patch(format!("{endpoint}/authors/{author}", endpoint = self.get_endpoint()))?
+            .json(payload)
+            .send()?.json()?)
+    }
+
+    pub fn try_get_large_files(&self) -> GitHubResult<Vec<ImportLargeFile>, ImportError> {
+        Ok(self.get_client()
+            .get(format!("{endpoint}/large_files", endpoint = self.get_endpoint()))?
+            .send()?.json()?)
+    }
+
+    pub fn try_set_lfs(&self, lfs: ImportLfs) -> GitHubResult<Import, ImportError> {
+        let ref payload = serde_json::json!({
+            "use_lfs": lfs,
+        });
+
+        Ok(self.get_client()
+            .patch(format!("{endpoint}/lfs", endpoint = self.get_endpoint()))?
+            .json(payload)
+            .send()?.json()?)
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleImport {
+    type Content = Import;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.repository.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        format!("repos/{repository}/import", repository = self.repository).into()
+    }
+}
+
+impl FmtDisplay for HandleImport {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{repository}", repository = self.repository)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_redacted() {
+        let source = ImportSource::new("https://svn.example.com/project")
+            .with_credentials("octocat", "hunter2");
+
+        let debug = format!("{source:?}");
+
+        assert!(!(debug.contains("hunter2")));
+        assert!(debug.contains("[REDACTED]"));
+        assert_eq!(serde_json::to_value(&(source)).unwrap()["vcs_password"], "hunter2");
+    }
+}
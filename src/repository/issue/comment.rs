@@ -1,6 +1,14 @@
 use std::{
 
+    collections::{BTreeMap},
     borrow::{Cow},
+    thread::{self},
+
+    time::{
+
+        Duration,
+        Instant,
+    },
 
     fmt::{
         
@@ -12,17 +20,35 @@ use std::{
 
 use crate::{
 
-    repository::issue::{HandleIssue},
+    repository::{
+
+        issue::{HandleIssue},
+
+        HandleRepositoryError,
+    },
 
     client::{
 
         ClientResponseError,
         ClientError,
+        Paginated,
         Client,
     },
 
-    models::common::issue::comment::{Comment},
+    models::common::{
+
+        reaction::{
+
+            ReactionKind,
+            Reaction,
+        },
+
+        issue::comment::{Comment},
+        repository::{Permission},
+    },
     
+    common::{Date},
+
     GitHubProperties,
     GitHubResult, 
     Number,
@@ -30,6 +56,8 @@ use crate::{
 
 use thiserror::{Error};
 
+const REACTION_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Error, Debug)]
 pub enum IssueCommentError {
     #[error("Client error!")]
@@ -38,17 +66,21 @@ pub enum IssueCommentError {
     Author { author: String },
     #[error("Issue comment not found: {number}")]
     Nothing { number: Number },
+    #[error("Failed to check permission for user: '{user}'")]
+    Permission { user: String, #[source] source: Box<HandleRepositoryError> },
 }
 
 #[derive(Clone, Debug)]
 pub struct HandleIssueComment {
     issue: HandleIssue,
     number: Number,
+    created: Option<Date>,
+    updated: Option<Date>,
 }
 
 impl HandleIssueComment {
     pub(crate) fn try_fetch(issue: &HandleIssue, number: impl Into<Number>) -> GitHubResult<HandleIssueComment, IssueCommentError> {
-        let Comment { number, .. } = {
+        let Comment { number, created_at, updated_at, .. } = {
 
             let repository = issue.get_parent();
             let number = number.into();
@@ -72,53 +104,24 @@ impl HandleIssueComment {
         Ok(HandleIssueComment {
             issue: issue.clone(),
             number,
+            created: created_at,
+            updated: updated_at,
         })
     }
 
     pub(crate) fn try_fetch_all(issue: &HandleIssue) -> GitHubResult<Vec<HandleIssueComment>, IssueCommentError> {
         let repository = issue.get_parent();
 
-        let mut collection = Vec::new();
-        let mut page = 0;
-
-        loop {
-
-            page = { page + 1 };
-
-            let capsules: Vec<Comment> = {
-                let ref query = [
-                    ("per_page", 100),
-                    ("page", page),
-                ];
-
-                let result = {
-                    
-                    repository.get_client()
-                        .get(format!("repos/{repository}/issues{issue}/comments"))?
-                        .query(query)
-                        .send()
-                };
-
-                match result {
-                    Err(ClientError::Response(ClientResponseError::Nothing { .. })) => break,
-                    Err(error) => return Err(error.into()),
-                    Ok(response) => response.json()?,
-                }
-            };
-
-            collection.extend_from_slice({
-                capsules.as_slice()
-            });
-
-            if capsules.len() < 100 {
-                break
-            }
-        }
+        let collection: Vec<Comment> = {
+            Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/issues/{issue}/comments"), &[])?
+        };
 
         let mut issues = Vec::new();
-        for Comment { number, .. } in collection {
+        for Comment { number, created_at, updated_at, .. } in collection {
             issues.push(HandleIssueComment {
-                issue: issue.clone(), number
+                issue: issue.clone(), number,
+                created: created_at,
+                updated: updated_at,
             });
         }
 
@@ -133,7 +136,7 @@ impl HandleIssueComment {
                 .to_string()
         });
 
-        let Comment { number, .. } = {
+        let Comment { number, created_at, updated_at, .. } = {
 
             repository.get_client()
                 .post(format!("repos/{repository}/issues/{issue}/comments"))?
@@ -145,6 +148,8 @@ impl HandleIssueComment {
         Ok(HandleIssueComment {
             issue: issue.clone(),
             number,
+            created: created_at,
+            updated: updated_at,
         })
     }
 
@@ -161,6 +166,79 @@ impl HandleIssueComment {
 
         Ok(())
     }
+
+    pub fn try_get_reactions(&self) -> GitHubResult<Vec<Reaction>, IssueCommentError> {
+        Ok(Paginated::try_fetch_all(self.get_client(), format!("{endpoint}/reactions", endpoint = self.get_endpoint()), &[])?)
+    }
+
+    pub fn try_wait_for_reaction<P: AsRef<str>>(&self, kinds: impl AsRef<[ReactionKind]>, from_users: impl AsRef<[P]>, timeout: Duration) -> GitHubResult<Option<Reaction>, IssueCommentError> {
+        let kinds = kinds.as_ref();
+        let users = from_users.as_ref();
+
+        let mut permissions = BTreeMap::new();
+        let started = Instant::now();
+
+        loop {
+
+            for reaction in self.try_get_reactions()? {
+                if !(is_matching_kind(&(reaction), kinds)) {
+                    continue
+                }
+
+                let Some(user) = reaction.get_user() else {
+                    continue
+                };
+
+                let name = user.get_name()
+                    .to_lowercase();
+
+                let authorized = match users.is_empty() {
+                    false => is_matching_user(&(reaction), users),
+                    true => match permissions.get(&(name)) {
+                        Some(authorized) => *authorized,
+                        None => {
+                            let permission = self.get_parent().get_parent()
+                                .try_get_collaborator_permission(name.as_str())
+                                .map_err(|error| IssueCommentError::Permission {
+                                    user: name.clone(),
+                                    source: Box::new(error),
+                                })?;
+
+                            *permissions.entry(name).or_insert(permission.satisfies(Permission::Write))
+                        },
+                    },
+                };
+
+                if authorized {
+                    return Ok(Some(reaction))
+                }
+            }
+
+            let remaining = timeout.saturating_sub(started.elapsed());
+
+            if remaining.is_zero() {
+                return Ok(None)
+            }
+
+            thread::sleep(remaining.min(REACTION_POLL_INTERVAL));
+        }
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_html_url(&self) -> String {
+        format!("{issue}#issuecomment-{number}", issue = self.issue.get_html_url(), number = self.number)
+    }
+
+    pub fn get_created(&self) -> Option<Date> {
+        self.created.clone()
+    }
+
+    pub fn get_updated(&self) -> Option<Date> {
+        self.updated.clone()
+    }
 }
 
 impl<'a> GitHubProperties<'a> for HandleIssueComment {
@@ -202,3 +280,102 @@ impl FmtDisplay for HandleIssueComment {
         })
     }
 }
+
+fn is_matching_kind(reaction: &Reaction, kinds: &[ReactionKind]) -> bool {
+    kinds.is_empty() || kinds.contains(&(reaction.get_kind()))
+}
+
+fn is_matching_user(reaction: &Reaction, users: &[impl AsRef<str>]) -> bool {
+    reaction.get_user().map_or(false, |user| {
+        users.iter().any(|candidate| candidate.as_ref().eq_ignore_ascii_case(user.get_name().as_str()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            MockResponse,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    fn comment(number: usize) -> serde_json::Value {
+        serde_json::json!({
+            "id": number,
+            "body": "Me too",
+            "user": { "login": "octocat", "id": 1, "type": "User" },
+            "created_at": "2024-05-01T12:00:00Z",
+            "updated_at": "2024-05-01T12:00:00Z",
+        })
+    }
+
+    #[test]
+    fn test_fetch_all() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "repos/dev-bio/octo/issues/7/comments?per_page=100&page=1", MockResponse::json(200, serde_json::json!([comment(1), comment(2)]))
+                .with_header("link", "<https://api.github.com/repos/dev-bio/octo/issues/7/comments?per_page=100&page=2>; rel=\"next\""))
+            .with_json(Method::GET, "repos/dev-bio/octo/issues/7/comments?per_page=100&page=2", serde_json::json!([comment(3)]));
+
+        let issue = HandleIssue {
+            repository: fixtures::repository(&(transport)),
+            number: 7,
+            created: None,
+            updated: None,
+        };
+
+        let comments = HandleIssueComment::try_fetch_all(&(issue)).unwrap();
+        let numbers: Vec<Number> = comments.iter()
+            .map(HandleIssueComment::get_number)
+            .collect();
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(transport.get_request_count(Method::GET, "repos/dev-bio/octo/issues/7/comments"), 2);
+
+        let transport = MockTransport::new();
+        let issue = HandleIssue { repository: fixtures::repository(&(transport)), .. issue };
+
+        assert!(matches!(HandleIssueComment::try_fetch_all(&(issue)), Err(IssueCommentError::Client(..))));
+    }
+
+    fn reaction(content: &str, login: Option<&str>) -> Reaction {
+        let user = login.map(|login| serde_json::json!({
+            "login": login,
+            "id": 1,
+            "type": "User",
+        }));
+
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "user": user,
+            "content": content,
+            "created_at": "2024-05-01T12:00:00Z",
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_matching() {
+        let approved = reaction("+1", Some("Octocat"));
+        let rejected = reaction("-1", Some("octocat"));
+        let anonymous = reaction("+1", None);
+
+        let kinds = [ReactionKind::PlusOne, ReactionKind::Rocket];
+        let users = ["octocat", "hubot"];
+
+        assert!(is_matching_kind(&(approved), &(kinds)));
+        assert!(!(is_matching_kind(&(rejected), &(kinds))));
+        assert!(is_matching_kind(&(rejected), &[]));
+
+        assert!(is_matching_user(&(approved), &(users)));
+        assert!(!(is_matching_user(&(approved), &["hubot"])));
+        assert!(!(is_matching_user(&(anonymous), &(users))));
+    }
+}
@@ -24,35 +24,56 @@ use crate::{
         },
 
         HandleRepository,
+        repository_of,
     },
 
     client::{
 
         ClientError,
+        Paginated,
         Client,
     },
     
     models::common::{
         
-        issue::{Issue},
+        issue::{
+
+            LinkedPullRequest,
+            ProjectMembership,
+            IssueExtended,
+            TrackedIssue,
+            LockReason,
+            Issue,
+        },
         user::{User},
     },
 
+    common::{Date},
+
     GitHubProperties,
     GitHubResult, 
     Number,
 };
 
-use serde::{Deserialize};
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
 
 use thiserror::{Error};
 
+pub mod reference;
 pub mod comment;
 
 #[derive(Error, Debug)]
 pub enum IssueError {
     #[error("Client error!")]
-    Client(#[from] ClientError),
+    Client(ClientError),
+    #[error("Request failed for issue {number} in '{repository}': '{endpoint}'")]
+    Request { repository: String, number: Number, endpoint: String, #[source] source: ClientError },
+    #[error("Request failed for issues in '{repository}': '{endpoint}'")]
+    Repository { repository: String, endpoint: String, #[source] source: ClientError },
     #[error("Issue comment error!")]
     Comment(#[from] IssueCommentError),
     #[error("Not an issue: {number}")]
@@ -63,10 +84,165 @@ pub enum IssueError {
     Assignee { assignee: String },
 }
 
+impl IssueError {
+    pub(crate) fn request(repository: impl FmtDisplay, number: Number, source: ClientError) -> IssueError {
+        IssueError::Request {
+            repository: repository.to_string(),
+            endpoint: source.get_endpoint()
+                .unwrap_or_default()
+                .to_owned(),
+            number,
+            source,
+        }
+    }
+
+    pub(crate) fn repository(repository: impl FmtDisplay, source: ClientError) -> IssueError {
+        IssueError::Repository {
+            repository: repository.to_string(),
+            endpoint: source.get_endpoint()
+                .unwrap_or_default()
+                .to_owned(),
+            source,
+        }
+    }
+}
+
+impl From<ClientError> for IssueError {
+    fn from(source: ClientError) -> IssueError {
+        let Some(endpoint) = source.get_endpoint() else {
+            return IssueError::Client(source)
+        };
+
+        let Some(repository) = repository_of(endpoint) else {
+            return IssueError::Client(source)
+        };
+
+        let segments: Vec<_> = endpoint.trim_start_matches('/')
+            .split('/')
+            .skip(3)
+            .collect();
+
+        let number = match segments.as_slice() {
+            ["issues", number, ..] => number.parse::<Number>().ok(),
+            _ => None,
+        };
+
+        match number {
+            Some(number) => IssueError::request(repository, number, source),
+            None => IssueError::repository(repository, source),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateFilter {
+    #[default]
+    Open,
+    Closed,
+    All,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IssueFilter {
+    state: StateFilter,
+    labels: Vec<String>,
+    assignee: Option<String>,
+    creator: Option<String>,
+    mentioned: Option<String>,
+    since: Option<Date>,
+}
+
+impl IssueFilter {
+    pub fn new() -> IssueFilter {
+        Default::default()
+    }
+
+    pub fn open() -> IssueFilter {
+        IssueFilter::new().with_state(StateFilter::Open)
+    }
+
+    pub fn closed() -> IssueFilter {
+        IssueFilter::new().with_state(StateFilter::Closed)
+    }
+
+    pub fn all() -> IssueFilter {
+        IssueFilter::new().with_state(StateFilter::All)
+    }
+
+    pub fn with_state(mut self, state: StateFilter) -> IssueFilter {
+        self.state = state;
+        self
+    }
+
+    pub fn with_label(mut self, label: impl AsRef<str>) -> IssueFilter {
+        self.labels.push(label.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_assignee(mut self, assignee: impl AsRef<str>) -> IssueFilter {
+        self.assignee = Some(assignee.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_creator(mut self, creator: impl AsRef<str>) -> IssueFilter {
+        self.creator = Some(creator.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_mentioned(mut self, mentioned: impl AsRef<str>) -> IssueFilter {
+        self.mentioned = Some(mentioned.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_since(mut self, since: Date) -> IssueFilter {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn get_state(&self) -> StateFilter {
+        self.state
+    }
+
+    fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Ok(serde_json::Value::String(state)) = serde_json::to_value(self.state) {
+            query.push(("state", state));
+        }
+
+        if !(self.labels.is_empty()) {
+            query.push(("labels", self.labels.join(",")));
+        }
+
+        if let Some(ref assignee) = self.assignee {
+            query.push(("assignee", assignee.clone()));
+        }
+
+        if let Some(ref creator) = self.creator {
+            query.push(("creator", creator.clone()));
+        }
+
+        if let Some(ref mentioned) = self.mentioned {
+            query.push(("mentioned", mentioned.clone()));
+        }
+
+        if let Some(since) = self.since {
+            query.push(("since", since.format("%Y-%m-%dT%H:%M:%SZ").to_string()));
+        }
+
+        query
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HandleIssue {
     repository: HandleRepository,
     number: Number, 
+    created: Option<Date>,
+    updated: Option<Date>,
 }
 
 impl HandleIssue {
@@ -103,39 +279,15 @@ impl HandleIssue {
         Ok(HandleIssue {
             repository: repository.clone(),
             number,
+            created: issue.get_created(),
+            updated: issue.get_updated(),
         })
     }
 
-    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleIssue>, IssueError> {
-        let mut collection = Vec::new();
-        let mut page = 0;
-
-        loop {
-
-            page = { page + 1 };
-
-            let capsules: Vec<Issue> = {
-
-                let ref query = [
-                    ("per_page", 100),
-                    ("page", page),
-                ];
-
-                repository.get_client()
-                    .get(format!("repos/{repository}/issues"))?
-                    .query(query)
-                    .send()?
-                    .json()?
-            };
-
-            collection.extend_from_slice({
-                capsules.as_slice()
-            });
-
-            if capsules.len() < 100 {
-                break
-            }
-        }
+    pub(crate) fn try_fetch_all(repository: &HandleRepository, filter: &IssueFilter) -> GitHubResult<Vec<HandleIssue>, IssueError> {
+        let collection: Vec<Issue> = Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/issues"), {
+            filter.get_query().as_slice()
+        })?;
 
         let mut issues = Vec::new();
         for issue in collection {
@@ -147,12 +299,53 @@ impl HandleIssue {
                 repository: repository.clone(), number: {
                     issue.get_number()
                 },
+                created: issue.get_created(),
+                updated: issue.get_updated(),
             });
         }
 
         Ok(issues)
     }
 
+    pub(crate) fn try_create(repository: &HandleRepository, title: impl AsRef<str>, body: impl AsRef<str>) -> GitHubResult<HandleIssue, IssueError> {
+        let ref payload = serde_json::json!({
+            "title": title.as_ref(),
+            "body": body.as_ref(),
+        });
+
+        let issue: Issue = {
+
+            repository.get_client()
+                .post(format!("repos/{repository}/issues"))?
+                .json(payload)
+                .send()?
+                .json()?
+        };
+
+        Ok(HandleIssue {
+            repository: repository.clone(),
+            number: issue.get_number(),
+            created: issue.get_created(),
+            updated: issue.get_updated(),
+        })
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn get_html_url(&self) -> String {
+        format!("{repository}/issues/{number}", repository = self.repository.get_html_url(), number = self.number)
+    }
+
+    pub fn get_created(&self) -> Option<Date> {
+        self.created.clone()
+    }
+
+    pub fn get_updated(&self) -> Option<Date> {
+        self.updated.clone()
+    }
+
     pub fn try_set_assignees<T: FmtDisplay>(&self, assignees: impl AsRef<[T]>) -> GitHubResult<(), IssueError> {
         let repository = self.get_parent();
 
@@ -193,6 +386,132 @@ impl HandleIssue {
         Ok(assignees)
     }
 
+    pub fn try_get_extended(&self) -> GitHubResult<IssueExtended, IssueError> {
+        const QUERY: &str = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issue(number: $number) {
+                        closedByPullRequestsReferences(first: 50, includeClosedPrs: true) {
+                            nodes { number title url state merged repository { nameWithOwner } }
+                        }
+                        projectItems(first: 50) {
+                            nodes { project { id number title url closed } }
+                        }
+                        trackedInIssues(first: 50) {
+                            nodes { number title url state repository { nameWithOwner } }
+                        }
+                        trackedIssues(first: 50) {
+                            nodes { number title url state repository { nameWithOwner } }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleNodes<T> {
+            nodes: Vec<T>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleProjectItem {
+            project: ProjectMembership,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleIssue {
+            #[serde(rename = "closedByPullRequestsReferences")]
+            pulls: CapsuleNodes<LinkedPullRequest>,
+            #[serde(rename = "projectItems")]
+            projects: CapsuleNodes<CapsuleProjectItem>,
+            #[serde(rename = "trackedInIssues")]
+            tracked_by: CapsuleNodes<TrackedIssue>,
+            #[serde(rename = "trackedIssues")]
+            tracking: CapsuleNodes<TrackedIssue>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            issue: CapsuleIssue,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            repository: CapsuleRepository,
+        }
+
+        let HandleIssue { repository, number, .. } = { self };
+
+        let issue: Issue = {
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?.json()?
+        };
+
+        let Capsule { repository: CapsuleRepository { issue: CapsuleIssue { pulls, projects, tracked_by, tracking } } } = {
+
+            let ref variables = serde_json::json!({
+                "owner": repository.get_parent().to_string(),
+                "name": repository.name.as_ref(),
+                "number": number,
+            });
+
+            self.get_client()
+                .try_query(QUERY, variables).map_err(|source| {
+                    IssueError::request(repository, *number, source)
+                })?
+        };
+
+        Ok(IssueExtended {
+            issue,
+            linked_pulls: pulls.nodes,
+            projects: projects.nodes.into_iter()
+                .map(|CapsuleProjectItem { project }| project)
+                .collect(),
+            tracked_by: tracked_by.nodes,
+            tracking: tracking.nodes,
+        })
+    }
+
+    pub fn try_lock(&self, reason: Option<LockReason>) -> GitHubResult<(), IssueError> {
+        Ok(HandleIssue::try_lock_conversation(self.get_parent(), self.number, reason)?)
+    }
+
+    pub fn try_unlock(&self) -> GitHubResult<(), IssueError> {
+        Ok(HandleIssue::try_unlock_conversation(self.get_parent(), self.number)?)
+    }
+
+    pub(crate) fn try_lock_conversation(repository: &HandleRepository, number: Number, reason: Option<LockReason>) -> GitHubResult<(), ClientError> {
+        let request = repository.get_client()
+            .put(format!("repos/{repository}/issues/{number}/lock"))?;
+
+        let _ = {
+
+            match reason {
+                Some(reason) => request.json(&(serde_json::json!({ "lock_reason": reason }))).send()?,
+                None => request.header("content-length", "0").send()?,
+            }
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn try_unlock_conversation(repository: &HandleRepository, number: Number) -> GitHubResult<(), ClientError> {
+        let _ = {
+
+            repository.get_client()
+                .delete(format!("repos/{repository}/issues/{number}/lock"))?
+                .send()?
+        };
+
+        Ok(())
+    }
+
     pub fn try_get_comment(&self, number: Number) -> GitHubResult<HandleIssueComment, IssueError>
    {
         Ok(HandleIssueComment::try_fetch(self, number)?)
@@ -265,3 +584,36 @@ impl FmtDisplay for HandleIssue {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_error_context() {
+        let transport = MockTransport::new();
+        let repository = fixtures::repository(&(transport));
+
+        match HandleIssue::try_fetch(&(repository), 7) {
+            Err(error @ IssueError::Request { .. }) => {
+                assert_eq!(error.to_string(), "Request failed for issue 7 in 'dev-bio/octo': 'repos/dev-bio/octo/issues/7'");
+                assert!(std::error::Error::source(&(error)).is_some());
+            },
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert_eq!(transport.get_request_count(Method::GET, "repos/dev-bio/octo/issues/7"), 1);
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+
+    collections::{HashMap},
+    
+    fmt::{
+    
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    }, 
+};
+
+use thiserror::{Error};
+
+use crate::{
+
+    repository::{
+
+        issue::{HandleIssue},
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+    },
+
+    account::{
+
+        AccountError,
+        Account,
+    },
+
+    models::common::issue::{Issue},
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum IssueReferenceError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Account error!")]
+    Account(#[from] AccountError),
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct IssueReference {
+    repository: Option<String>,
+    number: Number,
+}
+
+impl IssueReference {
+    pub fn parse_all(text: impl AsRef<str>) -> Vec<IssueReference> {
+        let text = text.as_ref();
+        let bytes = text.as_bytes();
+
+        let is_name = |byte: u8| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.');
+        let is_word = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+
+        let mut collection = Vec::new();
+        for (position, _) in text.match_indices('#') {
+            let digits = bytes[position + 1..].iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+
+            let end = position + 1 + digits;
+            if digits == 0 || bytes.get(end).copied().is_some_and(is_word) {
+                continue
+            }
+
+            let Ok(number) = text[position + 1..end].parse::<Number>() else {
+                continue
+            };
+
+            let start = bytes[..position].iter()
+                .rev()
+                .take_while(|byte| is_name(**byte) || **byte == b'/')
+                .count();
+
+            let start = position - start;
+            let prefix = &text[start..position];
+
+            if start > 0 && !(bytes[start - 1].is_ascii_whitespace() || matches!(bytes[start - 1], b'(' | b'[' | b',' | b':' | b';')) {
+                continue
+            }
+
+            let repository = match prefix.split('/').collect::<Vec<_>>().as_slice() {
+                [""] => None,
+                [owner, name] if !(owner.is_empty() || name.is_empty()) => {
+                    Some(prefix.to_lowercase())
+                },
+                _ => continue,
+            };
+
+            let reference = IssueReference { repository, number };
+            if !(collection.contains(&(reference))) {
+                collection.push(reference);
+            }
+        }
+
+        collection
+    }
+
+    pub fn get_repository(&self) -> Option<String> {
+        self.repository.clone()
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.repository.is_none()
+    }
+}
+
+impl FmtDisplay for IssueReference {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        match self.repository {
+            Some(ref repository) => write!(fmt, "{repository}#{number}", number = self.number),
+            None => write!(fmt, "#{number}", number = self.number),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ResolvedIssue {
+    PullRequest { repository: HandleRepository, number: Number },
+    Issue(HandleIssue),
+}
+
+impl ResolvedIssue {
+    pub fn get_repository(&self) -> HandleRepository {
+        match self {
+            ResolvedIssue::PullRequest { repository, .. } => repository.clone(),
+            ResolvedIssue::Issue(issue) => issue.repository.clone(),
+        }
+    }
+
+    pub fn get_number(&self) -> Number {
+        match self {
+            ResolvedIssue::PullRequest { number, .. } => number.clone(),
+            ResolvedIssue::Issue(issue) => issue.get_number(),
+        }
+    }
+
+    pub fn is_pull_request(&self) -> bool {
+        match self {
+            ResolvedIssue::PullRequest { .. } => true,
+            ResolvedIssue::Issue(_) => false,
+        }
+    }
+}
+
+pub(crate) fn try_resolve(repository: &HandleRepository, text: impl AsRef<str>) -> GitHubResult<Vec<ResolvedIssue>, IssueReferenceError> {
+    let mut repositories: HashMap<String, Option<HandleRepository>> = HashMap::new();
+    repositories.insert(repository.to_string(), Some(repository.clone()));
+
+    let mut collection = Vec::new();
+    for reference in IssueReference::parse_all(text) {
+        let name = reference.get_repository()
+            .unwrap_or_else(|| repository.to_string());
+
+        if !(repositories.contains_key(&(name))) {
+            let Some((owner, _)) = name.split_once('/') else {
+                continue
+            };
+
+            let target = match Account::try_from_name(repository.get_client(), owner) {
+                Err(AccountError::Client(ClientError::Response(ClientResponseError::Nothing { .. }))) => None,
+                Err(error) => return Err(error.into()),
+                Ok(account) => match account.try_get_repository(name.as_str()) {
+                    Err(_) => None,
+                    Ok(target) => Some(target),
+                },
+            };
+
+            repositories.insert(name.clone(), target);
+        }
+
+        let Some(Some(target)) = repositories.get(&(name)) else {
+            continue
+        };
+
+        let number = reference.get_number();
+        let result = {
+
+            target.get_client()
+                .get(format!("repos/{target}/issues/{number}"))?
+                .send()
+        };
+
+        let issue: Issue = match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => continue,
+            Err(error) => return Err(error.into()),
+            Ok(response) => response.json()?,
+        };
+
+        collection.push(match issue.is_pull_request() {
+            true => ResolvedIssue::PullRequest { 
+                repository: target.clone(), 
+                number,
+            },
+            false => ResolvedIssue::Issue(HandleIssue {
+                repository: target.clone(),
+                number,
+                created: issue.get_created(),
+                updated: issue.get_updated(),
+            }),
+        });
+    }
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all() {
+        let references = IssueReference::parse_all({
+            "Fixes #12, depends on dev-bio/octo#7 and (Other/Repo#3). \
+             Ignore issue#4, a/b/c#5, #6a, &#38; and https://host/path#8. Fixes #12 again."
+        });
+
+        let references: Vec<String> = references.iter()
+            .map(IssueReference::to_string)
+            .collect();
+
+        assert_eq!(references, vec!["#12", "dev-bio/octo#7", "other/repo#3"]);
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+use serde::{Deserialize};
+use chrono::{Utc};
+
+use crate::{
+
+    repository::{
+
+        metadata::{is_valid_key},
+
+        tree::{
+
+            TreeEntryMode,
+            TreeError,
+            TreeEntry,
+            Tree,
+        },
+
+        blob::{
+
+            BlobError,
+            Blob,
+        },
+
+        commit::{
+
+            CommitError,
+            HandleCommit,
+        },
+
+        sha::{Sha},
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+    },
+
+    common::{
+
+        Duration as ChronoDuration,
+        Date,
+
+        parse_date,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+pub const LOCK_NAMESPACE: &str = "refs/octo/locks";
+
+#[derive(Error, Debug)]
+pub enum LockError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Blob error!")]
+    Blob(#[from] BlobError),
+    #[error("Tree error!")]
+    Tree(#[from] TreeError),
+    #[error("Commit error!")]
+    Commit(#[from] CommitError),
+    #[error("Invalid lock name: '{name}'")]
+    Name { name: String },
+    #[error("Lock is no longer held: '{name}'")]
+    Lost { name: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleLock {
+    repository: HandleRepository,
+    name: String,
+    sha: Sha<'static>,
+    expires: Date,
+}
+
+impl HandleLock {
+    pub(crate) fn try_acquire(repository: &HandleRepository, name: impl AsRef<str>, ttl: ChronoDuration) -> GitHubResult<Option<HandleLock>, LockError> {
+        let name = name.as_ref();
+
+        if !(is_valid_key(name)) {
+            return Err(LockError::Name { name: name.to_owned() })
+        }
+
+        let current = try_get_head(repository, name)?;
+
+        if let Some((_, expires)) = current.as_ref() {
+            if expires.map_or(true, |expires| expires > Utc::now()) {
+                return Ok(None)
+            }
+        }
+
+        let parent = current.map(|(sha, _)| sha);
+
+        match try_update(repository, name, parent, ttl) {
+            Ok(lock) => Ok(Some(lock)),
+            Err(LockError::Lost { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_expires(&self) -> Date {
+        self.expires
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires <= Utc::now()
+    }
+
+    pub fn try_renew(&mut self, ttl: ChronoDuration) -> GitHubResult<(), LockError> {
+        let HandleLock { repository, name, sha, .. } = { self.clone() };
+
+        *(self) = try_update(&(repository), name.as_str(), Some(sha), ttl)?;
+
+        Ok(())
+    }
+
+    pub fn try_release(self) -> GitHubResult<bool, LockError> {
+        let HandleLock { repository, name, sha, .. } = { self };
+
+        match try_update(&(repository), name.as_str(), Some(sha), ChronoDuration::zero()) {
+            Err(LockError::Lost { .. }) => Ok(false),
+            Err(error) => Err(error),
+            Ok(_) => Ok(true),
+        }
+    }
+}
+
+impl FmtDisplay for HandleLock {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let HandleLock { name, .. } = { self };
+        write!(fmt, "{name}")
+    }
+}
+
+fn get_reference(name: &str) -> String {
+    format!("{LOCK_NAMESPACE}/{name}")
+}
+
+fn get_message(name: &str, expires: Date) -> String {
+    format!("Lock {name}\n\nexpires: {expires}", expires = expires.to_rfc3339())
+}
+
+fn parse_expiry(message: &str) -> Option<Date> {
+    message.lines()
+        .find_map(|line| line.trim().strip_prefix("expires:"))
+        .and_then(parse_date)
+}
+
+fn try_get_head(repository: &HandleRepository, name: &str) -> GitHubResult<Option<(Sha<'static>, Option<Date>)>, LockError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct CapsuleObject {
+        sha: Sha<'static>,
+    }
+
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct CapsuleReference {
+        object: CapsuleObject,
+    }
+
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct CapsuleCommit {
+        message: String,
+    }
+
+    let reference = get_reference(name);
+    let reference = reference.trim_start_matches("refs/");
+
+    let result = {
+
+        repository.get_client()
+            .get(format!("repos/{repository}/git/ref/{reference}"))?
+            .send()
+    };
+
+    let CapsuleReference { object: CapsuleObject { sha } } = match result {
+        Err(ClientError::Response(ClientResponseError::Nothing { .. })) => return Ok(None),
+        Err(error) => return Err(error.into()),
+        Ok(response) => response.json()?,
+    };
+
+    let CapsuleCommit { message } = {
+
+        repository.get_client()
+            .get(format!("repos/{repository}/git/commits/{sha}"))?
+            .send()?.json()?
+    };
+
+    Ok(Some((sha, parse_expiry(message.as_str()))))
+}
+
+fn try_update(repository: &HandleRepository, name: &str, parent: Option<Sha<'static>>, ttl: ChronoDuration) -> GitHubResult<HandleLock, LockError> {
+    let reference = get_reference(name);
+    let expires = Utc::now() + ttl;
+
+    let ref document = serde_json::json!({
+        "name": name,
+        "expires": expires,
+    });
+
+    let blob = Blob::try_create_text_blob(repository, document.to_string())?;
+
+    let tree = Tree::try_create(repository, [
+        TreeEntry::blob(blob)
+            .with_mode(TreeEntryMode::file())
+            .with_path("lock.json"),
+    ])?;
+
+    let parents = match parent {
+        Some(ref sha) => vec![HandleCommit::try_fetch(repository, sha.clone())?],
+        None => Vec::new(),
+    };
+
+    let commit = HandleCommit::try_create(repository, parents, tree, {
+        get_message(name, expires)
+    })?;
+
+    let sha = commit.get_sha()
+        .to_owned();
+
+    let result = match parent {
+        Some(_) => {
+            let ref payload = serde_json::json!({
+                "sha": sha,
+                "force": false,
+            });
+
+            repository.get_client()
+                .patch(format!("repos/{repository}/git/refs/{path}", path = reference.trim_start_matches("refs/")))?
+                .json(payload)
+                .send()
+        },
+        None => {
+            let ref payload = serde_json::json!({
+                "ref": reference,
+                "sha": sha,
+            });
+
+            repository.get_client()
+                .post(format!("repos/{repository}/git/refs"))?
+                .json(payload)
+                .send()
+        },
+    };
+
+    match result {
+        Err(ClientError::Response(ClientResponseError::Validation { .. })) => Err(LockError::Lost {
+            name: name.to_owned(),
+        }),
+        Err(error) => Err(error.into()),
+        Ok(_) => Ok(HandleLock {
+            repository: repository.clone(),
+            name: name.to_owned(),
+            sha,
+            expires,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            MockResponse,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_expiry() {
+        let expires = parse_date("2024-05-01T12:30:00Z").unwrap();
+        let message = get_message("deploy/production", expires);
+
+        assert_eq!(parse_expiry(message.as_str()), Some(expires));
+        assert_eq!(parse_expiry("Lock deploy"), None);
+    }
+
+    #[test]
+    fn test_release() {
+        let reference = "repos/dev-bio/octo/git/refs/octo/locks/deploy";
+
+        let transport = MockTransport::new()
+            .with_json(Method::POST, "repos/dev-bio/octo/git/blobs", serde_json::json!({ "sha": "b1" }))
+            .with_json(Method::POST, "repos/dev-bio/octo/git/trees", serde_json::json!({ "sha": "t1", "tree": [] }))
+            .with_json(Method::GET, "repos/dev-bio/octo/git/commits/c1", serde_json::json!({
+                "sha": "c1",
+                "author": { "date": "2024-05-01T12:00:00Z" },
+            }))
+            .with_json(Method::POST, "repos/dev-bio/octo/git/commits", serde_json::json!({
+                "sha": "c2",
+                "author": { "date": "2024-05-01T12:05:00Z" },
+            }))
+            .with_json(Method::PATCH, reference, serde_json::json!({}))
+            .with_response(Method::PATCH, reference, MockResponse::json(422, serde_json::json!({
+                "message": "Update is not a fast forward",
+            })));
+
+        let lock = HandleLock {
+            repository: fixtures::repository(&(transport)),
+            name: "deploy".to_owned(),
+            sha: "c1".to_owned().into(),
+            expires: Utc::now(),
+        };
+
+        assert!(lock.clone().try_release().unwrap());
+        assert!(!(lock.try_release().unwrap()));
+
+        assert_eq!(transport.get_request_body(Method::PATCH, reference).map(|body| body["force"].clone()), Some(serde_json::json!(false)));
+        assert!(transport.get_requests().iter().all(|(method, _)| *method != Method::DELETE));
+    }
+}
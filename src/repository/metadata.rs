@@ -0,0 +1,328 @@
+use std::{
+
+    fmt::{Debug as FmtDebug},
+};
+
+use thiserror::{Error};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    repository::{
+
+        tree::{
+
+            TreeEntryMode,
+            TreeError,
+            TreeEntry,
+            Tree,
+        },
+
+        blob::{
+
+            BlobError,
+            Blob,
+        },
+
+        commit::{
+
+            CommitError,
+            HandleCommit,
+        },
+
+        sha::{Sha},
+
+        HandleRepository,
+    },
+
+    client::{
+
+        ClientResponseError,
+        ClientError,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+pub const METADATA_NAMESPACE: &str = "refs/octo/meta";
+pub const METADATA_FILE: &str = "value.json";
+
+#[derive(Error, Debug)]
+pub enum MetadataError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Blob error!")]
+    Blob(#[from] BlobError),
+    #[error("Tree error!")]
+    Tree(#[from] TreeError),
+    #[error("Commit error!")]
+    Commit(#[from] CommitError),
+    #[error("Invalid metadata key: '{key}'")]
+    Key { key: String },
+    #[error("Failed to encode metadata: '{key}', reason: '{reason}'")]
+    Encode { key: String, reason: String },
+    #[error("Failed to decode metadata: '{key}', reason: '{reason}'")]
+    Decode { key: String, reason: String },
+    #[error("Metadata was changed concurrently: '{key}'")]
+    Conflict { key: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct MetadataEntry<T> {
+    value: T,
+    version: Sha<'static>,
+}
+
+impl<T: Clone> MetadataEntry<T> {
+    pub fn get_value(&self) -> T {
+        self.value.clone()
+    }
+
+    pub fn get_version(&self) -> Sha<'static> {
+        self.version.clone()
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleMetadata {
+    pub(crate) repository: HandleRepository,
+}
+
+impl HandleMetadata {
+    pub(crate) fn from(repository: &HandleRepository) -> HandleMetadata {
+        HandleMetadata { repository: repository.clone() }
+    }
+
+    pub fn get_reference(&self, key: impl AsRef<str>) -> GitHubResult<String, MetadataError> {
+        let key = key.as_ref();
+
+        match is_valid_key(key) {
+            true => Ok(format!("{METADATA_NAMESPACE}/{key}")),
+            false => Err(MetadataError::Key { key: key.to_owned() }),
+        }
+    }
+
+    pub fn try_get_version(&self, key: impl AsRef<str>) -> GitHubResult<Option<Sha<'static>>, MetadataError> {
+        let HandleMetadata { repository } = { self };
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleObject {
+            sha: Sha<'static>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            object: CapsuleObject,
+        }
+
+        let reference = self.get_reference(key)?;
+        let reference = reference.trim_start_matches("refs/");
+
+        let result = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/git/ref/{reference}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(None),
+            Err(error) => Err(error.into()),
+            Ok(response) => {
+                let Capsule { object: CapsuleObject { sha } } = response.json()?;
+                Ok(Some(sha))
+            },
+        }
+    }
+
+    pub fn try_read<T>(&self, key: impl AsRef<str>) -> GitHubResult<Option<MetadataEntry<T>>, MetadataError>
+    where T: DeserializeOwned + FmtDebug {
+        let HandleMetadata { repository } = { self };
+        let key = key.as_ref();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleTree {
+            sha: Sha<'static>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            tree: CapsuleTree,
+        }
+
+        let Some(version) = self.try_get_version(key)? else {
+            return Ok(None)
+        };
+
+        let Capsule { tree: CapsuleTree { sha } } = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/git/commits/{version}"))?
+                .send()?.json()?
+        };
+
+        let tree = Tree::try_fetch(repository, sha, false)?;
+
+        let Some(entry) = tree.get_entry(METADATA_FILE) else {
+            return Err(MetadataError::Decode {
+                key: key.to_owned(),
+                reason: format!("missing '{METADATA_FILE}'"),
+            })
+        };
+
+        let blob = Blob::try_fetch(repository, entry.get_sha())?;
+
+        let value = serde_json::from_slice(blob.as_bytes()).map_err(|error| {
+            MetadataError::Decode { key: key.to_owned(), reason: error.to_string() }
+        })?;
+
+        Ok(Some(MetadataEntry { value, version }))
+    }
+
+    pub fn try_write<T>(&self, key: impl AsRef<str>, value: &T) -> GitHubResult<Sha<'static>, MetadataError>
+    where T: Serialize + FmtDebug {
+        let key = key.as_ref();
+
+        let expected = self.try_get_version(key)?;
+        self.try_commit(key, expected, value, true)
+    }
+
+    pub fn try_compare_and_swap<T>(&self, key: impl AsRef<str>, expected: Option<Sha<'static>>, value: &T) -> GitHubResult<Sha<'static>, MetadataError>
+    where T: Serialize + FmtDebug {
+        let key = key.as_ref();
+
+        if self.try_get_version(key)? != expected {
+            return Err(MetadataError::Conflict { key: key.to_owned() })
+        }
+
+        self.try_commit(key, expected, value, false)
+    }
+
+    pub fn try_delete(&self, key: impl AsRef<str>) -> GitHubResult<bool, MetadataError> {
+        let HandleMetadata { repository } = { self };
+
+        let reference = self.get_reference(key)?;
+        let reference = reference.trim_start_matches("refs/");
+
+        let result = {
+
+            repository.get_client()
+                .delete(format!("repos/{repository}/git/refs/{reference}"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(false),
+            Err(ClientError::Response(ClientResponseError::Validation { .. })) => Ok(false),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    fn try_commit<T>(&self, key: &str, expected: Option<Sha<'static>>, value: &T, force: bool) -> GitHubResult<Sha<'static>, MetadataError>
+    where T: Serialize + FmtDebug {
+        let HandleMetadata { repository } = { self };
+
+        let reference = self.get_reference(key)?;
+
+        let document = serde_json::to_string_pretty(value).map_err(|error| {
+            MetadataError::Encode { key: key.to_owned(), reason: error.to_string() }
+        })?;
+
+        let blob = Blob::try_create_text_blob(repository, document)?;
+
+        let tree = Tree::try_create(repository, [
+            TreeEntry::blob(blob)
+                .with_mode(TreeEntryMode::file())
+                .with_path(METADATA_FILE),
+        ])?;
+
+        let parents = match expected {
+            Some(ref version) => vec![HandleCommit::try_fetch(repository, version.clone())?],
+            None => Vec::new(),
+        };
+
+        let commit = HandleCommit::try_create(repository, parents, tree, {
+            format!("Update {reference}")
+        })?;
+
+        let sha = commit.get_sha()
+            .to_owned();
+
+        let result = match expected {
+            Some(_) => {
+                let ref payload = serde_json::json!({
+                    "sha": sha,
+                    "force": force,
+                });
+
+                repository.get_client()
+                    .patch(format!("repos/{repository}/git/refs/{path}", path = reference.trim_start_matches("refs/")))?
+                    .json(payload)
+                    .send()
+            },
+            None => {
+                let ref payload = serde_json::json!({
+                    "ref": reference,
+                    "sha": sha,
+                });
+
+                repository.get_client()
+                    .post(format!("repos/{repository}/git/refs"))?
+                    .json(payload)
+                    .send()
+            },
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Validation { .. })) => Err(MetadataError::Conflict {
+                key: key.to_owned(),
+            }),
+            Err(error) => Err(error.into()),
+            Ok(_) => Ok(sha),
+        }
+    }
+}
+
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    !(key.is_empty()) && key.split('/').all(|component| {
+        !(component.is_empty() || component.starts_with('.') || component.ends_with(".lock")) && component.chars().all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.')
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        let valid = ["deploy", "deploy/production", "cache.v2", "team_a/state-1"];
+        let invalid = ["", "/deploy", "deploy/", "a//b", ".hidden", "state.lock", "a b", "../escape"];
+
+        for key in valid {
+            assert!(is_valid_key(key), "{key}");
+        }
+
+        for key in invalid {
+            assert!(!(is_valid_key(key)), "{key}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+
+    borrow::{Cow},
+    
+    fmt::{
+    
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    }, 
+};
+
+use serde::{Deserialize};
+
+use thiserror::{Error};
+
+use crate::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientError,
+        Paginated,
+        Client,
+    },
+
+    models::common::{
+
+        milestone::{
+
+            MilestoneProgress,
+            Milestone,
+        },
+
+        issue::{IssueState},
+    },
+
+    common::{Date},
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum MilestoneError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleMilestone {
+    repository: HandleRepository,
+    number: Number,
+}
+
+impl HandleMilestone {
+    pub(crate) fn try_fetch(repository: &HandleRepository, number: Number) -> GitHubResult<HandleMilestone, MilestoneError> {
+        let milestone: Milestone = {
+
+            repository.get_client()
+                .get(format!("repos/{repository}/milestones/{number}"))?
+                .send()?
+                .json()?
+        };
+
+        Ok(HandleMilestone {
+            repository: repository.clone(),
+            number: milestone.get_number(),
+        })
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository) -> GitHubResult<Vec<HandleMilestone>, MilestoneError> {
+        let ref query = [
+            ("state", "all".to_owned()),
+        ];
+
+        let collection: Vec<Milestone> = Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/milestones"), query)?;
+
+        Ok(collection.into_iter()
+            .map(|milestone| HandleMilestone {
+                repository: repository.clone(),
+                number: milestone.get_number(),
+            })
+            .collect())
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+
+    pub fn try_get_progress(&self) -> GitHubResult<MilestoneProgress, MilestoneError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleLabel {
+            name: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleIssue {
+            state: IssueState,
+            labels: Vec<CapsuleLabel>,
+            #[serde(deserialize_with = "crate::common::deserialize_date")]
+            created_at: Date,
+            #[serde(default, deserialize_with = "crate::common::deserialize_optional_date")]
+            closed_at: Option<Date>,
+        }
+
+        let HandleMilestone { repository, number } = { self };
+
+        let milestone: Milestone = {
+
+            self.get_client()
+                .get(format!("repos/{repository}/milestones/{number}"))?
+                .send()?
+                .json()?
+        };
+
+        let query = format!("repo:{repository} is:issue milestone:\"{title}\"", title = {
+            milestone.get_title()
+        });
+
+        let issues: Vec<CapsuleIssue> = Paginated::try_fetch_all(self.get_client(), "search/issues", &[("q", query)])?;
+
+        let collection: Vec<_> = issues.into_iter()
+            .map(|CapsuleIssue { state, labels, created_at, closed_at }| {
+                let labels = labels.into_iter()
+                    .map(|CapsuleLabel { name }| name)
+                    .collect();
+
+                (state, labels, created_at, closed_at)
+            })
+            .collect();
+
+        Ok(MilestoneProgress::compute(milestone, collection))
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleMilestone {
+    type Content = Milestone;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.repository.get_client()
+    }
+    
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let Self { repository, .. } = { self };
+        format!("repos/{repository}/milestones/{self}").into()
+    }
+}
+
+impl FmtDisplay for HandleMilestone {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        write!(fmt, "{number}", number = {
+            self.number.clone()
+        })
+    }
+}
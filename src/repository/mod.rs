@@ -1,18 +1,31 @@
 use std::{
 
+    collections::{
+
+        VecDeque,
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+
     borrow::{Cow}, 
+    sync::{Arc},
+    time::{Duration},
+    path::{PathBuf, Path},
+    thread::{self},
 
     fmt::{
     
         Formatter as FmtFormatter,
         Display as FmtDisplay,
         Result as FmtResult,
+        Debug as FmtDebug,
     }, 
 };
 
 use crate::{
 
-    client::{Client, ClientError, ClientResponseError},
+    client::{Client, ClientError, ClientResponseError, Paginated, media},
     account::{Account},
     
     repository::{
@@ -25,15 +38,48 @@ use crate::{
 
         commit::{
 
+            UnverifiedCommit,
             CommitError,
             HandleCommit,
         },
 
         issue::{
 
+            reference::{
+
+                IssueReferenceError,
+                ResolvedIssue,
+            },
+
+            IssueFilter,
             IssueError,
             HandleIssue,
         },
+
+        discussion::{
+
+            DiscussionError,
+            HandleDiscussion,
+        },
+
+        import::{
+
+            ImportError,
+            HandleImport,
+        },
+
+        actions::{
+
+            ActionsError,
+            HandleActions,
+        },
+
+        run::{
+
+            WorkflowRunFilter,
+            WorkflowRunError,
+            HandleWorkflowRun,
+        },
         
         tree::{
     
@@ -48,65 +94,308 @@ use crate::{
             Blob,
         },
 
+        milestone::{
+
+            MilestoneError,
+            HandleMilestone,
+        },
+
+        badges::{
+
+            StatusSummary,
+            Badge,
+        },
+
+        template::{TemplateSubstitution},
+        activity::{ActivityFilter},
+
+        scanning::{
+
+            SecretScanningError,
+            HandleSecretScanning,
+        },
+
+        metadata::{
+
+            MetadataError,
+            HandleMetadata,
+        },
+
+        lock::{
+
+            LockError,
+            HandleLock,
+        },
+
+        secrets::{
+
+            EnvironmentConfiguration,
+            SecretsError,
+            SyncOptions,
+            SyncReport,
+        },
+
+        codeowners::{
+
+            CodeOwnersError,
+            CodeOwners,
+            CodeOwner,
+        },
+
+        properties::{Visibility},
         sha::{Sha},
     }, 
     
-    models::common::repository::{Repository},
+    models::common::{
+
+        activity::{Activity},
+
+        statistics::{
+
+            ContributorStatistics,
+            CodeFrequency,
+            Languages,
+        },
+
+        workflow::{WorkflowDefinition},
+        release::{Release},
+        issue::{LockReason},
+        issue::template::{
+
+            IssueTemplate,
+            IssueForm,
+        },
+
+        discussion::{DiscussionCategory},
+        contributor::{Contributor},
+        user::{User},
+        repository::{
+
+            Permission,
+            Repository,
+        },
+
+        run::{
+
+            WorkflowDurationStatistics,
+            WorkflowRunStatus,
+            WorkflowRun,
+        },
+
+        ruleset::{
+
+            RulePreview,
+            BranchRule,
+        },
+
+        tag::{Tag},
+    },
     
+    common::{
+
+        Duration as ChronoDuration,
+        Date,
+    },
+
     GitHubProperties,
 };
 
 use serde::{
 
+    de::{DeserializeOwned},
+
     Deserialize,
     Serialize,
 };
 
+use secrecy::{Secret};
+use chrono::{Utc};
 use thiserror::{Error};
 use zip::result::{ZipError};
-
+use glob::{Pattern};
+
+pub mod codeowners;
+pub mod actions;
+pub mod secrets;
+pub mod scanning;
+pub mod badges;
+pub mod template;
+pub mod activity;
+pub mod metadata;
+pub mod lock;
+pub mod milestone;
 pub mod properties;
+pub mod discussion;
 pub mod reference;
+pub mod import;
+pub mod run;
 pub mod commit;
 pub mod issue;
 pub mod tree;
 pub mod blob;
 pub mod sha;
 
-use crate::{GitHubResult};
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+use crate::{GitHubResult, Number};
 
 #[derive(Error, Debug)]
 pub enum HandleRepositoryError {
     #[error("Client error!")]
-    Client(#[from] ClientError),
+    Client(ClientError),
+    #[error("Request failed for repository '{repository}': '{endpoint}'")]
+    Request { repository: String, endpoint: String, #[source] source: ClientError },
     #[error("Reference error!")]
     Reference(#[from] ReferenceError),
     #[error("Commit error!")]
     Commit(#[from] CommitError),
     #[error("Issue error!")]
     Issue(#[from] IssueError),
+    #[error("Milestone error!")]
+    Milestone(#[from] MilestoneError),
+    #[error("Discussion error!")]
+    Discussion(#[from] DiscussionError),
+    #[error("Import error!")]
+    Import(#[from] ImportError),
+    #[error("Actions error!")]
+    Actions(#[from] ActionsError),
+    #[error("Workflow run error!")]
+    WorkflowRun(#[from] WorkflowRunError),
     #[error("Blob error!")]
     Blob(#[from] BlobError),
     #[error("Tree error!")]
     Tree(#[from] TreeError),
+    #[error("Code owners error!")]
+    CodeOwners(#[from] CodeOwnersError),
+    #[error("Secrets error!")]
+    Secrets(#[from] SecretsError),
+    #[error("Secret scanning error!")]
+    SecretScanning(#[from] SecretScanningError),
+    #[error("Metadata error!")]
+    Metadata(#[from] MetadataError),
+    #[error("Lock error!")]
+    Lock(#[from] LockError),
     #[error("Invalid reference: '{name}'")]
     InvalidReference { name: String },
     #[error("Invalid branch: '{name}'")]
     InvalidBranch { name: String },
     #[error("Invalid tag: '{name}'")]
     InvalidTag { name: String },
+    #[error("Invalid issue template: '{path:?}', reason: '{reason}'")]
+    InvalidTemplate { path: PathBuf, reason: String },
+    #[error("Invalid workflow definition: '{path:?}', reason: '{reason}'")]
+    InvalidWorkflow { path: PathBuf, reason: String },
+    #[error("Invalid pattern: '{pattern}'")]
+    InvalidPattern { pattern: String },
+    #[error("Unterminated badge block: '{path:?}'")]
+    UnterminatedBadges { path: PathBuf },
     #[error("Failed to get default branch: '{name}'")]
     DefaultBranch { name: String },
     #[error("Extraction error!")]
     Archive(#[from] ZipError),
+    #[error("Failed to extract tarball into '{path:?}', reason: '{reason}'")]
+    Tarball { path: PathBuf, reason: String },
+    #[error("Refusing to change visibility of '{name}', reason: '{reason}'")]
+    Visibility { name: String, reason: String },
+    #[error("Statistics are still being computed: '{name}'")]
+    Statistics { name: String },
     #[error("Repository not found: '{name}'")]
     Nothing { name: String },
 }
 
+impl HandleRepositoryError {
+    pub(crate) fn request(repository: impl FmtDisplay, source: ClientError) -> HandleRepositoryError {
+        HandleRepositoryError::Request {
+            repository: repository.to_string(),
+            endpoint: source.get_endpoint()
+                .unwrap_or_default()
+                .to_owned(),
+            source,
+        }
+    }
+}
+
+impl From<ClientError> for HandleRepositoryError {
+    fn from(source: ClientError) -> HandleRepositoryError {
+        match source.get_endpoint().and_then(repository_of) {
+            Some(repository) => HandleRepositoryError::request(repository, source),
+            None => HandleRepositoryError::Client(source),
+        }
+    }
+}
+
+pub(crate) fn repository_of(endpoint: &str) -> Option<String> {
+    let mut segments = endpoint.trim_start_matches('/').split('/');
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("repos"), Some(owner), Some(name)) if !(owner.is_empty() || name.is_empty()) => {
+            Some(format!("{owner}/{name}"))
+        },
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RepositoryForks {
+    fork: bool,
+    forks: Number,
+    parent: Option<HandleRepository>,
+    source: Option<HandleRepository>,
+}
+
+impl RepositoryForks {
+    pub fn get_forks(&self) -> Number {
+        self.forks.clone()
+    }
+
+    pub fn get_parent(&self) -> Option<HandleRepository> {
+        self.parent.clone()
+    }
+
+    pub fn get_source(&self) -> Option<HandleRepository> {
+        self.source.clone()
+    }
+
+    pub fn is_fork(&self) -> bool {
+        self.fork
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DefaultBranchChange {
+    previous: String,
+    current: String,
+    retargeted: Vec<Number>,
+    failed: Vec<Number>,
+}
+
+impl DefaultBranchChange {
+    pub fn get_previous(&self) -> String {
+        self.previous.clone()
+    }
+
+    pub fn get_current(&self) -> String {
+        self.current.clone()
+    }
+
+    pub fn get_retargeted(&self) -> Vec<Number> {
+        self.retargeted.clone()
+    }
+
+    pub fn get_failed(&self) -> Vec<Number> {
+        self.failed.clone()
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.previous != self.current
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct HandleRepository {
     pub(crate) owner: Account,
-    pub(crate) name: String,
+    pub(crate) name: Arc<str>,
 }
 
 impl HandleRepository {
@@ -140,7 +429,7 @@ impl HandleRepository {
 
         Ok(HandleRepository {
             owner: owner.clone(),
-            name: name.to_lowercase(),
+            name: name.to_lowercase().into(),
         })
     }
 
@@ -151,35 +440,12 @@ impl HandleRepository {
             name: String,
         }
 
-        let mut collection = Vec::new();
-        let mut page = 0;
-
-        loop {
-
-            page = { page + 1 };
-
-            let capsules: Vec<Capsule> = {
-                let ref query = [
-                    ("per_page", 100),
-                    ("page", page),
-                ];
-
-                owner.get_client()
-                    .get(format!("users/{owner}/repos"))?
-                    .query(query).send()?.json()?
-            };
-
-            collection.extend_from_slice({
-                capsules.as_slice()
-            });
-
-            if capsules.len() < 100 {
-                break
-            }
-        }
+        let collection: Vec<Capsule> = Paginated::try_fetch_all(owner.get_client(), format!("users/{owner}/repos"), &[]).map_err(|source| {
+            HandleRepositoryError::request(owner, source)
+        })?;
 
         Ok(collection.into_iter().map(|Capsule { name }| HandleRepository { 
-            owner: owner.clone(), name: name.to_lowercase()
+            owner: owner.clone(), name: name.to_lowercase().into()
         }).collect())
     }
 
@@ -216,12 +482,537 @@ impl HandleRepository {
         Ok(total_count)
     }
 
+    fn try_get_statistics<T>(&self, kind: impl AsRef<str>) -> GitHubResult<Vec<T>, HandleRepositoryError>
+    where T: DeserializeOwned + FmtDebug {
+        let kind = kind.as_ref();
+
+        for attempt in 1..=5 {
+
+            let response = {
+
+                self.get_client()
+                    .get(format!("repos/{self}/stats/{kind}"))?
+                    .send()?
+            };
+
+            match response.code() {
+                202 => thread::sleep(Duration::from_secs(attempt * 2)),
+                204 => return Ok(Vec::new()),
+                _ => return Ok(response.json()?),
+            }
+        }
+
+        Err(HandleRepositoryError::Statistics { 
+            name: format!("{self}/{kind}") 
+        })
+    }
+
+    pub fn try_get_contributor_stats(&self) -> GitHubResult<Vec<ContributorStatistics>, HandleRepositoryError> {
+        self.try_get_statistics("contributors")
+    }
+
+    pub fn try_get_code_frequency(&self) -> GitHubResult<Vec<CodeFrequency>, HandleRepositoryError> {
+        self.try_get_statistics("code_frequency")
+    }
+
+    pub fn get_html_url(&self) -> String {
+        format!("{base}{owner}/{name}", base = self.get_client().get_html_url(), owner = self.owner, name = self.name)
+    }
+
+    pub fn get_actions(&self) -> HandleActions {
+        HandleActions::from(self)
+    }
+
+    pub fn get_secret_scanning(&self) -> HandleSecretScanning {
+        HandleSecretScanning::from(self)
+    }
+
+    pub fn get_metadata(&self) -> HandleMetadata {
+        HandleMetadata::from(self)
+    }
+
+    pub fn try_acquire_lock(&self, name: impl AsRef<str>, ttl: ChronoDuration) -> GitHubResult<Option<HandleLock>, HandleRepositoryError> {
+        Ok(HandleLock::try_acquire(self, name, ttl)?)
+    }
+
+    pub fn try_archive(&self) -> GitHubResult<(), HandleRepositoryError> {
+        let ref payload = serde_json::json!({
+            "archived": true,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_unarchive(&self) -> GitHubResult<(), HandleRepositoryError> {
+        let ref payload = serde_json::json!({
+            "archived": false,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_set_visibility(&self, visibility: Visibility, force: bool) -> GitHubResult<(), HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            visibility: Visibility,
+            archived: bool,
+        }
+
+        let Capsule { visibility: current, archived } = self.try_get_properties()?;
+
+        if current == visibility {
+            return Ok(())
+        }
+
+        if archived {
+            return Err(HandleRepositoryError::Visibility {
+                name: self.to_string(),
+                reason: "repository is archived".to_owned(),
+            })
+        }
+
+        if !(force) {
+            let count = secrets::try_count_secrets(self)?;
+
+            if count > 0 {
+                return Err(HandleRepositoryError::Visibility {
+                    name: self.to_string(),
+                    reason: format!("repository has {count} actions secrets"),
+                })
+            }
+        }
+
+        let ref payload = serde_json::json!({
+            "visibility": visibility,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_languages(&self) -> GitHubResult<Languages, HandleRepositoryError> {
+        Ok(self.get_client()
+            .get(format!("repos/{self}/languages"))?
+            .send()?.json()?)
+    }
+
+    pub fn try_get_topics(&self) -> GitHubResult<Vec<String>, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            names: Vec<String>,
+        }
+
+        let Capsule { names } = {
+            self.get_client()
+                .get(format!("repos/{self}/topics"))?
+                .send()?.json()?
+        };
+
+        Ok(names)
+    }
+
+    pub fn try_list_contributors(&self, include_anonymous: bool) -> GitHubResult<Vec<Contributor>, HandleRepositoryError> {
+        let ref query = [
+            ("anon", include_anonymous.to_string()),
+        ];
+
+        Ok(Paginated::try_fetch_all(self.get_client(), format!("repos/{self}/contributors"), query)?)
+    }
+
+    pub fn try_get_forks(&self) -> GitHubResult<RepositoryForks, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleRepository {
+            owner: User,
+            name: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            fork: bool,
+            forks_count: Number,
+            parent: Option<CapsuleRepository>,
+            source: Option<CapsuleRepository>,
+        }
+
+        let Capsule { fork, forks_count, parent, source } = {
+            self.get_client()
+                .get(format!("repos/{self}"))?
+                .send()?.json()?
+        };
+
+        let handle = |capsule: Option<CapsuleRepository>| capsule.and_then(|CapsuleRepository { owner, name }| {
+            Account::from_user(self.get_client(), &(owner)).map(|owner| HandleRepository {
+                name: name.to_lowercase().into(),
+                owner,
+            })
+        });
+
+        Ok(RepositoryForks {
+            fork,
+            forks: forks_count,
+            parent: handle(parent),
+            source: handle(source),
+        })
+    }
+
+    pub fn try_is_fork(&self) -> GitHubResult<bool, HandleRepositoryError> {
+        Ok(self.try_get_forks()?
+            .is_fork())
+    }
+
+    pub fn try_get_parent(&self) -> GitHubResult<Option<HandleRepository>, HandleRepositoryError> {
+        Ok(self.try_get_forks()?
+            .get_parent())
+    }
+
+    pub fn try_get_source(&self) -> GitHubResult<Option<HandleRepository>, HandleRepositoryError> {
+        Ok(self.try_get_forks()?
+            .get_source())
+    }
+
+    pub fn import(&self) -> HandleImport {
+        HandleImport::from(self)
+    }
+
+    pub fn try_get_workflow_run(&self, number: Number) -> GitHubResult<HandleWorkflowRun, HandleRepositoryError> {
+        Ok(HandleWorkflowRun::try_fetch(self, number)?)
+    }
+
+    pub fn try_dispatch_workflow(&self, workflow: impl AsRef<str>, reference: impl AsRef<str>, inputs: impl Serialize) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(HandleWorkflowRun::try_dispatch(self, workflow, reference, inputs)?)
+    }
+
+    pub fn try_get_workflow_runs(&self, filter: WorkflowRunFilter) -> GitHubResult<Vec<WorkflowRun>, HandleRepositoryError> {
+        Ok(HandleWorkflowRun::try_fetch_runs(self, &(filter))?)
+    }
+
+    pub fn try_get_workflow_durations(&self, from: Date, to: Date) -> GitHubResult<Vec<WorkflowDurationStatistics>, HandleRepositoryError> {
+        let ref filter = WorkflowRunFilter::new()
+            .with_status(WorkflowRunStatus::Completed)
+            .with_created(from, to);
+
+        let mut collection = Vec::new();
+        for run in HandleWorkflowRun::try_fetch_runs(self, filter)? {
+            let handle = HandleWorkflowRun {
+                repository: self.clone(),
+                number: run.get_number(),
+            };
+
+            collection.push((run, handle.try_get_timing()?));
+        }
+
+        Ok(WorkflowDurationStatistics::aggregate(collection))
+    }
+
+    pub fn try_cancel_runs(&self, filter: WorkflowRunFilter, older_than: ChronoDuration) -> GitHubResult<Vec<(HandleWorkflowRun, GitHubResult<(), WorkflowRunError>)>, HandleRepositoryError> {
+        let threshold = Utc::now() - older_than;
+
+        let mut collection = Vec::new();
+        for status in [WorkflowRunStatus::Queued, WorkflowRunStatus::InProgress] {
+            let ref filter = filter.clone()
+                .with_status(status);
+
+            for run in HandleWorkflowRun::try_fetch_runs(self, filter)? {
+                let started = run.get_date_started()
+                    .unwrap_or(run.get_date_created());
+
+                if started > threshold {
+                    continue
+                }
+
+                let handle = HandleWorkflowRun {
+                    repository: self.clone(),
+                    number: run.get_number(),
+                };
+
+                let result = handle.try_cancel();
+                collection.push((handle, result));
+            }
+        }
+
+        Ok(collection)
+    }
+
     pub fn try_get_issue(&self, id: usize) -> GitHubResult<HandleIssue, HandleRepositoryError> {
         Ok(HandleIssue::try_fetch(self, id)?)
     }
 
+    pub fn try_create_issue(&self, title: impl AsRef<str>, body: impl AsRef<str>) -> GitHubResult<HandleIssue, HandleRepositoryError> {
+        Ok(HandleIssue::try_create(self, title, body)?)
+    }
+
+    pub fn try_lock_conversation(&self, number: Number, reason: Option<LockReason>) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(HandleIssue::try_lock_conversation(self, number, reason)?)
+    }
+
+    pub fn try_unlock_conversation(&self, number: Number) -> GitHubResult<(), HandleRepositoryError> {
+        Ok(HandleIssue::try_unlock_conversation(self, number)?)
+    }
+
+    pub fn try_resolve_review_thread(&self, id: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        const QUERY: &str = r#"
+            mutation($thread: ID!) {
+                resolveReviewThread(input: { threadId: $thread }) {
+                    thread { id isResolved }
+                }
+            }
+        "#;
+
+        self.try_set_review_thread(QUERY, "resolveReviewThread", id)
+    }
+
+    pub fn try_unresolve_review_thread(&self, id: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        const QUERY: &str = r#"
+            mutation($thread: ID!) {
+                unresolveReviewThread(input: { threadId: $thread }) {
+                    thread { id isResolved }
+                }
+            }
+        "#;
+
+        self.try_set_review_thread(QUERY, "unresolveReviewThread", id)
+    }
+
+    fn try_set_review_thread(&self, query: &str, mutation: &str, id: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleThread {
+            #[serde(rename = "isResolved")]
+            resolved: bool,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleMutation {
+            thread: CapsuleThread,
+        }
+
+        let ref variables = serde_json::json!({
+            "thread": id.as_ref(),
+        });
+
+        let mut capsule: HashMap<String, CapsuleMutation> = {
+            self.get_client()
+                .try_query(query, variables).map_err(|source| {
+                    HandleRepositoryError::request(self, source)
+                })?
+        };
+
+        match capsule.remove(mutation) {
+            Some(CapsuleMutation { thread: CapsuleThread { resolved } }) => Ok(resolved),
+            None => Err(HandleRepositoryError::Client(ClientError::Response({
+                ClientResponseError::Query { message: format!("missing '{mutation}' in response") }
+            }))),
+        }
+    }
+
     pub fn try_get_all_issues(&self) -> GitHubResult<Vec<HandleIssue>, HandleRepositoryError> {
-        Ok(HandleIssue::try_fetch_all(self)?)
+        Ok(HandleIssue::try_fetch_all(self, &(IssueFilter::new()))?)
+    }
+
+    pub fn try_get_issues(&self, filter: IssueFilter) -> GitHubResult<Vec<HandleIssue>, HandleRepositoryError> {
+        Ok(HandleIssue::try_fetch_all(self, &(filter))?)
+    }
+
+    pub fn try_resolve_issue_references(&self, text: impl AsRef<str>) -> GitHubResult<Vec<ResolvedIssue>, IssueReferenceError> {
+        issue::reference::try_resolve(self, text)
+    }
+
+    pub fn try_get_milestone(&self, number: Number) -> GitHubResult<HandleMilestone, HandleRepositoryError> {
+        Ok(HandleMilestone::try_fetch(self, number)?)
+    }
+
+    pub fn try_get_all_milestones(&self) -> GitHubResult<Vec<HandleMilestone>, HandleRepositoryError> {
+        Ok(HandleMilestone::try_fetch_all(self)?)
+    }
+
+    pub fn try_list_issue_templates(&self) -> GitHubResult<Vec<IssueTemplate>, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(rename = "type")]
+            kind: String,
+            path: PathBuf,
+            sha: Sha<'static>,
+        }
+
+        let result = {
+
+            self.get_client()
+                .get(format!("repos/{self}/contents/.github/ISSUE_TEMPLATE"))?
+                .send()
+        };
+
+        let capsules: Vec<Capsule> = match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => return Ok(Vec::new()),
+            Err(error) => return Err(HandleRepositoryError::request(self, error)),
+            Ok(response) => response.json()?,
+        };
+
+        let mut collection = Vec::new();
+        for Capsule { kind, path, sha } in capsules {
+            if kind != "file" {
+                continue
+            }
+
+            let extension = path.extension()
+                .and_then(|extension| extension.to_str())
+                .map(str::to_lowercase);
+
+            let stem = path.file_stem()
+                .and_then(|stem| stem.to_str());
+
+            if stem == Some("config") {
+                continue
+            }
+
+            let blob = Blob::try_fetch(self, sha)?;
+            let content = blob.as_text_lossy()
+                .into_owned();
+
+            match extension.as_deref() {
+                Some("yml") | Some("yaml") => collection.push(IssueTemplate::Form {
+                    form: IssueForm::try_parse(content.as_str()).map_err(|error| {
+                        HandleRepositoryError::InvalidTemplate { path: path.clone(), reason: error.to_string() }
+                    })?,
+                    path,
+                }),
+                Some("md") => collection.push(IssueTemplate::Markdown { 
+                    path, 
+                    content,
+                }),
+                _ => continue,
+            }
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_workflow_definition(&self, path: impl AsRef<Path>, reference: impl AsRef<str>) -> GitHubResult<WorkflowDefinition, HandleRepositoryError> {
+        let path = path.as_ref();
+        let path = match path.parent() {
+            Some(parent) if parent.as_os_str().is_empty() => Path::new(".github/workflows").join(path),
+            _ => path.to_owned(),
+        };
+
+        let result = {
+
+            self.get_client()
+                .get(format!("repos/{self}/contents/{path}", path = path.to_string_lossy().trim_start_matches('/')))?
+                .query(&[("ref", reference.as_ref())])
+                .accept(media::RAW)
+                .send()
+        };
+
+        let content = match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => {
+                return Err(HandleRepositoryError::Blob(BlobError::Nothing { path }))
+            },
+            Err(error) => return Err(HandleRepositoryError::request(self, error)),
+            Ok(response) => response.text()?,
+        };
+
+        WorkflowDefinition::try_parse(content.as_str()).map_err(|error| {
+            HandleRepositoryError::InvalidWorkflow { path, reason: error.to_string() }
+        })
+    }
+
+    pub fn try_get_discussion(&self, number: Number) -> GitHubResult<HandleDiscussion, HandleRepositoryError> {
+        Ok(HandleDiscussion::try_fetch(self, number)?)
+    }
+
+    pub fn try_get_all_discussions(&self) -> GitHubResult<Vec<HandleDiscussion>, HandleRepositoryError> {
+        Ok(HandleDiscussion::try_fetch_all(self)?)
+    }
+
+    pub fn try_get_discussion_categories(&self) -> GitHubResult<Vec<DiscussionCategory>, HandleRepositoryError> {
+        Ok(HandleDiscussion::try_fetch_categories(self)?)
+    }
+
+    pub fn try_create_discussion(&self, category: impl AsRef<str>, title: impl AsRef<str>, body: impl AsRef<str>) -> GitHubResult<HandleDiscussion, HandleRepositoryError> {
+        Ok(HandleDiscussion::try_create(self, category, title, body)?)
+    }
+
+    pub fn try_get_collaborator_permission(&self, user: impl AsRef<str>) -> GitHubResult<Permission, HandleRepositoryError> {
+        let user = user.as_ref();
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            role_name: Option<String>,
+            permission: Permission,
+        }
+
+        let result = {
+
+            self.get_client()
+                .get(format!("repos/{self}/collaborators/{user}/permission"))?
+                .send()
+        };
+
+        match result {
+            Err(ClientError::Response(ClientResponseError::Nothing { .. })) => Ok(Permission::None),
+            Err(error) => Err(HandleRepositoryError::request(self, error)),
+            Ok(response) => {
+                let Capsule { role_name, permission } = response.json()?;
+                Ok(role_name.and_then(Permission::parse)
+                    .unwrap_or(permission))
+            },
+        }
+    }
+
+    pub(crate) fn try_get_node_id(&self) -> GitHubResult<String, ClientError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            node_id: String,
+        }
+
+        let Capsule { node_id } = {
+
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?
+                .json()?
+        };
+
+        Ok(node_id)
+    }
+
+    pub(crate) fn try_get_number(&self) -> GitHubResult<Number, ClientError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            id: Number,
+        }
+
+        let Capsule { id } = {
+
+            self.get_client()
+                .get(self.get_endpoint())?
+                .send()?
+                .json()?
+        };
+
+        Ok(id)
     }
 
     pub fn try_has_tag(&self, tag: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
@@ -245,6 +1036,26 @@ impl HandleRepository {
         }
     }
 
+    pub fn try_list_tags(&self) -> GitHubResult<Vec<Tag>, HandleRepositoryError> {
+        Ok(Paginated::try_fetch_all(self.get_client(), format!("repos/{self}/tags"), &[])?)
+    }
+
+    pub fn try_create_release(&self, tag: impl AsRef<str>, name: impl AsRef<str>, body: impl AsRef<str>, draft: bool, prerelease: bool) -> GitHubResult<Release, HandleRepositoryError> {
+        let ref payload = serde_json::json!({
+            "tag_name": tag.as_ref(),
+            "name": name.as_ref(),
+            "body": body.as_ref(),
+            "draft": draft,
+            "prerelease": prerelease,
+        });
+
+        Ok(self.get_client()
+            .post(format!("repos/{self}/releases"))?
+            .json(payload)
+            .send()?
+            .json()?)
+    }
+
     pub fn try_get_tag(&self, tag: impl AsRef<str>) -> GitHubResult<HandleReference, HandleRepositoryError> {
         let tag = tag.as_ref();
 
@@ -322,6 +1133,93 @@ impl HandleRepository {
         })?)
     }
 
+    pub fn try_set_default_branch(&self, branch: impl AsRef<str>, retarget: bool) -> GitHubResult<DefaultBranchChange, HandleRepositoryError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            default_branch: String,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsulePull {
+            number: Number,
+        }
+
+        let current = match self.try_get_branch(branch)? {
+            HandleReference::Branch { branch, .. } => branch,
+            reference => return Err(HandleRepositoryError::InvalidBranch {
+                name: reference.to_string(),
+            }),
+        };
+
+        let Capsule { default_branch: previous } = self.try_get_properties()?;
+
+        let mut change = DefaultBranchChange {
+            previous: previous.clone(),
+            current: current.clone(),
+            retargeted: Vec::new(),
+            failed: Vec::new(),
+        };
+
+        if previous == current {
+            return Ok(change)
+        }
+
+        let ref payload = serde_json::json!({
+            "default_branch": current,
+        });
+
+        self.get_client()
+            .patch(self.get_endpoint())?
+            .json(payload)
+            .send()?;
+
+        if !(retarget) {
+            return Ok(change)
+        }
+
+        let ref query = [
+            ("state", "open".to_owned()),
+            ("base", previous.clone()),
+        ];
+
+        let pulls: Vec<CapsulePull> = Paginated::try_fetch_all(self.get_client(), format!("repos/{self}/pulls"), query)?;
+
+        let ref payload = serde_json::json!({
+            "base": current,
+        });
+
+        for CapsulePull { number } in pulls {
+            let result = {
+
+                self.get_client()
+                    .patch(format!("repos/{self}/pulls/{number}"))
+                    .and_then(|request| request.json(payload).send())
+            };
+
+            match result {
+                Ok(_) => change.retargeted.push(number),
+                Err(_) => change.failed.push(number),
+            }
+        }
+
+        Ok(change)
+    }
+
+    pub fn try_preview_rules(&self, branch: impl AsRef<str>) -> GitHubResult<RulePreview, HandleRepositoryError> {
+        let branch = branch.as_ref();
+        let branch = branch.strip_prefix("refs/heads/")
+            .unwrap_or(branch);
+
+        let rules: Vec<BranchRule> = Paginated::try_fetch_all(self.get_client(), format!("repos/{self}/rules/branches/{branch}"), &[])?;
+
+        Ok(RulePreview {
+            branch: branch.to_owned(),
+            rules,
+        })
+    }
+
     pub fn try_has_reference(&self, reference: impl AsRef<str>) -> GitHubResult<bool, HandleRepositoryError> {
         Ok(self.try_get_some_reference(reference)?.is_some())
     }
@@ -338,6 +1236,10 @@ impl HandleRepository {
         Ok(HandleReference::try_fetch(self, reference)?)
     }
 
+    pub fn try_list_references(&self, prefix: impl AsRef<str>) -> GitHubResult<Vec<HandleReference>, HandleRepositoryError> {
+        Ok(HandleReference::try_fetch_matching(self, prefix)?)
+    }
+
     pub fn try_create_tag(&self, tag: impl AsRef<str>, commit: HandleCommit) -> GitHubResult<HandleReference, HandleRepositoryError>  {
         let tag = tag.as_ref();
 
@@ -389,11 +1291,83 @@ impl HandleRepository {
     pub fn try_delete_reference(&self, reference: HandleReference) -> GitHubResult<(), HandleRepositoryError> {
         Ok(reference.try_delete()?)
     }
+
+    pub fn try_delete_merged_branches(&self, base: impl AsRef<str>, protect: impl IntoIterator<Item = impl AsRef<str>>, dry_run: bool) -> GitHubResult<Vec<HandleReference>, HandleRepositoryError> {
+        let base = self.try_get_branch(base)?;
+        let default = self.try_get_default_branch()?;
+
+        let patterns = protect.into_iter().map(|pattern| {
+            Pattern::new(pattern.as_ref()).map_err(|_| {
+                HandleRepositoryError::InvalidPattern { 
+                    pattern: pattern.as_ref().to_owned() 
+                }
+            })
+        }).collect::<GitHubResult<Vec<Pattern>, HandleRepositoryError>>()?;
+
+        let head = base.try_get_commit()?;
+
+        let mut collection = Vec::new();
+        for branch in self.try_list_references("heads/")? {
+            let HandleReference::Branch { branch: ref name, .. } = branch else {
+                continue
+            };
+
+            if branch.to_string() == base.to_string() || branch.to_string() == default.to_string() {
+                continue
+            }
+
+            if patterns.iter().any(|pattern| pattern.matches(name)) {
+                continue
+            }
+
+            let commit = branch.try_get_commit()?;
+            if !(commit.try_compare(head.clone())?.is_fast_forward()) {
+                continue
+            }
+
+            if !(dry_run) {
+                branch.try_delete()?;
+            }
+
+            collection.push(branch);
+        }
+
+        Ok(collection)
+    }
     
     pub fn try_get_blob<'a>(&self, sha: impl Into<Sha<'a>>) -> GitHubResult<Blob, HandleRepositoryError> {
         Ok(Blob::try_fetch(self, sha)?)
     }
 
+    pub fn try_get_blob_for_path(&self, reference: impl AsRef<str>, path: impl AsRef<Path>) -> GitHubResult<Blob, HandleRepositoryError> {
+        Blob::try_fetch_for_path(self, reference, path)
+    }
+
+    pub fn try_get_codeowners(&self, reference: impl AsRef<str>) -> GitHubResult<CodeOwners, HandleRepositoryError> {
+        CodeOwners::try_fetch(self, reference)
+    }
+
+    pub fn try_resolve_codeowners(&self, paths: impl IntoIterator<Item = impl AsRef<Path>>, reference: impl AsRef<str>) -> GitHubResult<BTreeMap<PathBuf, Vec<CodeOwner>>, HandleRepositoryError> {
+        Ok(CodeOwners::try_fetch(self, reference)?
+            .resolve(paths))
+    }
+
+    pub fn try_sync_secrets(&self, secrets: &BTreeMap<String, Secret<String>>, options: SyncOptions) -> GitHubResult<SyncReport, HandleRepositoryError> {
+        Ok(secrets::try_sync_secrets(self, secrets, options)?)
+    }
+
+    pub fn try_sync_variables(&self, variables: &BTreeMap<String, String>, options: SyncOptions) -> GitHubResult<SyncReport, HandleRepositoryError> {
+        Ok(secrets::try_sync_variables(self, variables, options)?)
+    }
+
+    pub fn try_list_environments(&self) -> GitHubResult<Vec<String>, HandleRepositoryError> {
+        Ok(secrets::try_list_environments(self)?)
+    }
+
+    pub fn try_get_environment_configuration(&self, environment: impl AsRef<str>) -> GitHubResult<EnvironmentConfiguration, HandleRepositoryError> {
+        Ok(secrets::try_get_environment_configuration(self, environment)?)
+    }
+
     pub fn try_create_binary_blob(&self, content: impl AsRef<[u8]>) -> GitHubResult<Blob, HandleRepositoryError> {
         Ok(Blob::try_create_binary_blob(self, content)?)
     }
@@ -418,6 +1392,10 @@ impl HandleRepository {
         Ok(HandleCommit::try_fetch(self, commit)?)
     }
 
+    pub fn try_get_latest_commit_for_path(&self, reference: impl AsRef<str>, path: impl AsRef<Path>) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+        Ok(HandleCommit::try_fetch_latest(self, reference, path)?)
+    }
+
     pub fn try_has_commit<'a>(&self, commit: impl Into<Sha<'a>>) -> GitHubResult<bool, HandleRepositoryError> {
         match HandleCommit::try_fetch(self, commit) {
             Err(CommitError::Client(ClientError::Response(ClientResponseError::Nothing { .. }))) => Ok(false),
@@ -426,6 +1404,85 @@ impl HandleRepository {
         }
     }
 
+    pub fn try_resolve_commit(&self, reference: impl AsRef<str>) -> GitHubResult<HandleCommit, HandleRepositoryError> {
+        let reference = reference.as_ref();
+
+        let candidate = |result: GitHubResult<Option<HandleReference>, HandleRepositoryError>| match result {
+            Err(HandleRepositoryError::Reference(ReferenceError::Invalid { .. })) => Ok(None),
+            Err(HandleRepositoryError::InvalidBranch { .. }) => Ok(None),
+            Err(HandleRepositoryError::InvalidTag { .. }) => Ok(None),
+            result => result,
+        };
+
+        if let Some(reference) = candidate(self.try_get_some_reference(reference))? {
+            return reference.try_get_commit()
+        }
+
+        if let Some(branch) = candidate(self.try_get_some_branch(reference))? {
+            return branch.try_get_commit()
+        }
+
+        if let Some(tag) = candidate(self.try_get_some_tag(reference))? {
+            return tag.try_get_commit()
+        }
+
+        self.try_get_commit(reference)
+    }
+
+    pub fn try_verify_history_signed(&self, reference: impl AsRef<str>, depth: usize, early_exit: bool) -> GitHubResult<Vec<UnverifiedCommit>, HandleRepositoryError> {
+        let head = self.try_resolve_commit(reference)?;
+
+        let mut collection = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([head]);
+
+        while let Some(commit) = queue.pop_front() {
+            if visited.len() >= depth {
+                break
+            }
+
+            if !(visited.insert(commit.get_sha().to_owned())) {
+                continue
+            }
+
+            if let Some(unverified) = commit.try_get_verification()? {
+                collection.push(unverified);
+
+                if early_exit {
+                    break
+                }
+            }
+
+            queue.extend(commit.try_get_parents()?);
+        }
+
+        Ok(collection)
+    }
+
+    pub fn try_get_activity(&self, filter: &ActivityFilter) -> GitHubResult<Vec<Activity>, HandleRepositoryError> {
+        Ok(activity::try_fetch_all(self, filter)?)
+    }
+
+    pub fn try_get_build_badge(&self, branch: impl AsRef<str>) -> GitHubResult<Badge, HandleRepositoryError> {
+        badges::try_get_build_badge(self, branch)
+    }
+
+    pub fn try_generate(&self, owner: &Account, name: impl AsRef<str>, private: bool) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        template::try_generate(self, owner, name, private)
+    }
+
+    pub fn try_generate_with_substitution(&self, owner: &Account, name: impl AsRef<str>, private: bool, substitution: &TemplateSubstitution) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+        template::try_generate_with_substitution(self, owner, name, private, substitution)
+    }
+
+    pub fn try_apply_substitution(&self, substitution: &TemplateSubstitution, message: impl AsRef<str>) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+        template::try_substitute(self, substitution, message)
+    }
+
+    pub fn try_commit_status_summary(&self, summary: &StatusSummary, branch: impl AsRef<str>, path: impl AsRef<Path>, message: impl AsRef<str>) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+        badges::try_commit_summary(self, summary, branch, path, message)
+    }
+
     pub fn try_create_commit(&self, parents: impl AsRef<[HandleCommit]>, tree: Tree, message: impl AsRef<str>) -> GitHubResult<HandleCommit, HandleRepositoryError> { 
         Ok(HandleCommit::try_create(self, parents, tree, message)?) 
     }
@@ -453,4 +1510,49 @@ impl FmtDisplay for HandleRepository {
     fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
         write!(fmt, "{owner}/{name}", owner = self.owner, name = self.name)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            MockResponse,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_commit_errors() {
+        let transport = MockTransport::new()
+            .with_response(Method::GET, "repos/dev-bio/octo/git/ref/heads/main", MockResponse::new(403));
+
+        let repository = fixtures::repository(&(transport));
+
+        assert!(matches!(repository.try_resolve_commit("main"), Err(HandleRepositoryError::Reference(ReferenceError::Client(..)))));
+        assert!(transport.get_requests().iter().all(|(_, path)| !(path.contains("/commits/"))));
+    }
+
+    #[test]
+    fn test_error_context() {
+        let transport = MockTransport::new();
+        let repository = fixtures::repository(&(transport));
+
+        match repository.try_get_languages() {
+            Err(error @ HandleRepositoryError::Request { .. }) => {
+                assert_eq!(error.to_string(), "Request failed for repository 'dev-bio/octo': 'repos/dev-bio/octo/languages'");
+            },
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert_eq!(repository_of("repos/dev-bio/octo/issues/7").as_deref(), Some("dev-bio/octo"));
+        assert_eq!(repository_of("users/dev-bio/repos"), None);
+    }
+}
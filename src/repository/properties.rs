@@ -11,6 +11,7 @@ use crate::{
 use super::{HandleRepository, HandleRepositoryError};
 
 #[derive(Debug, Clone)]
+#[derive(PartialEq, Eq)]
 #[derive(Serialize, Deserialize)]
 pub enum Visibility {
     #[serde(rename = "public")]
@@ -1,6 +1,10 @@
 use std::{
 
-    collections::{HashSet},
+    collections::{
+
+        BTreeSet,
+        HashSet,
+    },
     
     fmt::{
     
@@ -11,7 +15,12 @@ use std::{
 };
 
 use thiserror::{Error};
-use serde::{Deserialize};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
 
 use crate::{
 
@@ -46,10 +55,32 @@ pub enum ReferenceError {
     Nothing { reference: String },
     #[error("Circular reference: '{reference}'")]
     Circular { reference: String },
+    #[error("Not a fast-forward: '{reference}' from '{current}' to '{target}'")]
+    NonFastForward { reference: String, current: String, target: String },
     #[error("Reference is deleted!")]
     Delete,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct RequiredChecksDelta {
+    pub(crate) added: Vec<String>,
+    pub(crate) removed: Vec<String>,
+}
+
+impl RequiredChecksDelta {
+    pub fn get_added(&self) -> Vec<String> {
+        self.added.clone()
+    }
+
+    pub fn get_removed(&self) -> Vec<String> {
+        self.removed.clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum HandleReference {
     PullRequest { repository: HandleRepository, branch: String, issue: Number },
@@ -135,13 +166,36 @@ impl HandleReference {
             }
         };
 
-        if dbg!(dbg!(name).ends_with(dbg!(reference))) { Ok(parsed) } else { 
+        if name.ends_with(reference) { Ok(parsed) } else { 
             Err(ReferenceError::Nothing {
                 reference: reference.to_string()
             })
         }
     }
 
+    pub(crate) fn try_fetch_matching(repository: &HandleRepository, prefix: impl AsRef<str>) -> GitHubResult<Vec<HandleReference>, ReferenceError> {
+        let prefix = prefix.as_ref();
+        let prefix = prefix.strip_prefix("refs/")
+            .unwrap_or(prefix);
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(rename = "ref")]
+            name: String,
+        }
+
+        let capsules: Vec<Capsule> = {
+            repository.get_client()
+                .get(format!("repos/{repository}/git/matching-refs/{prefix}"))?
+                .send()?.json()?
+        };
+
+        Ok(capsules.into_iter().filter_map(|Capsule { name }| {
+            Self::try_parse(repository, name).ok()
+        }).collect())
+    }
+
     pub(crate) fn try_create(repository: &HandleRepository, commit: HandleCommit, reference: impl AsRef<str>) -> GitHubResult<HandleReference, ReferenceError> {
         let reference = reference.as_ref();
         let parsed = Self::try_parse(repository, {
@@ -204,6 +258,100 @@ impl HandleReference {
         Ok(())
     }
 
+    pub fn try_fast_forward(&self, commit: HandleCommit) -> GitHubResult<(), HandleRepositoryError> {
+        let current = self.try_get_commit()?;
+
+        let compare = current.try_compare(commit.clone())?;
+        if !(compare.is_fast_forward()) {
+            return Err(HandleRepositoryError::Reference({
+                ReferenceError::NonFastForward {
+                    reference: self.to_string(),
+                    current: current.get_sha().to_string(),
+                    target: commit.get_sha().to_string(),
+                }
+            }))
+        }
+
+        self.try_set_commit(false, commit.get_sha())
+    }
+
+    pub fn try_force_push(&self, commit: HandleCommit) -> GitHubResult<(), HandleRepositoryError> {
+        self.try_set_commit(true, commit.get_sha())
+    }
+
+    /// Replaces the required status checks of a branch; concurrent updates are last-writer-wins.
+    /// Newly added contexts are pinned to `app` when given, existing contexts keep their app.
+    pub fn try_sync_required_checks(&self, contexts: impl IntoIterator<Item = impl AsRef<str>>, app: Option<i64>) -> GitHubResult<RequiredChecksDelta, ReferenceError> {
+        let HandleReference::Branch { repository, branch } = self else {
+            return Err(ReferenceError::Invalid { 
+                reference: self.to_string() 
+            })
+        };
+
+        #[derive(Debug, Clone)]
+        #[derive(Serialize, Deserialize)]
+        struct CapsuleCheck {
+            context: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            app_id: Option<i64>,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            #[serde(default)]
+            strict: bool,
+            #[serde(default)]
+            checks: Vec<CapsuleCheck>,
+        }
+
+        let endpoint = format!("repos/{repository}/branches/{branch}/protection/required_status_checks");
+
+        let Capsule { strict, checks } = {
+            repository.get_client()
+                .get(endpoint.as_str())?
+                .send()?.json()?
+        };
+
+        let desired: BTreeSet<String> = contexts.into_iter()
+            .map(|context| context.as_ref().to_owned())
+            .collect();
+
+        let current: BTreeSet<String> = checks.iter()
+            .map(|check| check.context.clone())
+            .collect();
+
+        let delta = RequiredChecksDelta {
+            added: desired.difference(&(current)).cloned().collect(),
+            removed: current.difference(&(desired)).cloned().collect(),
+        };
+
+        if delta.is_empty() {
+            return Ok(delta)
+        }
+
+        let mut checks: Vec<CapsuleCheck> = checks.into_iter()
+            .filter(|check| desired.contains(&(check.context)))
+            .collect();
+
+        checks.extend(delta.added.iter().map(|context| CapsuleCheck {
+            context: context.clone(),
+            app_id: app,
+        }));
+
+        let ref payload = serde_json::json!({
+            "strict": strict,
+            "checks": checks,
+        });
+
+        repository.get_client()
+            .patch(endpoint.as_str())?
+            .json(payload)
+            .send()?;
+
+        Ok(delta)
+    }
+
     pub fn try_get_commit(&self) -> GitHubResult<HandleCommit, HandleRepositoryError> {
         let repository = self.get_repository();
         let client = self.get_client();
@@ -314,4 +462,59 @@ impl FmtDisplay for HandleReference {
             HandleReference::Tag { tag, .. } => write!(fmt, "tags/{tag}"),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_sync_required_checks() {
+        let endpoint = "repos/dev-bio/octo/branches/main/protection/required_status_checks";
+
+        let transport = MockTransport::new()
+            .with_json(Method::GET, endpoint, serde_json::json!({
+                "strict": true,
+                "checks": [
+                    { "context": "build", "app_id": 15368 },
+                    { "context": "lint", "app_id": null },
+                ],
+            }))
+            .with_json(Method::PATCH, endpoint, serde_json::json!({}));
+
+        let reference = HandleReference::Branch {
+            repository: fixtures::repository(&(transport)),
+            branch: "main".to_owned(),
+        };
+
+        let delta = reference.try_sync_required_checks(["build", "test"], Some(42)).unwrap();
+
+        assert_eq!(delta.get_added(), vec!["test".to_owned()]);
+        assert_eq!(delta.get_removed(), vec!["lint".to_owned()]);
+
+        assert_eq!(transport.get_request_body(Method::PATCH, endpoint), Some(serde_json::json!({
+            "strict": true,
+            "checks": [
+                { "context": "build", "app_id": 15368 },
+                { "context": "test", "app_id": 42 },
+            ],
+        })));
+
+        let delta = reference.try_sync_required_checks(["build", "lint"], None).unwrap();
+
+        assert!(delta.is_empty());
+        assert_eq!(transport.get_request_count(Method::PATCH, endpoint), 1);
+    }
+}
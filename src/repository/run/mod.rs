@@ -0,0 +1,235 @@
+use std::{
+
+    borrow::{Cow},
+
+    fmt::{
+
+        Formatter as FmtFormatter,
+        Display as FmtDisplay,
+        Result as FmtResult,
+    },
+};
+
+use thiserror::{Error};
+use serde::{Serialize};
+
+use crate::{
+
+    repository::{HandleRepository},
+
+    client::{
+
+        ClientError,
+        Paginated,
+        Client,
+    },
+
+    models::common::run::{
+
+        WorkflowRunStatus,
+        WorkflowRunTiming,
+        WorkflowRun,
+    },
+
+    common::{Date},
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum WorkflowRunError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorkflowRunFilter {
+    workflow: Option<String>,
+    branch: Option<String>,
+    event: Option<String>,
+    actor: Option<String>,
+    status: Option<WorkflowRunStatus>,
+    created: Option<(Date, Date)>,
+}
+
+impl WorkflowRunFilter {
+    pub fn new() -> WorkflowRunFilter {
+        Default::default()
+    }
+
+    pub fn with_workflow(mut self, workflow: impl AsRef<str>) -> WorkflowRunFilter {
+        self.workflow = Some(workflow.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_branch(mut self, branch: impl AsRef<str>) -> WorkflowRunFilter {
+        self.branch = Some(branch.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_event(mut self, event: impl AsRef<str>) -> WorkflowRunFilter {
+        self.event = Some(event.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_actor(mut self, actor: impl AsRef<str>) -> WorkflowRunFilter {
+        self.actor = Some(actor.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_status(mut self, status: WorkflowRunStatus) -> WorkflowRunFilter {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_created(mut self, from: Date, to: Date) -> WorkflowRunFilter {
+        self.created = Some((from, to));
+        self
+    }
+
+    fn get_endpoint(&self, repository: &HandleRepository) -> String {
+        match self.workflow {
+            Some(ref workflow) => format!("repos/{repository}/actions/workflows/{workflow}/runs"),
+            None => format!("repos/{repository}/actions/runs"),
+        }
+    }
+
+    fn get_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(ref branch) = self.branch {
+            query.push(("branch", branch.clone()));
+        }
+
+        if let Some(ref event) = self.event {
+            query.push(("event", event.clone()));
+        }
+
+        if let Some(ref actor) = self.actor {
+            query.push(("actor", actor.clone()));
+        }
+
+        if let Some(status) = self.status {
+            if let Ok(serde_json::Value::String(status)) = serde_json::to_value(status) {
+                query.push(("status", status));
+            }
+        }
+
+        if let Some((from, to)) = self.created {
+            query.push(("created", format!("{from}..{to}",
+                from = from.format("%Y-%m-%dT%H:%M:%SZ"),
+                to = to.format("%Y-%m-%dT%H:%M:%SZ"))));
+        }
+
+        query
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleWorkflowRun {
+    pub(crate) repository: HandleRepository,
+    pub(crate) number: Number,
+}
+
+impl HandleWorkflowRun {
+    pub(crate) fn try_fetch(repository: &HandleRepository, number: Number) -> GitHubResult<HandleWorkflowRun, WorkflowRunError> {
+        let WorkflowRun { number, .. } = {
+            repository.get_client()
+                .get(format!("repos/{repository}/actions/runs/{number}"))?
+                .send()?.json()?
+        };
+
+        Ok(HandleWorkflowRun {
+            repository: repository.clone(),
+            number,
+        })
+    }
+
+    pub(crate) fn try_fetch_runs(repository: &HandleRepository, filter: &WorkflowRunFilter) -> GitHubResult<Vec<WorkflowRun>, WorkflowRunError> {
+        let endpoint = filter.get_endpoint(repository);
+
+        Ok(Paginated::try_fetch_all(repository.get_client(), endpoint, filter.get_query().as_slice())?)
+    }
+
+    pub(crate) fn try_fetch_latest(repository: &HandleRepository, filter: &WorkflowRunFilter) -> GitHubResult<Option<WorkflowRun>, WorkflowRunError> {
+        let endpoint = filter.get_endpoint(repository);
+
+        Ok(Paginated::try_fetch(repository.get_client(), endpoint, filter.get_query().as_slice(), 1, 1)?
+            .into_items().into_iter().next())
+    }
+
+    pub(crate) fn try_fetch_all(repository: &HandleRepository, filter: &WorkflowRunFilter) -> GitHubResult<Vec<HandleWorkflowRun>, WorkflowRunError> {
+        Ok(HandleWorkflowRun::try_fetch_runs(repository, filter)?.into_iter()
+            .map(|WorkflowRun { number, .. }| HandleWorkflowRun {
+                repository: repository.clone(),
+                number,
+            }).collect())
+    }
+
+    pub(crate) fn try_dispatch(repository: &HandleRepository, workflow: impl AsRef<str>, reference: impl AsRef<str>, inputs: impl Serialize) -> GitHubResult<(), WorkflowRunError> {
+        let workflow = workflow.as_ref();
+
+        let ref payload = serde_json::json!({
+            "ref": reference.as_ref(),
+            "inputs": inputs,
+        });
+
+        repository.get_client()
+            .post(format!("repos/{repository}/actions/workflows/{workflow}/dispatches"))?
+            .json(payload)
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn try_get_run(&self) -> GitHubResult<WorkflowRun, WorkflowRunError> {
+        Ok(self.get_client()
+            .get(self.get_endpoint())?
+            .send()?.json()?)
+    }
+
+    pub fn try_get_timing(&self) -> GitHubResult<WorkflowRunTiming, WorkflowRunError> {
+        Ok(self.get_client()
+            .get(format!("{endpoint}/timing", endpoint = self.get_endpoint()))?
+            .send()?.json()?)
+    }
+
+    pub fn try_cancel(&self) -> GitHubResult<(), WorkflowRunError> {
+        self.get_client()
+            .post(format!("{endpoint}/cancel", endpoint = self.get_endpoint()))?
+            .send()?;
+
+        Ok(())
+    }
+
+    pub fn get_number(&self) -> Number {
+        self.number.clone()
+    }
+}
+
+impl<'a> GitHubProperties<'a> for HandleWorkflowRun {
+    type Content = WorkflowRun;
+    type Parent = HandleRepository;
+
+    fn get_client(&'a self) -> &'a Client {
+        self.repository.get_client()
+    }
+
+    fn get_parent(&'a self) -> &'a Self::Parent {
+        &(self.repository)
+    }
+
+    fn get_endpoint(&'a self) -> Cow<'a, str> {
+        let HandleWorkflowRun { repository, number } = { self };
+        format!("repos/{repository}/actions/runs/{number}").into()
+    }
+}
+
+impl FmtDisplay for HandleWorkflowRun {
+    fn fmt(&self, fmt: &mut FmtFormatter<'_>) -> FmtResult {
+        let HandleWorkflowRun { number, .. } = { self };
+        write!(fmt, "{number}")
+    }
+}
@@ -0,0 +1,126 @@
+use thiserror::{Error};
+
+use crate::{
+
+    repository::{HandleRepository},
+    client::{ClientError, Paginated},
+
+    models::common::scanning::{
+
+        PushProtectionBypass,
+        BypassRequestStatus,
+        BypassTimePeriod,
+        BypassRequest,
+        BypassReview,
+        BypassReason,
+    },
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+#[derive(Error, Debug)]
+pub enum SecretScanningError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+}
+
+#[derive(Clone, Debug)]
+pub struct HandleSecretScanning {
+    pub(crate) repository: HandleRepository,
+}
+
+impl HandleSecretScanning {
+    pub(crate) fn from(repository: &HandleRepository) -> HandleSecretScanning {
+        HandleSecretScanning { repository: repository.clone() }
+    }
+
+    pub fn try_get_bypass_requests(&self, status: Option<BypassRequestStatus>, period: BypassTimePeriod) -> GitHubResult<Vec<BypassRequest>, SecretScanningError> {
+        let HandleSecretScanning { repository } = { self };
+
+        let status = match status.map(serde_json::to_value) {
+            Some(Ok(serde_json::Value::String(status))) => status,
+            _ => "all".to_owned(),
+        };
+
+        let period = match serde_json::to_value(period) {
+            Ok(serde_json::Value::String(period)) => period,
+            _ => "month".to_owned(),
+        };
+
+        Ok(Paginated::try_fetch_all(repository.get_client(), {
+            format!("repos/{repository}/bypass-requests/secret-scanning")
+        }, &[("request_status", status), ("time_period", period)])?)
+    }
+
+    pub fn try_get_bypass_request(&self, number: Number) -> GitHubResult<BypassRequest, SecretScanningError> {
+        let HandleSecretScanning { repository } = { self };
+
+        Ok(repository.get_client()
+            .get(format!("repos/{repository}/bypass-requests/secret-scanning/{number}"))?
+            .send()?.json()?)
+    }
+
+    pub fn try_review_bypass_request(&self, request: &BypassRequest, review: BypassReview, message: &str) -> GitHubResult<&HandleSecretScanning, SecretScanningError> {
+        let HandleSecretScanning { repository } = { self };
+
+        let ref payload = serde_json::json!({
+            "status": review,
+            "message": message,
+        });
+
+        let _ = {
+
+            repository.get_client()
+                .patch(format!("repos/{repository}/bypass-responses/secret-scanning/{number}", number = request.get_number()))?
+                .json(payload)
+                .send()?
+        };
+
+        Ok(self)
+    }
+
+    pub fn try_create_push_protection_bypass(&self, placeholder: &str, reason: BypassReason) -> GitHubResult<PushProtectionBypass, SecretScanningError> {
+        let HandleSecretScanning { repository } = { self };
+
+        let ref payload = serde_json::json!({
+            "placeholder_id": placeholder,
+            "reason": reason,
+        });
+
+        Ok(repository.get_client()
+            .post(format!("repos/{repository}/secret-scanning/push-protection-bypasses"))?
+            .json(payload)
+            .send()?.json()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_bypass_requests_period() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "repos/dev-bio/octo/bypass-requests/secret-scanning?request_status=open&time_period=month&per_page=100&page=1", serde_json::json!([]));
+
+        let scanning = HandleSecretScanning::from(&(fixtures::repository(&(transport))));
+        let requests = scanning.try_get_bypass_requests(Some(BypassRequestStatus::Open), BypassTimePeriod::Month).unwrap();
+
+        assert!(requests.is_empty());
+        assert_eq!(transport.get_request_count(Method::GET, "repos/dev-bio/octo/bypass-requests/secret-scanning"), 1);
+    }
+}
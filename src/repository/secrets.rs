@@ -0,0 +1,382 @@
+use std::{
+
+    collections::{BTreeMap},
+};
+
+use thiserror::{Error};
+use serde::{Deserialize};
+
+use secrecy::{
+
+    ExposeSecret,
+    Secret,
+};
+
+use crypto_box::{
+
+    aead::{OsRng},
+    PublicKey,
+};
+
+use base64::{
+
+    engine::general_purpose::{STANDARD},
+    Engine,
+};
+
+use crate::{
+
+    repository::{HandleRepository},
+    client::{ClientError, Paginated},
+    common::{Date},
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+#[derive(Error, Debug)]
+pub enum SecretsError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Invalid public key: '{key}'")]
+    PublicKey { key: String },
+    #[error("Failed to encrypt secret: '{name}'")]
+    Encryption { name: String },
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SyncOptions {
+    prune: bool,
+    dry_run: bool,
+}
+
+impl SyncOptions {
+    pub fn new() -> SyncOptions {
+        Default::default()
+    }
+
+    pub fn with_prune(mut self, prune: bool) -> SyncOptions {
+        self.prune = prune;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> SyncOptions {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn is_prune(&self) -> bool {
+        self.prune
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SyncReport {
+    pub(crate) created: Vec<String>,
+    pub(crate) updated: Vec<String>,
+    pub(crate) unchanged: Vec<String>,
+    pub(crate) deleted: Vec<String>,
+    pub(crate) dry_run: bool,
+}
+
+impl SyncReport {
+    pub fn get_created(&self) -> Vec<String> {
+        self.created.clone()
+    }
+
+    pub fn get_updated(&self) -> Vec<String> {
+        self.updated.clone()
+    }
+
+    pub fn get_unchanged(&self) -> Vec<String> {
+        self.unchanged.clone()
+    }
+
+    pub fn get_deleted(&self) -> Vec<String> {
+        self.deleted.clone()
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.deleted.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize)]
+pub struct ConfigurationEntry {
+    pub(crate) name: String,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) created_at: Date,
+    #[serde(deserialize_with = "crate::common::deserialize_date")]
+    pub(crate) updated_at: Date,
+}
+
+impl ConfigurationEntry {
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_created(&self) -> Date {
+        self.created_at.clone()
+    }
+
+    pub fn get_updated(&self) -> Date {
+        self.updated_at.clone()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EnvironmentConfiguration {
+    pub(crate) secrets: Vec<ConfigurationEntry>,
+    pub(crate) variables: Vec<ConfigurationEntry>,
+}
+
+impl EnvironmentConfiguration {
+    pub fn get_secrets(&self) -> Vec<ConfigurationEntry> {
+        self.secrets.clone()
+    }
+
+    pub fn get_variables(&self) -> Vec<ConfigurationEntry> {
+        self.variables.clone()
+    }
+
+    pub fn get_missing_secrets(&self, expected: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+        missing(&(self.secrets), expected)
+    }
+
+    pub fn get_missing_variables(&self, expected: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+        missing(&(self.variables), expected)
+    }
+}
+
+fn missing(entries: &[ConfigurationEntry], expected: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    expected.into_iter()
+        .map(|name| name.as_ref().to_uppercase())
+        .filter(|name| !(entries.iter().any(|entry| entry.name.eq_ignore_ascii_case(name))))
+        .collect()
+}
+
+fn try_list_entries(repository: &HandleRepository, endpoint: &str) -> GitHubResult<Vec<ConfigurationEntry>, SecretsError> {
+    Ok(Paginated::try_fetch_all(repository.get_client(), endpoint, &[])?)
+}
+
+pub(crate) fn try_get_environment_configuration(repository: &HandleRepository, environment: impl AsRef<str>) -> GitHubResult<EnvironmentConfiguration, SecretsError> {
+    let environment = environment.as_ref();
+
+    let secrets = try_list_entries(repository, format!("repos/{repository}/environments/{environment}/secrets").as_str())?;
+    let variables = try_list_entries(repository, format!("repos/{repository}/environments/{environment}/variables").as_str())?;
+
+    Ok(EnvironmentConfiguration { secrets, variables })
+}
+
+pub(crate) fn try_list_environments(repository: &HandleRepository) -> GitHubResult<Vec<String>, SecretsError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct Capsule {
+        name: String,
+    }
+
+    let environments: Vec<Capsule> = Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/environments"), &[])?;
+
+    Ok(environments.into_iter()
+        .map(|Capsule { name }| name)
+        .collect())
+}
+
+pub(crate) fn try_count_secrets(repository: &HandleRepository) -> GitHubResult<usize, SecretsError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct Capsule {
+        total_count: usize,
+    }
+
+    let Capsule { total_count } = {
+        let ref query = [
+            ("per_page", 1),
+        ];
+
+        repository.get_client()
+            .get(format!("repos/{repository}/actions/secrets"))?
+            .query(query).send()?.json()?
+    };
+
+    Ok(total_count)
+}
+
+fn try_list_secrets(repository: &HandleRepository) -> GitHubResult<Vec<String>, SecretsError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct Capsule {
+        name: String,
+    }
+
+    let secrets: Vec<Capsule> = Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/actions/secrets"), &[])?;
+
+    Ok(secrets.into_iter()
+        .map(|Capsule { name }| name)
+        .collect())
+}
+
+fn try_list_variables(repository: &HandleRepository) -> GitHubResult<BTreeMap<String, String>, SecretsError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct Capsule {
+        name: String,
+        value: String,
+    }
+
+    let variables: Vec<Capsule> = Paginated::try_fetch_all(repository.get_client(), format!("repos/{repository}/actions/variables"), &[])?;
+
+    Ok(variables.into_iter()
+        .map(|Capsule { name, value }| (name, value))
+        .collect())
+}
+
+pub(crate) fn try_sync_secrets(repository: &HandleRepository, secrets: &BTreeMap<String, Secret<String>>, options: SyncOptions) -> GitHubResult<SyncReport, SecretsError> {
+    #[derive(Debug)]
+    #[derive(Deserialize)]
+    struct Capsule {
+        key_id: String,
+        key: String,
+    }
+
+    let secrets: BTreeMap<String, &Secret<String>> = secrets.iter()
+        .map(|(name, value)| (name.to_uppercase(), value))
+        .collect();
+
+    let existing = try_list_secrets(repository)?;
+
+    let mut report = SyncReport { dry_run: options.dry_run, .. Default::default() };
+
+    for name in secrets.keys() {
+        match existing.iter().any(|candidate| candidate.eq_ignore_ascii_case(name)) {
+            true => report.updated.push(name.clone()),
+            false => report.created.push(name.clone()),
+        }
+    }
+
+    if options.prune {
+        report.deleted.extend(existing.into_iter()
+            .filter(|name| !(secrets.contains_key(&(name.to_uppercase())))));
+    }
+
+    if options.dry_run {
+        return Ok(report)
+    }
+
+    if !(secrets.is_empty()) {
+        let Capsule { key_id, key } = {
+            repository.get_client()
+                .get(format!("repos/{repository}/actions/secrets/public-key"))?
+                .send()?.json()?
+        };
+
+        let public = try_parse_public_key(&(key_id), &(key))?;
+
+        for (name, value) in secrets.iter() {
+            let sealed = try_seal(&(public), name, value)?;
+
+            let ref payload = serde_json::json!({
+                "encrypted_value": sealed,
+                "key_id": key_id,
+            });
+
+            repository.get_client()
+                .put(format!("repos/{repository}/actions/secrets/{name}"))?
+                .json(payload)
+                .send()?;
+        }
+    }
+
+    for name in report.deleted.iter() {
+        repository.get_client()
+            .delete(format!("repos/{repository}/actions/secrets/{name}"))?
+            .send()?;
+    }
+
+    Ok(report)
+}
+
+pub(crate) fn try_parse_public_key(key_id: &str, key: &str) -> GitHubResult<PublicKey, SecretsError> {
+    STANDARD.decode(key).ok()
+        .and_then(|bytes| PublicKey::from_slice(bytes.as_slice()).ok())
+        .ok_or_else(|| SecretsError::PublicKey { key: key_id.to_owned() })
+}
+
+pub(crate) fn try_seal(public: &PublicKey, name: &str, value: &Secret<String>) -> GitHubResult<String, SecretsError> {
+    let sealed = public.seal(&mut OsRng, value.expose_secret().as_bytes()).map_err(|_| {
+        SecretsError::Encryption { name: name.to_owned() }
+    })?;
+
+    Ok(STANDARD.encode(sealed))
+}
+
+pub(crate) fn try_sync_variables(repository: &HandleRepository, variables: &BTreeMap<String, String>, options: SyncOptions) -> GitHubResult<SyncReport, SecretsError> {
+    let variables: BTreeMap<String, &String> = variables.iter()
+        .map(|(name, value)| (name.to_uppercase(), value))
+        .collect();
+
+    let existing: BTreeMap<String, (String, String)> = try_list_variables(repository)?.into_iter()
+        .map(|(name, value)| (name.to_uppercase(), (name, value)))
+        .collect();
+
+    let mut report = SyncReport { dry_run: options.dry_run, .. Default::default() };
+
+    for (name, value) in variables.iter() {
+        match existing.get(name) {
+            Some((_, current)) if current == *value => report.unchanged.push(name.clone()),
+            Some(_) => report.updated.push(name.clone()),
+            None => report.created.push(name.clone()),
+        }
+    }
+
+    if options.prune {
+        report.deleted.extend(existing.iter()
+            .filter(|(key, _)| !(variables.contains_key(*key)))
+            .map(|(_, (name, _))| name.clone()));
+    }
+
+    if options.dry_run {
+        return Ok(report)
+    }
+
+    for name in report.created.iter() {
+        let ref payload = serde_json::json!({
+            "name": name,
+            "value": variables[name],
+        });
+
+        repository.get_client()
+            .post(format!("repos/{repository}/actions/variables"))?
+            .json(payload)
+            .send()?;
+    }
+
+    for name in report.updated.iter() {
+        let ref payload = serde_json::json!({
+            "name": name,
+            "value": variables[name],
+        });
+
+        repository.get_client()
+            .patch(format!("repos/{repository}/actions/variables/{name}"))?
+            .json(payload)
+            .send()?;
+    }
+
+    for name in report.deleted.iter() {
+        repository.get_client()
+            .delete(format!("repos/{repository}/actions/variables/{name}"))?
+            .send()?;
+    }
+
+    Ok(report)
+}
@@ -28,6 +28,11 @@ impl<'h> Sha<'h> {
         Sha(Cow::Owned(self.as_ref()
             .to_owned()))
     }
+
+    pub fn into_owned(self) -> Sha<'static> {
+        let Sha(value) = { self };
+        Sha(Cow::Owned(value.into_owned()))
+    }
 }
 
 impl<'h> AsRef<str> for Sha<'h> {
@@ -0,0 +1,187 @@
+use std::{
+
+    collections::{BTreeMap},
+    path::{PathBuf},
+    time::{Duration},
+    thread::{self},
+};
+
+use crate::{
+
+    repository::{
+
+        tree::{
+
+            TreeEntryMode,
+            TreeEntry,
+        },
+
+        blob::{
+
+            BlobError,
+            Blob,
+        },
+
+        commit::{HandleCommit},
+
+        HandleRepositoryError,
+        HandleRepository,
+    },
+
+    account::{Account},
+
+    GitHubProperties,
+    GitHubResult,
+};
+
+const GENERATE_ATTEMPTS: u64 = 10;
+
+#[derive(Clone, Debug, Default)]
+pub struct TemplateSubstitution {
+    variables: BTreeMap<String, String>,
+    paths: Vec<PathBuf>,
+}
+
+impl TemplateSubstitution {
+    pub fn new() -> TemplateSubstitution {
+        Default::default()
+    }
+
+    pub fn with_variable(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> TemplateSubstitution {
+        self.variables.insert(name.as_ref().trim().to_owned(), value.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> TemplateSubstitution {
+        self.paths.push(path.into());
+        self
+    }
+
+    pub fn get_variables(&self) -> BTreeMap<String, String> {
+        self.variables.clone()
+    }
+
+    pub fn get_paths(&self) -> Vec<PathBuf> {
+        self.paths.clone()
+    }
+
+    pub fn apply(&self, text: impl AsRef<str>) -> String {
+        let text = text.as_ref();
+
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start + 2..].find("}}") else {
+                break
+            };
+
+            let name = rest[start + 2..start + 2 + end].trim();
+
+            result.push_str(&rest[..start]);
+
+            match self.variables.get(name) {
+                Some(value) => result.push_str(value.as_str()),
+                None => result.push_str(&rest[start..start + end + 4]),
+            }
+
+            rest = &rest[start + end + 4..];
+        }
+
+        result.push_str(rest);
+        result
+    }
+}
+
+pub(crate) fn try_generate(template: &HandleRepository, owner: &Account, name: impl AsRef<str>, private: bool) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+    let name = name.as_ref();
+
+    let ref payload = serde_json::json!({
+        "owner": owner.to_string(),
+        "name": name,
+        "private": private,
+    });
+
+    let _ = {
+
+        template.get_client()
+            .post(format!("repos/{template}/generate"))?
+            .json(payload)
+            .send()?
+    };
+
+    HandleRepository::try_fetch(owner, name)
+}
+
+pub(crate) fn try_substitute(repository: &HandleRepository, substitution: &TemplateSubstitution, message: impl AsRef<str>) -> GitHubResult<Option<HandleCommit>, HandleRepositoryError> {
+    let reference = repository.try_get_default_branch()?;
+    let parent = reference.try_get_commit()?;
+
+    let mut entries = Vec::new();
+
+    for path in substitution.paths.iter() {
+        let current = match Blob::try_fetch_for_path(repository, parent.get_sha().to_string(), path) {
+            Err(HandleRepositoryError::Blob(BlobError::Nothing { .. })) => continue,
+            Err(error) => return Err(error),
+            Ok(blob) => match blob.as_text() {
+                Some(text) => text.to_owned(),
+                None => continue,
+            },
+        };
+
+        let updated = substitution.apply(current.as_str());
+
+        if updated == current {
+            continue
+        }
+
+        let blob = repository.try_create_text_blob(updated)?;
+
+        entries.push(TreeEntry::blob(blob)
+            .with_mode(TreeEntryMode::file())
+            .with_path(path));
+    }
+
+    if entries.is_empty() {
+        return Ok(None)
+    }
+
+    let tree = repository.try_create_tree_with_base(parent.clone(), entries)?;
+    let commit = repository.try_create_commit([parent], tree, message)?;
+
+    reference.try_fast_forward(commit.clone())?;
+
+    Ok(Some(commit))
+}
+
+pub(crate) fn try_generate_with_substitution(template: &HandleRepository, owner: &Account, name: impl AsRef<str>, private: bool, substitution: &TemplateSubstitution) -> GitHubResult<HandleRepository, HandleRepositoryError> {
+    let repository = try_generate(template, owner, name, private)?;
+
+    for attempt in 1..=GENERATE_ATTEMPTS {
+        match repository.try_get_default_branch() {
+            Err(_) if attempt < GENERATE_ATTEMPTS => thread::sleep(Duration::from_secs(attempt)),
+            Err(error) => return Err(error),
+            Ok(_) => break,
+        }
+    }
+
+    try_substitute(&(repository), substitution, "Apply template variables")?;
+
+    Ok(repository)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let substitution = TemplateSubstitution::new()
+            .with_variable("project_name", "octo")
+            .with_variable("owner", "dev-bio");
+
+        assert_eq!(substitution.apply("# {{project_name}} by {{ owner }}"), "# octo by dev-bio");
+        assert_eq!(substitution.apply("{{unknown}} stays, {{project_name"), "{{unknown}} stays, {{project_name");
+        assert_eq!(substitution.apply("no placeholders"), "no placeholders");
+    }
+}
@@ -0,0 +1,405 @@
+use std::{
+
+    collections::{BTreeMap},
+
+    path::{
+
+        PathBuf,
+        Path,
+    },
+
+    sync::{
+
+        atomic::{
+
+            AtomicUsize,
+            Ordering,
+        },
+
+        Mutex,
+    },
+
+    thread::{self},
+};
+
+use thiserror::{Error};
+use serde::{Deserialize};
+use glob::{Pattern};
+use regex::{Regex};
+
+use crate::{
+
+    repository::{
+
+        blob::{
+
+            BlobError,
+            Blob,
+        },
+
+        tree::{Tree},
+        sha::{Sha},
+
+        HandleRepository,
+    },
+
+    client::{ClientError},
+
+    GitHubProperties,
+    GitHubResult,
+    Number,
+};
+
+pub const DEFAULT_MAX_SIZE: usize = 1024 * 1024;
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+#[derive(Error, Debug)]
+pub enum TreeIndexError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Blob error!")]
+    Blob(#[from] BlobError),
+    #[error("Invalid pattern: '{pattern}'")]
+    Pattern { pattern: String },
+    #[error("Invalid expression: '{expression}', reason: '{reason}'")]
+    Expression { expression: String, reason: String },
+    #[error("Tree is too large to index: '{sha}'")]
+    Truncated { sha: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeIndexOptions {
+    patterns: Vec<String>,
+    extensions: Vec<String>,
+    max_size: usize,
+    concurrency: usize,
+}
+
+impl Default for TreeIndexOptions {
+    fn default() -> TreeIndexOptions {
+        TreeIndexOptions {
+            patterns: Vec::new(),
+            extensions: Vec::new(),
+            max_size: DEFAULT_MAX_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+impl TreeIndexOptions {
+    pub fn new() -> TreeIndexOptions {
+        Default::default()
+    }
+
+    pub fn with_pattern(mut self, pattern: impl AsRef<str>) -> TreeIndexOptions {
+        self.patterns.push(pattern.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_extension(mut self, extension: impl AsRef<str>) -> TreeIndexOptions {
+        self.extensions.push(extension.as_ref().trim_start_matches('.').to_owned());
+        self
+    }
+
+    pub fn with_max_size(mut self, size: usize) -> TreeIndexOptions {
+        self.max_size = size;
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> TreeIndexOptions {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    fn try_get_patterns(&self) -> GitHubResult<Vec<Pattern>, TreeIndexError> {
+        self.patterns.iter().map(|pattern| {
+            Pattern::new(pattern.as_str()).map_err(|_| TreeIndexError::Pattern {
+                pattern: pattern.clone(),
+            })
+        }).collect()
+    }
+
+    fn matches(&self, patterns: &[Pattern], path: &Path) -> bool {
+        let extension = path.extension()
+            .and_then(|extension| extension.to_str());
+
+        let pattern = patterns.is_empty() || patterns.iter()
+            .any(|pattern| pattern.matches_path(path));
+
+        let extension = self.extensions.is_empty() || extension.map_or(false, |extension| {
+            self.extensions.iter().any(|candidate| candidate.eq_ignore_ascii_case(extension))
+        });
+
+        pattern && extension
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IndexedFile {
+    path: PathBuf,
+    sha: Sha<'static>,
+    size: usize,
+    content: Option<String>,
+}
+
+impl IndexedFile {
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn get_sha(&self) -> Sha<'static> {
+        self.sha.clone()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get_content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    pub fn is_indexed(&self) -> bool {
+        self.content.is_some()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq)]
+pub struct IndexMatch {
+    path: PathBuf,
+    line: Number,
+    text: String,
+}
+
+impl IndexMatch {
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn get_line(&self) -> Number {
+        self.line
+    }
+
+    pub fn get_text(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TreeIndex {
+    files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+impl TreeIndex {
+    pub fn try_build(repository: &HandleRepository, tree: &Tree, options: &TreeIndexOptions) -> GitHubResult<TreeIndex, TreeIndexError> {
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct CapsuleEntry {
+            path: PathBuf,
+            #[serde(rename = "type")]
+            kind: String,
+            sha: Sha<'static>,
+            #[serde(default)]
+            size: usize,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize)]
+        struct Capsule {
+            tree: Vec<CapsuleEntry>,
+            #[serde(default)]
+            truncated: bool,
+        }
+
+        let patterns = options.try_get_patterns()?;
+
+        let Capsule { tree: entries, truncated } = {
+            let ref query = [
+                ("recursive", "true"),
+            ];
+
+            repository.get_client()
+                .get(format!("repos/{repository}/git/trees/{sha}", sha = tree.get_sha()))?
+                .query(query).send()?.json()?
+        };
+
+        if truncated {
+            return Err(TreeIndexError::Truncated { sha: tree.get_sha().to_string() })
+        }
+
+        let entries: Vec<CapsuleEntry> = entries.into_iter()
+            .map(|entry| CapsuleEntry { path: tree.get_path().join(entry.path.as_path()), .. entry })
+            .filter(|entry| entry.kind == "blob" && options.matches(patterns.as_slice(), entry.path.as_path()))
+            .collect();
+
+        let files = Mutex::new(BTreeMap::new());
+        let failure = Mutex::new(None);
+        let index = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..options.concurrency.min(entries.len()) {
+                scope.spawn(|| loop {
+                    let Some(CapsuleEntry { path, sha, size, .. }) = entries.get(index.fetch_add(1, Ordering::Relaxed)) else {
+                        break
+                    };
+
+                    let content = match *(size) > options.max_size {
+                        true => Ok(None),
+                        false => Blob::try_fetch(repository, sha.clone())
+                            .map(|blob| blob.as_text().map(str::to_owned)),
+                    };
+
+                    match content {
+                        Ok(content) => if let Ok(mut files) = files.lock() {
+                            files.insert(path.clone(), IndexedFile {
+                                path: path.clone(),
+                                sha: sha.clone(),
+                                size: *(size),
+                                content,
+                            });
+                        },
+                        Err(error) => {
+                            if let Ok(mut failure) = failure.lock() {
+                                failure.get_or_insert(error);
+                            }
+
+                            index.store(entries.len(), Ordering::Relaxed);
+                            break
+                        },
+                    }
+                });
+            }
+        });
+
+        if let Some(error) = failure.into_inner().ok().flatten() {
+            return Err(error.into())
+        }
+
+        Ok(TreeIndex {
+            files: files.into_inner()
+                .unwrap_or_default(),
+        })
+    }
+
+    pub fn get_file(&self, path: impl AsRef<Path>) -> Option<&IndexedFile> {
+        self.files.get(path.as_ref())
+    }
+
+    pub fn get_paths(&self) -> Vec<PathBuf> {
+        self.files.keys()
+            .cloned()
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &IndexedFile> {
+        self.files.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn search(&self, expression: &Regex) -> Vec<IndexMatch> {
+        self.files.values().flat_map(|file| {
+            file.content.iter().flat_map(move |content| {
+                content.lines().enumerate()
+                    .filter(|(_, text)| expression.is_match(text))
+                    .map(|(number, text)| IndexMatch {
+                        path: file.path.clone(),
+                        line: number + 1,
+                        text: text.to_owned(),
+                    })
+            })
+        }).collect()
+    }
+
+    pub fn try_search(&self, expression: impl AsRef<str>) -> GitHubResult<Vec<IndexMatch>, TreeIndexError> {
+        let expression = expression.as_ref();
+
+        let ref compiled = Regex::new(expression).map_err(|error| {
+            TreeIndexError::Expression { expression: expression.to_owned(), reason: error.to_string() }
+        })?;
+
+        Ok(self.search(compiled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    fn file(path: &str, content: Option<&str>) -> (PathBuf, IndexedFile) {
+        (path.into(), IndexedFile {
+            path: path.into(),
+            sha: path.to_owned().into(),
+            size: content.map_or(0, str::len),
+            content: content.map(str::to_owned),
+        })
+    }
+
+    #[test]
+    fn test_search() {
+        let index = TreeIndex {
+            files: BTreeMap::from([
+                file("src/lib.rs", Some("pub mod client;\n// TODO: remove\nfn main() {}")),
+                file("docs/guide.md", Some("nothing to do here")),
+                file("assets/logo.png", None),
+            ]),
+        };
+
+        let matches = index.try_search(r"TODO:").unwrap();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].get_path(), Path::new("src/lib.rs"));
+        assert_eq!(matches[0].get_line(), 2);
+        assert!(index.try_search("(").is_err());
+    }
+
+    #[test]
+    fn test_options() {
+        let options = TreeIndexOptions::new()
+            .with_pattern("src/**")
+            .with_extension(".rs");
+
+        let ref patterns = options.try_get_patterns().unwrap();
+
+        assert!(options.matches(patterns, Path::new("src/client/mod.rs")));
+        assert!(!(options.matches(patterns, Path::new("src/client/README.md"))));
+        assert!(!(options.matches(patterns, Path::new("tests/client.rs"))));
+        assert_eq!(options.with_concurrency(0).concurrency, 1);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "repos/dev-bio/octo/git/trees/abc?recursive=true", serde_json::json!({
+                "sha": "abc",
+                "tree": [{ "path": "src/lib.rs", "type": "blob", "sha": "def", "size": 12 }],
+                "truncated": true,
+            }));
+
+        let repository = fixtures::repository(&(transport));
+
+        let tree = Tree { tree: Vec::new(), path: PathBuf::new(), sha: "abc".to_owned().into() };
+
+        assert!(matches!(TreeIndex::try_build(&(repository), &(tree), &(TreeIndexOptions::new())), Err(TreeIndexError::Truncated { .. })));
+        assert_eq!(transport.get_request_count(Method::GET, "repos/dev-bio/octo/git/trees/abc"), 1);
+    }
+}
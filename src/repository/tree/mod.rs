@@ -1,5 +1,7 @@
 use std::{
 
+    collections::{BTreeMap},
+
     path::{
         
         PathBuf, 
@@ -45,6 +47,9 @@ use crate::{
 
 use super::{HandleRepositoryError, blob::Blob};
 
+#[cfg(feature = "index")]
+pub mod index;
+
 #[derive(Debug, Clone)]
 pub enum TreeEntryMode {
     File,
@@ -151,6 +156,22 @@ impl TreeEntry {
         }
     }
 
+    pub fn get_sha(&self) -> Sha<'_> {
+        match self {
+            TreeEntry::Blob { sha, .. } => sha.clone(),
+            TreeEntry::Tree { sha, .. } => sha.clone(),
+            TreeEntry::Commit { sha, .. } => sha.clone(),
+        }
+    }
+
+    pub fn get_mode(&self) -> u32 {
+        match self {
+            TreeEntry::Blob { mode, .. } => mode.clone(),
+            TreeEntry::Tree { mode, .. } => mode.clone(),
+            TreeEntry::Commit { mode, .. } => mode.clone(),
+        }
+    }
+
     pub fn with_mode(self, mode: TreeEntryMode) -> Self {
         match self {
             TreeEntry::Blob { path, sha, .. } => {
@@ -195,20 +216,62 @@ where D: Deserializer<'de> {
 fn serialize_mode<S>(mode: &u32, serializer: S) -> GitHubResult<S::Ok, S::Error>
 where S: Serializer {
     serializer.serialize_str({
-        dbg!(format!("{mode:06o}")
-            .as_str())
+        format!("{mode:06o}")
+            .as_str()
     })
 }
 
+#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum TreeDelta {
+    Added { entry: TreeEntry },
+    Removed { entry: TreeEntry },
+    Modified { before: TreeEntry, after: TreeEntry },
+}
+
+impl TreeDelta {
+    pub fn get_path(&self) -> &Path {
+        match self {
+            TreeDelta::Added { entry } => entry.get_path(),
+            TreeDelta::Removed { entry } => entry.get_path(),
+            TreeDelta::Modified { after, .. } => after.get_path(),
+        }
+    }
+
+    pub fn is_added(&self) -> bool {
+        match self {
+            TreeDelta::Added { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_removed(&self) -> bool {
+        match self {
+            TreeDelta::Removed { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_modified(&self) -> bool {
+        match self {
+            TreeDelta::Modified { .. } => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum TreeError {
     #[error("Client error!")]
     Client(#[from] ClientError),
+    #[error("Tree not found: '{path:?}'")]
+    Nothing { path: PathBuf },
 }
 
 #[derive(Clone, Debug)]
 pub struct Tree {
     pub(crate) tree: Vec<TreeEntry>,
+    pub(crate) path: PathBuf,
     pub(crate) sha: Sha<'static>,
 }
 
@@ -232,6 +295,7 @@ impl Tree {
         Ok(Tree { 
 
             tree,
+            path: Default::default(),
             sha,
         })
     }
@@ -266,6 +330,7 @@ impl Tree {
         Ok(Tree { 
 
             tree,
+            path: Default::default(),
             sha,
         })
     }
@@ -293,11 +358,105 @@ impl Tree {
         Ok(Tree { 
 
             tree,
+            path: Default::default(),
             sha,
         })
     }
 }
 
+impl Tree {
+    pub fn diff(&self, other: &Tree) -> Vec<TreeDelta> {
+        let before: BTreeMap<&Path, &TreeEntry> = self.tree.iter()
+            .map(|entry| (entry.get_path(), entry))
+            .collect();
+
+        let after: BTreeMap<&Path, &TreeEntry> = other.tree.iter()
+            .map(|entry| (entry.get_path(), entry))
+            .collect();
+
+        let mut collection = Vec::new();
+
+        for (path, entry) in before.iter() {
+            match after.get(path) {
+                Some(other) if other != entry => {
+                    collection.push(TreeDelta::Modified { 
+                        before: (*entry).clone(), 
+                        after: (*other).clone(),
+                    })
+                },
+                Some(_) => continue,
+                None => collection.push(TreeDelta::Removed { 
+                    entry: (*entry).clone() 
+                }),
+            }
+        }
+
+        for (path, entry) in after.iter() {
+            if !(before.contains_key(path)) {
+                collection.push(TreeDelta::Added { 
+                    entry: (*entry).clone() 
+                })
+            }
+        }
+
+        collection.sort_by(|one, two| {
+            one.get_path().cmp(two.get_path())
+        });
+
+        collection
+    }
+
+    pub fn get_entry(&self, path: impl AsRef<Path>) -> Option<&TreeEntry> {
+        let path = path.as_ref();
+
+        self.tree.iter().find(|entry| {
+            entry.get_path() == path
+        })
+    }
+
+    pub fn try_get_subtree(&self, path: impl AsRef<Path>, repository: &HandleRepository) -> GitHubResult<Tree, TreeError> {
+        let path = path.as_ref();
+
+        if let Some(TreeEntry::Tree { sha, .. }) = self.get_entry(path) {
+            let Tree { tree, sha, .. } = Tree::try_fetch(repository, sha.clone(), false)?;
+            return Ok(Tree { tree, path: self.path.join(path), sha })
+        }
+
+        let mut current = self.clone();
+        let mut walked = PathBuf::new();
+
+        for component in path.components() {
+            walked.push(component);
+
+            let sha = match current.get_entry(component) {
+                Some(TreeEntry::Tree { sha, .. }) => sha.clone(),
+                _ => return Err(TreeError::Nothing { 
+                    path: self.path.join(path) 
+                }),
+            };
+
+            let Tree { tree, sha, .. } = Tree::try_fetch(repository, sha, false)?;
+            current = Tree { tree, path: self.path.join(walked.as_path()), sha };
+        }
+
+        Ok(current)
+    }
+
+    pub fn iter_paths(&self) -> impl Iterator<Item = (PathBuf, &TreeEntry)> {
+        self.tree.iter().map(|entry| {
+            (self.path.join(entry.get_path()), entry)
+        })
+    }
+
+    pub fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn get_sha(&self) -> Sha<'_> {
+        self.sha.clone()
+    }
+}
+
 impl Deref for Tree {
     type Target = [TreeEntry];
     fn deref(&self) -> &Self::Target {
@@ -322,3 +481,55 @@ impl FmtDisplay for Tree {
         write!(fmt, "{sha}", sha = self.sha)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+
+        TreeDelta,
+        TreeEntry,
+        Tree,
+    };
+
+    fn blob(path: &str, sha: &str) -> TreeEntry {
+        TreeEntry::Blob { 
+            path: path.into(), 
+            mode: 0o100644, 
+            sha: sha.to_owned().into(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Tree {
+            tree: Vec::from([
+                blob("README.md", "a"),
+                blob("src/lib.rs", "b"),
+                blob("src/old.rs", "c"),
+            ]),
+            path: Default::default(),
+            sha: "before".to_owned().into(),
+        };
+
+        let after = Tree {
+            tree: Vec::from([
+                blob("README.md", "a"),
+                blob("src/lib.rs", "d"),
+                blob("src/new.rs", "e"),
+            ]),
+            path: Default::default(),
+            sha: "after".to_owned().into(),
+        };
+
+        let delta = before.diff(&after);
+
+        assert_eq!(delta, Vec::from([
+            TreeDelta::Modified { before: blob("src/lib.rs", "b"), after: blob("src/lib.rs", "d") },
+            TreeDelta::Added { entry: blob("src/new.rs", "e") },
+            TreeDelta::Removed { entry: blob("src/old.rs", "c") },
+        ]));
+
+        assert_eq!(after.diff(&after).len(), 0);
+    }
+}
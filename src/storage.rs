@@ -0,0 +1,354 @@
+use std::{
+
+    collections::{BTreeMap},
+    fmt::{Debug as FmtDebug},
+};
+
+use thiserror::{Error};
+
+use serde::{
+
+    de::{DeserializeOwned},
+
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+
+    client::{
+
+        ClientError,
+        Client,
+    },
+
+    GitHubResult,
+};
+
+pub const GIST_STORE_INDEX: &str = "octo-store.json";
+
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("Client error!")]
+    Client(#[from] ClientError),
+    #[error("Invalid storage key: '{key}'")]
+    Key { key: String },
+    #[error("Failed to encode value: '{key}', reason: '{reason}'")]
+    Encode { key: String, reason: String },
+    #[error("Failed to decode value: '{key}', reason: '{reason}'")]
+    Decode { key: String, reason: String },
+    #[error("Gist has no revision history: '{gist}'")]
+    History { gist: String },
+    #[error("Gist was changed concurrently: '{gist}', expected revision: '{expected}', actual revision: '{actual}'")]
+    Conflict { gist: String, expected: String, actual: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct GistEntry<T> {
+    value: T,
+    revision: String,
+}
+
+impl<T: Clone> GistEntry<T> {
+    pub fn get_value(&self) -> T {
+        self.value.clone()
+    }
+
+    pub fn get_revision(&self) -> String {
+        self.revision.clone()
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+#[derive(Debug)]
+#[derive(Deserialize)]
+struct CapsuleHistory {
+    version: String,
+}
+
+#[derive(Debug)]
+#[derive(Deserialize)]
+struct CapsuleFile {
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    truncated: bool,
+    #[serde(default)]
+    raw_url: Option<String>,
+}
+
+#[derive(Debug)]
+#[derive(Deserialize)]
+struct CapsuleGist {
+    id: String,
+    #[serde(default)]
+    files: BTreeMap<String, CapsuleFile>,
+    #[serde(default)]
+    history: Vec<CapsuleHistory>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GistStore {
+    pub(crate) client: Client,
+    pub(crate) gist: String,
+}
+
+impl GistStore {
+    pub fn from(client: &Client, gist: impl AsRef<str>) -> GistStore {
+        GistStore { client: client.clone(), gist: gist.as_ref().to_owned() }
+    }
+
+    pub fn try_create(client: &Client, description: impl AsRef<str>) -> GitHubResult<GistStore, StorageError> {
+        let ref payload = serde_json::json!({
+            "description": description.as_ref(),
+            "public": false,
+            "files": {
+                GIST_STORE_INDEX: { "content": "{}" },
+            },
+        });
+
+        let CapsuleGist { id, .. } = {
+            client.post("gists")?
+                .json(payload)
+                .send()?.json()?
+        };
+
+        Ok(GistStore::from(client, id))
+    }
+
+    pub fn get_gist(&self) -> String {
+        self.gist.clone()
+    }
+
+    pub fn try_get_revision(&self) -> GitHubResult<String, StorageError> {
+        let CapsuleGist { history, .. } = self.try_fetch()?;
+
+        self.try_latest(history.as_slice())
+    }
+
+    pub fn try_keys(&self) -> GitHubResult<Vec<String>, StorageError> {
+        let CapsuleGist { files, .. } = self.try_fetch()?;
+
+        Ok(files.into_keys()
+            .filter(|name| name != GIST_STORE_INDEX)
+            .filter_map(|name| name.strip_suffix(".json").map(str::to_owned))
+            .collect())
+    }
+
+    pub fn try_get<T: DeserializeOwned + FmtDebug>(&self, key: impl AsRef<str>) -> GitHubResult<Option<GistEntry<T>>, StorageError> {
+        let key = key.as_ref();
+        let file = file_of(key)?;
+
+        let CapsuleGist { mut files, history, .. } = self.try_fetch()?;
+
+        let revision = self.try_latest(history.as_slice())?;
+
+        let CapsuleFile { content, truncated, raw_url } = match files.remove(file.as_str()) {
+            Some(capsule) => capsule,
+            None => return Ok(None),
+        };
+
+        let content = self.try_read(CapsuleFile { content, truncated, raw_url })?;
+
+        let value = serde_json::from_str(content.as_str()).map_err(|error| {
+            StorageError::Decode { key: key.to_owned(), reason: error.to_string() }
+        })?;
+
+        Ok(Some(GistEntry { value, revision }))
+    }
+
+    pub fn try_put<T: Serialize>(&self, key: impl AsRef<str>, value: &T, expected: Option<&str>) -> GitHubResult<String, StorageError> {
+        let key = key.as_ref();
+        let file = file_of(key)?;
+
+        let content = serde_json::to_string_pretty(value).map_err(|error| {
+            StorageError::Encode { key: key.to_owned(), reason: error.to_string() }
+        })?;
+
+        self.try_update(expected, serde_json::json!({
+            file: { "content": content },
+        }))
+    }
+
+    pub fn try_delete(&self, key: impl AsRef<str>, expected: Option<&str>) -> GitHubResult<String, StorageError> {
+        let file = file_of(key.as_ref())?;
+
+        self.try_update(expected, serde_json::json!({
+            file: null,
+        }))
+    }
+
+    fn try_update(&self, expected: Option<&str>, files: serde_json::Value) -> GitHubResult<String, StorageError> {
+        let GistStore { client, gist } = { self };
+
+        if let Some(expected) = expected {
+            let actual = self.try_get_revision()?;
+
+            if actual != expected {
+                return Err(StorageError::Conflict {
+                    gist: gist.clone(),
+                    expected: expected.to_owned(),
+                    actual,
+                })
+            }
+        }
+
+        let ref payload = serde_json::json!({
+            "files": files,
+        });
+
+        let CapsuleGist { history, .. } = {
+            client.patch(format!("gists/{gist}"))?
+                .json(payload)
+                .send()?.json()?
+        };
+
+        let revision = self.try_latest(history.as_slice())?;
+
+        if let Some(expected) = expected {
+            let previous = history.get(1)
+                .map(|CapsuleHistory { version }| version.clone());
+
+            if let Some(actual) = previous.filter(|previous| previous != expected) {
+                return Err(StorageError::Conflict {
+                    gist: gist.clone(),
+                    expected: expected.to_owned(),
+                    actual,
+                })
+            }
+        }
+
+        Ok(revision)
+    }
+
+    fn try_read(&self, file: CapsuleFile) -> GitHubResult<String, StorageError> {
+        let CapsuleFile { content, truncated, raw_url } = file;
+
+        Ok(match (truncated, raw_url) {
+            (true, Some(url)) => self.client.get(url)?
+                .send()?.text()?,
+            (_, _) => content.unwrap_or_default(),
+        })
+    }
+
+    fn try_fetch(&self) -> GitHubResult<CapsuleGist, StorageError> {
+        let GistStore { client, gist } = { self };
+
+        Ok(client.get(format!("gists/{gist}"))?
+            .send()?.json()?)
+    }
+
+    fn try_latest(&self, history: &[CapsuleHistory]) -> GitHubResult<String, StorageError> {
+        history.first()
+            .map(|CapsuleHistory { version }| version.clone())
+            .ok_or_else(|| StorageError::History { gist: self.gist.clone() })
+    }
+}
+
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    !(key.is_empty()) && !(key.starts_with('.')) && key.chars().all(|character| {
+        character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.')
+    })
+}
+
+fn file_of(key: &str) -> GitHubResult<String, StorageError> {
+    match is_valid_key(key) {
+        true => Ok(format!("{key}.json")),
+        false => Err(StorageError::Key { key: key.to_owned() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+
+        repository::{fixtures},
+
+        client::{
+
+            MockResponse,
+            MockTransport,
+            Method,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_is_valid_key() {
+        assert!(is_valid_key("schedule"));
+        assert!(is_valid_key("last-run_v2.state"));
+
+        assert!(!(is_valid_key("")));
+        assert!(!(is_valid_key(".hidden")));
+        assert!(!(is_valid_key("nested/key")));
+
+        assert_eq!(file_of("schedule").unwrap(), "schedule.json");
+    }
+
+    #[test]
+    fn test_raw_content_unauthorized() {
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "gists/store", serde_json::json!({
+                "id": "store",
+                "files": { "schedule.json": {
+                    "truncated": true,
+                    "raw_url": "https://gist.githubusercontent.com/octocat/store/raw/r1/schedule.json",
+                } },
+                "history": [{ "version": "r1" }],
+            }))
+            .with_response(Method::GET, "octocat/store/raw/r1/schedule.json", MockResponse::new(200).with_body("\"mine\""));
+
+        let client = Client::builder()
+            .with_transport(transport.clone())
+            .with_token("secret")
+            .build()
+            .unwrap();
+
+        let entry = GistStore::from(&(client), "store").try_get::<String>("schedule")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(entry.get_value(), "mine");
+        assert_eq!(transport.get_authorized_count(Method::GET, "gists/store"), 1);
+        assert_eq!(transport.get_authorized_count(Method::GET, "octocat/store/raw/r1/schedule.json"), 0);
+    }
+
+    #[test]
+    fn test_concurrent_write_conflicts() {
+        let history = |versions: &[&str]| -> serde_json::Value {
+            versions.iter()
+                .map(|version| serde_json::json!({ "version": version }))
+                .collect()
+        };
+
+        let transport = MockTransport::new()
+            .with_json(Method::GET, "gists/store", serde_json::json!({
+                "id": "store",
+                "history": history(&["r1"]),
+            }))
+            .with_json(Method::PATCH, "gists/store", serde_json::json!({
+                "id": "store",
+                "history": history(&["r3", "r2", "r1"]),
+            }));
+
+        let client = fixtures::client(&(transport));
+
+        let store = GistStore::from(&(client), "store");
+
+        match store.try_put("schedule", &("mine"), Some("r1")) {
+            Err(StorageError::Conflict { expected, actual, .. }) => {
+                assert_eq!(expected, "r1");
+                assert_eq!(actual, "r2");
+            },
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert_eq!(transport.get_request_count(Method::PATCH, "gists/store"), 1);
+        assert_eq!(transport.get_requests().len(), 2);
+    }
+}